@@ -0,0 +1,74 @@
+use avalanche_types::{
+    avm::txs::vertex::Vertex,
+    codec::linear::Unpack,
+    ids::{self, short},
+    key,
+    packer::{reader::PackerReader, Packer},
+    txs::utxo::{self, Utxo},
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_utxo_unpack(c: &mut Criterion) {
+    let utxo = Utxo {
+        utxo_id: utxo::Id {
+            tx_id: ids::Id::from_slice(&[1; 32]),
+            output_index: 7,
+            ..utxo::Id::default()
+        },
+        asset_id: ids::Id::from_slice(&[2; 32]),
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 200000000000000000,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![short::Id::from_slice(&[3; 20])],
+            },
+        }),
+        ..Utxo::default()
+    };
+    let b = utxo.pack(0).unwrap().take_bytes();
+
+    // both decode with "PackerReader::unpack_utxo", where "packer" is
+    // the "Unpack" path that the codec takes for the nested UTXOs
+    let mut group = c.benchmark_group("utxo_unpack");
+    group.bench_function("packer", |bench| {
+        bench.iter(|| {
+            let packer = Packer::load_bytes_for_unpack(b.len(), black_box(&b));
+            packer.unpack_u16().unwrap();
+            Utxo::unpack_from(&packer).unwrap()
+        })
+    });
+    group.bench_function("packer_reader", |bench| {
+        bench.iter(|| Utxo::unpack(black_box(&b)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_vertex_unpack(c: &mut Criterion) {
+    let mut vtx = Vertex {
+        codec_version: 0,
+        chain_id: ids::Id::from_slice(&[1; 32]),
+        height: 1234567,
+        epoch: 0,
+        parent_ids: (0..8u8).map(|i| ids::Id::from_slice(&[i; 32])).collect(),
+        txs: (0..64u8).map(|i| vec![i; 512]).collect(),
+    };
+    let packer = Packer::new(1 << 20, 0);
+    packer.pack_vertex(&mut vtx).unwrap();
+    let b = packer.take_bytes();
+
+    let mut group = c.benchmark_group("vertex_unpack");
+    group.bench_function("packer", |bench| {
+        bench.iter(|| {
+            let packer = Packer::load_bytes_for_unpack(b.len(), black_box(&b));
+            packer.unpack_vertex().unwrap()
+        })
+    });
+    group.bench_function("packer_reader", |bench| {
+        bench.iter(|| PackerReader::new(black_box(&b)).unpack_vertex().unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_utxo_unpack, bench_vertex_unpack);
+criterion_main!(benches);
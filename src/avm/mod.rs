@@ -1 +1,3 @@
+pub mod nftfx;
+pub mod propertyfx;
 pub mod txs;
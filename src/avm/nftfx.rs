@@ -0,0 +1,510 @@
+//! Non-fungible token feature extension ("nftfx") of the X-chain.
+use std::cmp::Ordering;
+
+use crate::{
+    codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    errors::{Error, Result},
+    ids, impl_linear_codec, key,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Maximum size of the NFT payload in bytes.
+/// ref. "avalanchego/vms/nftfx.MaxPayloadSize"
+pub const MAX_PAYLOAD_SIZE: usize = 1 << 10;
+
+/// Returns the ID of the nftfx feature extension.
+/// ref. "avalanchego/vms/nftfx.ID"
+pub fn fx_id() -> ids::Id {
+    ids::Id::from_slice(b"nftfx")
+}
+
+fn verify_payload(payload: &[u8]) -> Result<()> {
+    if payload.len() > MAX_PAYLOAD_SIZE {
+        return Err(Error::Other {
+            message: format!(
+                "payload too large ({} > {})", // ref. "errPayloadTooLarge"
+                payload.len(),
+                MAX_PAYLOAD_SIZE
+            ),
+            retryable: false,
+        });
+    }
+    Ok(())
+}
+
+/// Represents the right to mint the NFTs of the group, owned by the "output_owners".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOutput>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct MintOutput {
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for MintOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOutput {
+    pub fn default() -> Self {
+        Self {
+            group_id: 0,
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(group_id: u32, output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self {
+            group_id,
+            output_owners,
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.MintOutput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.output_owners.verify_syntax()
+    }
+}
+
+// "nftfx.MintOutput.OutputOwners" is embedded inline
+impl_linear_codec!(
+    MintOutput,
+    interface {
+        group_id,
+        output_owners,
+    }
+);
+
+impl Ord for MintOutput {
+    fn cmp(&self, other: &MintOutput) -> Ordering {
+        self.group_id
+            .cmp(&other.group_id) // returns when "group_id"s are not Equal
+            .then_with(
+                || self.output_owners.cmp(&other.output_owners), // if "group_id"s are Equal, compare "output_owners"
+            )
+    }
+}
+
+impl PartialOrd for MintOutput {
+    fn partial_cmp(&self, other: &MintOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for MintOutput {
+    fn eq(&self, other: &MintOutput) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Represents the NFT of the group with its payload, owned by the "output_owners".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOutput>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct TransferOutput {
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+    #[serde_as(as = "Hex0xBytes")]
+    pub payload: Vec<u8>,
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for TransferOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl TransferOutput {
+    pub fn default() -> Self {
+        Self {
+            group_id: 0,
+            payload: Vec::new(),
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(
+        group_id: u32,
+        payload: Vec<u8>,
+        output_owners: key::secp256k1::txs::OutputOwners,
+    ) -> Self {
+        Self {
+            group_id,
+            payload,
+            output_owners,
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.TransferOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.TransferOutput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        verify_payload(&self.payload)?;
+        self.output_owners.verify_syntax()
+    }
+}
+
+// "nftfx.TransferOutput.OutputOwners" is embedded inline
+impl_linear_codec!(
+    TransferOutput,
+    interface {
+        group_id,
+        payload,
+        output_owners,
+    }
+);
+
+impl Ord for TransferOutput {
+    fn cmp(&self, other: &TransferOutput) -> Ordering {
+        self.group_id
+            .cmp(&other.group_id)
+            .then_with(|| self.payload.cmp(&other.payload))
+            .then_with(|| self.output_owners.cmp(&other.output_owners))
+    }
+}
+
+impl PartialOrd for TransferOutput {
+    fn partial_cmp(&self, other: &TransferOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for TransferOutput {
+    fn eq(&self, other: &TransferOutput) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Consumes the minting right of the group to mint the NFTs
+/// with the same payload, one for each of the "outputs".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOperation>
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct MintOperation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "groupID")]
+    pub group_id: u32,
+    #[serde_as(as = "Hex0xBytes")]
+    pub payload: Vec<u8>,
+    pub outputs: Vec<key::secp256k1::txs::OutputOwners>,
+}
+
+impl Default for MintOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOperation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            group_id: 0,
+            payload: Vec::new(),
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.MintOperation.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        verify_payload(&self.payload)?;
+        for output in self.outputs.iter() {
+            output.verify_syntax()?;
+        }
+        self.mint_input.verify_syntax()
+    }
+
+    /// Returns the NFTs produced by the operation.
+    /// ref. "avalanchego/vms/nftfx.MintOperation.Outs"
+    pub fn outs(&self) -> Vec<TransferOutput> {
+        self.outputs
+            .iter()
+            .map(|owners| TransferOutput::new(self.group_id, self.payload.clone(), owners.clone()))
+            .collect()
+    }
+}
+
+// "MintOperation.Outputs" are the pointers to the structs
+// thus embedded inline without the type IDs
+impl_linear_codec!(
+    MintOperation,
+    interface {
+        mint_input,
+        group_id,
+        payload,
+        outputs,
+    }
+);
+
+/// Consumes the NFT to transfer it to the new owners in the "output".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct TransferOperation {
+    pub input: key::secp256k1::txs::Input,
+    pub output: TransferOutput,
+}
+
+impl Default for TransferOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl TransferOperation {
+    pub fn default() -> Self {
+        Self {
+            input: key::secp256k1::txs::Input::default(),
+            output: TransferOutput::default(),
+        }
+    }
+
+    pub fn new(input: key::secp256k1::txs::Input, output: TransferOutput) -> Self {
+        Self { input, output }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.TransferOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/nftfx.TransferOperation.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.input.verify_syntax()?;
+        self.output.verify_syntax()
+    }
+}
+
+// all fields are structs thus embedded inline without the type IDs
+impl_linear_codec!(TransferOperation, interface { input, output });
+
+/// Signatures to spend the nftfx outputs, which embeds "secp256k1fx.Credential"
+/// but registered with its own type ID.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#Credential>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Credential {
+    #[serde(flatten)]
+    pub cred: key::secp256k1::txs::Credential,
+}
+
+impl Default for Credential {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Credential {
+    pub fn default() -> Self {
+        Self {
+            cred: key::secp256k1::txs::Credential::default(),
+        }
+    }
+
+    pub fn new(cred: key::secp256k1::txs::Credential) -> Self {
+        Self { cred }
+    }
+
+    pub fn type_name() -> String {
+        "nftfx.Credential".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+// "nftfx.Credential.Credential" is embedded inline
+impl_linear_codec!(Credential, interface { cred });
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::nftfx::test_nftfx_outputs_codec --exact --show-output
+#[test]
+fn test_nftfx_outputs_codec() {
+    use crate::{ids::short, packer};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xaa; 20])]);
+
+    let mint_output = MintOutput::new(3, owners.clone());
+    assert_eq!(MintOutput::type_id(), 10);
+    assert!(mint_output.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&mint_output, &packer).unwrap();
+    let packed = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        // nftfx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x0a, //
+        // group ID
+        0x00, 0x00, 0x00, 0x03, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xaa; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: MintOutput = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(mint_output, unpacked);
+
+    let transfer_output = TransferOutput::new(3, vec![0x01, 0x02], owners);
+    assert_eq!(TransferOutput::type_id(), 11);
+    assert!(transfer_output.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&transfer_output, &packer).unwrap();
+    let packed = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        // nftfx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x0b, //
+        // group ID
+        0x00, 0x00, 0x00, 0x03, //
+        // payload length
+        0x00, 0x00, 0x00, 0x02, //
+        // payload
+        0x01, 0x02, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xaa; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: TransferOutput = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(transfer_output, unpacked);
+
+    let json_encoded = serde_json::to_string(&transfer_output).unwrap();
+    assert!(json_encoded.contains("\"payload\":\"0x0102\""));
+    let json_decoded: TransferOutput = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(transfer_output, json_decoded);
+
+    let mut too_large = transfer_output.clone();
+    too_large.payload = vec![0; MAX_PAYLOAD_SIZE + 1];
+    assert!(too_large.verify_syntax().is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::nftfx::test_nftfx_operations_codec --exact --show-output
+#[test]
+fn test_nftfx_operations_codec() {
+    use crate::{ids::short, packer};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xbb; 20])]);
+
+    let mint_op = MintOperation {
+        mint_input: key::secp256k1::txs::Input::new(vec![0]),
+        group_id: 1,
+        payload: vec![0xff],
+        outputs: vec![owners.clone()],
+    };
+    assert_eq!(MintOperation::type_id(), 12);
+    assert!(mint_op.verify_syntax().is_ok());
+    assert_eq!(
+        mint_op.outs(),
+        vec![TransferOutput::new(1, vec![0xff], owners.clone())]
+    );
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&mint_op, &packer).unwrap();
+    let packed = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        // nftfx.MintOperation type ID
+        0x00, 0x00, 0x00, 0x0c, //
+        // number of signature indices
+        0x00, 0x00, 0x00, 0x01, //
+        // signature index
+        0x00, 0x00, 0x00, 0x00, //
+        // group ID
+        0x00, 0x00, 0x00, 0x01, //
+        // payload length
+        0x00, 0x00, 0x00, 0x01, //
+        // payload
+        0xff, //
+        // number of outputs
+        0x00, 0x00, 0x00, 0x01, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xbb; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: MintOperation = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(mint_op, unpacked);
+
+    let transfer_op = TransferOperation::new(
+        key::secp256k1::txs::Input::new(vec![0]),
+        TransferOutput::new(1, vec![0xff], owners),
+    );
+    assert_eq!(TransferOperation::type_id(), 13);
+    assert!(transfer_op.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&transfer_op, &packer).unwrap();
+    let packed = packer.take_bytes();
+    // type ID, then the input and the output inline without the type IDs
+    assert_eq!(&packed[..4], &[0x00, 0x00, 0x00, 0x0d]);
+    assert_eq!(&packed[12..16], &[0x00, 0x00, 0x00, 0x01]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: TransferOperation = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(transfer_op, unpacked);
+
+    let cred = Credential::new(key::secp256k1::txs::Credential::new(vec![vec![0x01; 65]]));
+    assert_eq!(Credential::type_id(), 14);
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&cred, &packer).unwrap();
+    let packed = packer.take_bytes();
+    assert_eq!(packed.len(), 4 + 4 + 65);
+    assert_eq!(
+        &packed[..8],
+        &[0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x01]
+    );
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: Credential = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(cred, unpacked);
+
+    let mut invalid = mint_op.clone();
+    invalid.payload = vec![0; MAX_PAYLOAD_SIZE + 1];
+    assert!(invalid.verify_syntax().is_err());
+}
@@ -0,0 +1,364 @@
+//! Property feature extension ("propertyfx") of the X-chain.
+use std::cmp::Ordering;
+
+use crate::{codec, errors::Result, ids, impl_linear_codec, key};
+use serde::{Deserialize, Serialize};
+
+/// Returns the ID of the propertyfx feature extension.
+/// ref. "avalanchego/vms/propertyfx.ID"
+pub fn fx_id() -> ids::Id {
+    ids::Id::from_slice(b"propertyfx")
+}
+
+/// Represents the right to mint the property, owned by the "output_owners".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOutput>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct MintOutput {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for MintOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOutput {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/propertyfx.MintOutput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.output_owners.verify_syntax()
+    }
+}
+
+// "propertyfx.MintOutput.OutputOwners" is embedded inline
+impl_linear_codec!(MintOutput, interface { output_owners });
+
+impl Ord for MintOutput {
+    fn cmp(&self, other: &MintOutput) -> Ordering {
+        self.output_owners.cmp(&(other.output_owners))
+    }
+}
+
+impl PartialOrd for MintOutput {
+    fn partial_cmp(&self, other: &MintOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for MintOutput {
+    fn eq(&self, other: &MintOutput) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Represents the ownership of the property, owned by the "output_owners".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#OwnedOutput>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct OwnedOutput {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for OwnedOutput {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl OwnedOutput {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.OwnedOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/propertyfx.OwnedOutput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.output_owners.verify_syntax()
+    }
+}
+
+// "propertyfx.OwnedOutput.OutputOwners" is embedded inline
+impl_linear_codec!(OwnedOutput, interface { output_owners });
+
+impl Ord for OwnedOutput {
+    fn cmp(&self, other: &OwnedOutput) -> Ordering {
+        self.output_owners.cmp(&(other.output_owners))
+    }
+}
+
+impl PartialOrd for OwnedOutput {
+    fn partial_cmp(&self, other: &OwnedOutput) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OwnedOutput {
+    fn eq(&self, other: &OwnedOutput) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Consumes the minting right to produce the "owned_output",
+/// while producing the "mint_output" to keep the minting right.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct MintOperation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "mintOutput")]
+    pub mint_output: MintOutput,
+    #[serde(rename = "ownedOutput")]
+    pub owned_output: OwnedOutput,
+}
+
+impl Default for MintOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl MintOperation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            mint_output: MintOutput::default(),
+            owned_output: OwnedOutput::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/propertyfx.MintOperation.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.mint_input.verify_syntax()?;
+        self.mint_output.verify_syntax()?;
+        self.owned_output.verify_syntax()
+    }
+}
+
+// all fields are structs thus embedded inline without the type IDs
+impl_linear_codec!(
+    MintOperation,
+    interface {
+        mint_input,
+        mint_output,
+        owned_output,
+    }
+);
+
+/// Consumes the "propertyfx.OwnedOutput" without producing any output.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#BurnOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct BurnOperation {
+    /// The custom de/serializer embeds "input" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub input: key::secp256k1::txs::Input,
+}
+
+impl Default for BurnOperation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl BurnOperation {
+    pub fn default() -> Self {
+        Self {
+            input: key::secp256k1::txs::Input::default(),
+        }
+    }
+
+    pub fn new(input: key::secp256k1::txs::Input) -> Self {
+        Self { input }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.BurnOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/propertyfx.BurnOperation.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.input.verify_syntax()
+    }
+}
+
+// "propertyfx.BurnOperation.Input" is embedded inline
+impl_linear_codec!(BurnOperation, interface { input });
+
+/// Signatures to spend the propertyfx outputs, which embeds "secp256k1fx.Credential"
+/// but registered with its own type ID.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#Credential>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Credential {
+    #[serde(flatten)]
+    pub cred: key::secp256k1::txs::Credential,
+}
+
+impl Default for Credential {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Credential {
+    pub fn default() -> Self {
+        Self {
+            cred: key::secp256k1::txs::Credential::default(),
+        }
+    }
+
+    pub fn new(cred: key::secp256k1::txs::Credential) -> Self {
+        Self { cred }
+    }
+
+    pub fn type_name() -> String {
+        "propertyfx.Credential".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+// "propertyfx.Credential.Credential" is embedded inline
+impl_linear_codec!(Credential, interface { cred });
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::propertyfx::test_propertyfx_codec --exact --show-output
+#[test]
+fn test_propertyfx_codec() {
+    use crate::{ids::short, packer};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xcc; 20])]);
+
+    let mint_op = MintOperation {
+        mint_input: key::secp256k1::txs::Input::new(vec![0]),
+        mint_output: MintOutput::new(owners.clone()),
+        owned_output: OwnedOutput::new(owners.clone()),
+    };
+    assert_eq!(MintOutput::type_id(), 15);
+    assert_eq!(OwnedOutput::type_id(), 16);
+    assert_eq!(MintOperation::type_id(), 17);
+    assert!(mint_op.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&mint_op, &packer).unwrap();
+    let packed = packer.take_bytes();
+    let mut expected: Vec<u8> = vec![
+        // propertyfx.MintOperation type ID
+        0x00, 0x00, 0x00, 0x11, //
+        // number of signature indices
+        0x00, 0x00, 0x00, 0x01, //
+        // signature index
+        0x00, 0x00, 0x00, 0x00, //
+        // mint output locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // mint output threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xcc; 20]);
+    expected.extend_from_slice(&[
+        // owned output locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // owned output threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected.extend_from_slice(&[0xcc; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: MintOperation = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(mint_op, unpacked);
+
+    let owned_output = OwnedOutput::new(owners);
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&owned_output, &packer).unwrap();
+    let packed = packer.take_bytes();
+    assert_eq!(&packed[..4], &[0x00, 0x00, 0x00, 0x10]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: OwnedOutput = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(owned_output, unpacked);
+
+    let burn_op = BurnOperation::new(key::secp256k1::txs::Input::new(vec![1]));
+    assert_eq!(BurnOperation::type_id(), 18);
+    assert!(burn_op.verify_syntax().is_ok());
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&burn_op, &packer).unwrap();
+    let packed = packer.take_bytes();
+    assert_eq!(
+        &packed[..],
+        &[
+            0x00, 0x00, 0x00, 0x12, // propertyfx.BurnOperation type ID
+            0x00, 0x00, 0x00, 0x01, // number of signature indices
+            0x00, 0x00, 0x00, 0x01, // signature index
+        ]
+    );
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: BurnOperation = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(burn_op, unpacked);
+
+    let json_encoded = serde_json::to_string(&burn_op).unwrap();
+    let json_decoded: BurnOperation = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(burn_op, json_decoded);
+
+    assert_eq!(Credential::type_id(), 19);
+    let cred = Credential::new(key::secp256k1::txs::Credential::new(vec![vec![0x02; 65]]));
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&cred, &packer).unwrap();
+    let packed = packer.take_bytes();
+    assert_eq!(&packed[..4], &[0x00, 0x00, 0x00, 0x13]);
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: Credential = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(cred, unpacked);
+
+    let mut invalid = mint_op.clone();
+    invalid.owned_output.output_owners.threshold = 2;
+    assert!(invalid.verify_syntax().is_err());
+}
@@ -0,0 +1,493 @@
+use std::cmp::Ordering;
+
+use crate::{
+    avm::{self, txs::fx},
+    codec::{self, linear::Unpack},
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, packer, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// ref. "avalanchego/vms/avm/txs.maxNameLen"
+pub const MAX_NAME_LEN: usize = 128;
+/// ref. "avalanchego/vms/avm/txs.maxSymbolLen"
+pub const MAX_SYMBOL_LEN: usize = 4;
+/// ref. "avalanchego/vms/avm/txs.maxDenomination"
+pub const MAX_DENOMINATION: u8 = 32;
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx>
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#CreateAssetTx>
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub name: String,
+    pub symbol: String,
+    pub denomination: u8,
+    pub states: Vec<InitialState>,
+    pub fx_creds: Vec<fx::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            name: String::new(),
+            symbol: String::new(),
+            denomination: 0,
+            states: Vec::new(),
+            fx_creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "avm.CreateAssetTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    /// The transaction ID is also the asset ID of the new asset.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    /// Verifies the asset description and the initial states.
+    /// ref. "avalanchego/vms/avm/txs/executor.SyntacticVerifier.CreateAssetTx"
+    pub fn verify_syntax(&self) -> Result<()> {
+        let err = if self.name.is_empty() {
+            Some("name is too short") // ref. "errNameTooShort"
+        } else if self.name.len() > MAX_NAME_LEN {
+            Some("name is too long") // ref. "errNameTooLong"
+        } else if self.symbol.len() > MAX_SYMBOL_LEN {
+            Some("symbol is too long") // ref. "errSymbolTooLong"
+        } else if self.states.is_empty() {
+            Some("assets must support at least one Fx") // ref. "errNoFxs"
+        } else if self.denomination > MAX_DENOMINATION {
+            Some("denomination is too large") // ref. "errDenominationTooLarge"
+        } else if self.name.trim() != self.name {
+            Some("unexpected whitespace provided") // ref. "errUnexpectedWhitespace"
+        } else if !self
+            .name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ')
+        {
+            Some("asset's name must be made up of only letters and numbers")
+        // ref. "errIllegalNameCharacter"
+        } else if !self.symbol.chars().all(|c| c.is_ascii_uppercase()) {
+            Some("asset's symbol must be all upper case letters") // ref. "errIllegalSymbolCharacter"
+        } else {
+            None
+        };
+        if let Some(message) = err {
+            return Err(Error::Other {
+                message: message.to_string(),
+                retryable: false,
+            });
+        }
+
+        self.base_tx.verify_syntax()?;
+        for state in self.states.iter() {
+            state.verify_syntax()?;
+        }
+        if !cmp_manager::is_sorted_and_unique(&self.states) {
+            return Err(Error::Other {
+                message: "initial states not sorted and unique".to_string(), // ref. "errInitialStatesNotSortedUnique"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let (fx_creds, metadata) = avm::txs::sign_tx(Self::type_id(), self, signers, &[]).await?;
+        self.fx_creds = fx_creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "avm.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, fx_creds, metadata) = avm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.fx_creds = fx_creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        name,
+        symbol,
+        denomination,
+        states,
+    }
+);
+
+/// Represents the initial outputs of the asset for the feature extension.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#InitialState>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct InitialState {
+    /// Index of the feature extension (e.g., "fx::SECP256K1_FX_INDEX").
+    pub fx_index: u32,
+    pub outputs: Vec<Output>,
+}
+
+impl Default for InitialState {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl InitialState {
+    pub fn default() -> Self {
+        Self {
+            fx_index: 0,
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn new(fx_index: u32, outputs: Vec<Output>) -> Self {
+        Self { fx_index, outputs }
+    }
+
+    /// ref. "avalanchego/vms/avm/txs.InitialState.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        for output in self.outputs.iter() {
+            if output.fx_index() != self.fx_index {
+                return Err(Error::Other {
+                    message: format!(
+                        "output of fx index {} in the initial state of fx index {}",
+                        output.fx_index(),
+                        self.fx_index
+                    ),
+                    retryable: false,
+                });
+            }
+            output.verify_syntax()?;
+        }
+        if !self.outputs.windows(2).all(|w| w[0] <= w[1]) {
+            return Err(Error::Other {
+                message: "outputs not sorted".to_string(), // ref. "errOutputsNotSorted"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+}
+
+// "InitialState.Outs" is a slice of "verify.State" interfaces
+impl_linear_codec!(InitialState { fx_index, outputs });
+
+/// ref. "avalanchego/vms/avm/txs.InitialState.Less"
+impl Ord for InitialState {
+    fn cmp(&self, other: &InitialState) -> Ordering {
+        self.fx_index.cmp(&(other.fx_index))
+    }
+}
+
+impl PartialOrd for InitialState {
+    fn partial_cmp(&self, other: &InitialState) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for InitialState {
+    fn eq(&self, other: &InitialState) -> bool {
+        self.fx_index == other.fx_index && self.outputs == other.outputs
+    }
+}
+
+/// Represents the feature extension output in the initial state,
+/// which is "verify.State" interface in avalanchego.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/verify#State>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub enum Output {
+    /// "*secp256k1fx.TransferOutput" for the initial supply.
+    Secp256k1Transfer(key::secp256k1::txs::transfer::Output),
+    /// "*secp256k1fx.MintOutput" for the minting right.
+    Secp256k1Mint(key::secp256k1::txs::mint::Output),
+    /// "*nftfx.MintOutput" for the minting right of the NFT group.
+    NftMint(avm::nftfx::MintOutput),
+    /// "*nftfx.TransferOutput" for the initial NFT.
+    NftTransfer(avm::nftfx::TransferOutput),
+    /// "*propertyfx.MintOutput" for the minting right of the property.
+    PropertyMint(avm::propertyfx::MintOutput),
+    /// "*propertyfx.OwnedOutput" for the initial property.
+    PropertyOwned(avm::propertyfx::OwnedOutput),
+}
+
+impl Output {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Output::Secp256k1Transfer(_) => key::secp256k1::txs::transfer::Output::type_id(),
+            Output::Secp256k1Mint(_) => key::secp256k1::txs::mint::Output::type_id(),
+            Output::NftMint(_) => avm::nftfx::MintOutput::type_id(),
+            Output::NftTransfer(_) => avm::nftfx::TransferOutput::type_id(),
+            Output::PropertyMint(_) => avm::propertyfx::MintOutput::type_id(),
+            Output::PropertyOwned(_) => avm::propertyfx::OwnedOutput::type_id(),
+        }
+    }
+
+    /// Returns the index of the feature extension that owns the output.
+    pub fn fx_index(&self) -> u32 {
+        match self {
+            Output::Secp256k1Transfer(_) | Output::Secp256k1Mint(_) => fx::SECP256K1_FX_INDEX,
+            Output::NftMint(_) | Output::NftTransfer(_) => fx::NFT_FX_INDEX,
+            Output::PropertyMint(_) | Output::PropertyOwned(_) => fx::PROPERTY_FX_INDEX,
+        }
+    }
+
+    pub fn verify_syntax(&self) -> Result<()> {
+        match self {
+            Output::Secp256k1Transfer(out) => out.verify_syntax(),
+            Output::Secp256k1Mint(out) => out.verify_syntax(),
+            Output::NftMint(out) => out.verify_syntax(),
+            Output::NftTransfer(out) => out.verify_syntax(),
+            Output::PropertyMint(out) => out.verify_syntax(),
+            Output::PropertyOwned(out) => out.verify_syntax(),
+        }
+    }
+
+    /// Returns the packed bytes with the type ID prefix.
+    fn bytes(&self) -> Vec<u8> {
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        codec::linear::Pack::pack_to(self, &packer).expect("failed to pack output");
+        packer.take_bytes().to_vec()
+    }
+}
+
+impl codec::linear::Pack for Output {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        match self {
+            Output::Secp256k1Transfer(out) => codec::linear::pack_interface(out, packer),
+            Output::Secp256k1Mint(out) => codec::linear::pack_interface(out, packer),
+            Output::NftMint(out) => codec::linear::pack_interface(out, packer),
+            Output::NftTransfer(out) => codec::linear::pack_interface(out, packer),
+            Output::PropertyMint(out) => codec::linear::pack_interface(out, packer),
+            Output::PropertyOwned(out) => codec::linear::pack_interface(out, packer),
+        }
+    }
+}
+
+impl codec::linear::Unpack for Output {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if codec::linear::is_type::<key::secp256k1::txs::transfer::Output>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(Output::Secp256k1Transfer(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<key::secp256k1::txs::mint::Output>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(Output::Secp256k1Mint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::nftfx::MintOutput>(packer.registry(), type_id) {
+            Ok(Output::NftMint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::nftfx::TransferOutput>(packer.registry(), type_id) {
+            Ok(Output::NftTransfer(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::propertyfx::MintOutput>(packer.registry(), type_id)
+        {
+            Ok(Output::PropertyMint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::propertyfx::OwnedOutput>(packer.registry(), type_id)
+        {
+            Ok(Output::PropertyOwned(Unpack::unpack_from(packer)?))
+        } else {
+            Err(Error::Other {
+                message: format!("unexpected type ID {} for InitialState output", type_id),
+                retryable: false,
+            })
+        }
+    }
+}
+
+/// Sorts by the marshaled bytes, thus by the type ID first.
+/// ref. "avalanchego/vms/avm/txs.innerSortState.Less"
+impl Ord for Output {
+    fn cmp(&self, other: &Output) -> Ordering {
+        self.bytes().cmp(&other.bytes())
+    }
+}
+
+impl PartialOrd for Output {
+    fn partial_cmp(&self, other: &Output) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Output {
+    fn eq(&self, other: &Output) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::create_asset::test_create_asset_tx_serialization --exact --show-output
+#[test]
+fn test_create_asset_tx_serialization() {
+    use crate::ids::short;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xfc; 20])]);
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 2,
+            blockchain_id: ids::Id::from_slice(&[0xff; 32]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x0f; 32]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[0x1f; 32]),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        name: "Volatility Index".to_string(),
+        symbol: "VIX".to_string(),
+        denomination: 2,
+        states: vec![InitialState::new(
+            fx::SECP256K1_FX_INDEX,
+            vec![
+                Output::Secp256k1Mint(key::secp256k1::txs::mint::Output::new(owners.clone())),
+                Output::Secp256k1Transfer(key::secp256k1::txs::transfer::Output::new(
+                    12345,
+                    owners.clone(),
+                )),
+            ],
+        )],
+        ..Tx::default()
+    };
+    assert!(tx.verify_syntax().is_ok());
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    ab!(tx.sign(vec![vec![test_key.clone()]])).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+
+    let mut expected: Vec<u8> = vec![
+        // name
+        0x00, 0x10, //
+        b'V', b'o', b'l', b'a', b't', b'i', b'l', b'i', //
+        b't', b'y', b' ', b'I', b'n', b'd', b'e', b'x', //
+        // symbol
+        0x00, 0x03, b'V', b'I', b'X', //
+        // denomination
+        0x02, //
+        // number of initial states
+        0x00, 0x00, 0x00, 0x01, //
+        // fx index
+        0x00, 0x00, 0x00, 0x00, //
+        // number of outputs
+        0x00, 0x00, 0x00, 0x02, //
+        // secp256k1fx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x06, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xfc; 20]);
+    expected.extend_from_slice(&[
+        // secp256k1fx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x07, //
+        // amount
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected.extend_from_slice(&[0xfc; 20]);
+
+    // the unsigned tx ends with the asset description and the initial states
+    let unsigned = &tx_metadata.tx_bytes_with_no_signature;
+    assert_eq!(&unsigned[..6], &[0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+    assert!(unsigned.ends_with(&expected));
+
+    let parsed = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed, tx);
+    assert_eq!(parsed.tx_id(), tx.tx_id());
+    let parsed =
+        crate::avm::txs::Transaction::unpack(&tx_metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed.type_id(), Tx::type_id());
+    assert_eq!(parsed.tx_id(), tx.tx_id());
+
+    // mint outputs are sorted ahead of the transfer outputs by the type ID
+    let mut unsorted = tx.clone();
+    unsorted.states[0].outputs.reverse();
+    assert!(unsorted.verify_syntax().is_err());
+
+    for (name, symbol, denomination) in [
+        ("", "VIX", 2),
+        (" Volatility Index", "VIX", 2),
+        ("Volatility-Index", "VIX", 2),
+        ("Volatility Index", "VIXES", 2),
+        ("Volatility Index", "vix", 2),
+        ("Volatility Index", "VIX", 33),
+    ] {
+        let mut invalid = tx.clone();
+        invalid.name = name.to_string();
+        invalid.symbol = symbol.to_string();
+        invalid.denomination = denomination;
+        assert!(invalid.verify_syntax().is_err());
+    }
+}
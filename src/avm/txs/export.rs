@@ -1,8 +1,8 @@
 use crate::{
-    avm::txs::fx,
+    avm::{self, txs::fx},
     codec,
-    errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    errors::Result,
+    ids, impl_linear_codec, key, platformvm, txs,
 };
 use serde::{Deserialize, Serialize};
 
@@ -70,200 +70,39 @@ impl Tx {
     ///
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let b = packer.take_bytes();
-        packer.set_bytes(&b);
-
-        // pack the second field in the struct
-        packer.pack_bytes(self.destination_chain_id.as_ref())?;
-
-        // pack the third field in the struct
-        if self.destination_chain_transferable_outputs.is_some() {
-            let destination_chain_transferable_outputs = self
-                .destination_chain_transferable_outputs
-                .as_ref()
-                .unwrap();
-            packer.pack_u32(destination_chain_transferable_outputs.len() as u32)?;
-
-            for transferable_output in destination_chain_transferable_outputs.iter() {
-                // "TransferableOutput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableOutput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                if transferable_output.transfer_output.is_none()
-                    && transferable_output.stakeable_lock_out.is_none()
-                {
-                    return Err(Error::Other {
-                        message:  "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_out = {
-                    if transferable_output.transfer_output.is_some() {
-                        key::secp256k1::txs::transfer::Output::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockOut::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(type_id_transferable_out)?;
-
-                match type_id_transferable_out {
-                    7 => {
-                        // "key::secp256k1::txs::transfer::Output"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(transfer_output.amount)?;
-
-                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferOutput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        packer.pack_u64(transfer_output.output_owners.locktime)?;
-                        packer.pack_u32(transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
-                        for addr in transfer_output.output_owners.addresses.iter() {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    22 => {
-                        // "platformvm::txs::StakeableLockOut"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        let stakeable_lock_out =
-                            transferable_output.stakeable_lock_out.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                        packer.pack_u64(stakeable_lock_out.locktime)?;
-
-                        // secp256k1fx.TransferOutput type ID
-                        packer.pack_u32(7)?;
-
-                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockOut"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        //
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
-                        packer
-                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
-                        packer
-                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(
-                            stakeable_lock_out
-                                .transfer_output
-                                .output_owners
-                                .addresses
-                                .len() as u32,
-                        )?;
-                        for addr in stakeable_lock_out
-                            .transfer_output
-                            .output_owners
-                            .addresses
-                            .iter()
-                        {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableOutput",
-                                type_id_transferable_out
-                            ),
-                            retryable: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            packer.pack_u32(0_u32)?;
-        }
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.ExportTx" unsigned tx
-        // not other fields -- only hash "avm.ExportTx.*" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#ExportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(fx_creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            let mut fx_cred = fx::Credential::default();
-            fx_cred.cred = cred;
-
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
-        }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+        let (fx_creds, metadata) = avm::txs::sign_tx(Self::type_id(), self, signers, &[]).await?;
+        self.fx_creds = fx_creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    /// Parses the signed tx bytes (e.g., "avm.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, fx_creds, metadata) = avm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.fx_creds = fx_creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        Ok(())
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        destination_chain_id,
+        destination_chain_transferable_outputs,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::export::test_export_tx_serialization_with_two_signers --exact --show-output
 /// ref. "avalanchego/vms/avm.TestExportTxSerialization"
 #[test]
@@ -465,4 +304,13 @@ fn test_export_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+
+    let parsed = crate::avm::txs::Transaction::unpack(&tx_bytes_with_signatures)
+        .expect("failed Transaction::unpack");
+    assert_eq!(parsed.type_id(), Tx::type_id());
+    assert_eq!(parsed, crate::avm::txs::Transaction::Export(tx));
 }
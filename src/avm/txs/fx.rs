@@ -1,6 +1,21 @@
-use crate::{ids, key};
+use crate::{
+    avm, codec,
+    errors::{Error, Result},
+    ids, key, packer,
+};
 use serde::{Deserialize, Serialize};
 
+/// Index of "secp256k1fx" in the X-chain feature extensions,
+/// in the order of the fxs registered with the VM.
+/// ref. "avalanchego/vms/avm.VM.Initialize"
+pub const SECP256K1_FX_INDEX: u32 = 0;
+
+/// Index of "nftfx" in the X-chain feature extensions.
+pub const NFT_FX_INDEX: u32 = 1;
+
+/// Index of "propertyfx" in the X-chain feature extensions.
+pub const PROPERTY_FX_INDEX: u32 = 2;
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#FxCredential>
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Credential {
@@ -22,3 +37,54 @@ impl Credential {
         }
     }
 }
+
+/// "FxCredential.Credential" is an interface thus prefixed with the type ID,
+/// and the "fx_id" is serialize:"false" thus left empty as in the signing path.
+/// The "nftfx" and "propertyfx" credentials are the same signatures with
+/// their own type IDs, thus packed as such only when the "fx_id" matches.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx>
+impl codec::linear::Pack for Credential {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        if self.fx_id == avm::nftfx::fx_id() {
+            let cred = avm::nftfx::Credential::new(self.cred.clone());
+            return codec::linear::pack_interface(&cred, packer);
+        }
+        if self.fx_id == avm::propertyfx::fx_id() {
+            let cred = avm::propertyfx::Credential::new(self.cred.clone());
+            return codec::linear::pack_interface(&cred, packer);
+        }
+        codec::linear::pack_interface(&self.cred, packer)
+    }
+}
+
+impl codec::linear::Unpack for Credential {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if codec::linear::is_type::<key::secp256k1::txs::Credential>(packer.registry(), type_id) {
+            let cred = codec::linear::Unpack::unpack_from(packer)?;
+            Ok(Self {
+                cred,
+                ..Self::default()
+            })
+        } else if codec::linear::is_type::<avm::nftfx::Credential>(packer.registry(), type_id) {
+            let nft_cred: avm::nftfx::Credential = codec::linear::Unpack::unpack_from(packer)?;
+            Ok(Self {
+                fx_id: avm::nftfx::fx_id(),
+                cred: nft_cred.cred,
+            })
+        } else if codec::linear::is_type::<avm::propertyfx::Credential>(packer.registry(), type_id)
+        {
+            let property_cred: avm::propertyfx::Credential =
+                codec::linear::Unpack::unpack_from(packer)?;
+            Ok(Self {
+                fx_id: avm::propertyfx::fx_id(),
+                cred: property_cred.cred,
+            })
+        } else {
+            Err(Error::Other {
+                message: format!("unexpected type ID {} for FxCredential", type_id),
+                retryable: false,
+            })
+        }
+    }
+}
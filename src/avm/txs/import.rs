@@ -1,8 +1,8 @@
 use crate::{
-    avm::txs::fx,
+    avm::{self, txs::fx},
     codec,
-    errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    errors::Result,
+    ids, impl_linear_codec, key, platformvm, txs,
 };
 use serde::{Deserialize, Serialize};
 
@@ -67,193 +67,39 @@ impl Tx {
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let b = packer.take_bytes();
-        packer.set_bytes(&b);
-
-        // pack the second field in the struct
-        packer.pack_bytes(self.source_chain_id.as_ref())?;
-
-        // pack the third field in the struct
-        if self.source_chain_transferable_inputs.is_some() {
-            let source_chain_transferable_inputs =
-                self.source_chain_transferable_inputs.as_ref().unwrap();
-            packer.pack_u32(source_chain_transferable_inputs.len() as u32)?;
-
-            for transferable_input in source_chain_transferable_inputs.iter() {
-                // "TransferableInput.UTXOID" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXOID
-                packer.pack_bytes(transferable_input.utxo_id.tx_id.as_ref())?;
-                packer.pack_u32(transferable_input.utxo_id.output_index)?;
-
-                // "TransferableInput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_input.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                if transferable_input.transfer_input.is_none()
-                    && transferable_input.stakeable_lock_in.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_in = {
-                    if transferable_input.transfer_input.is_some() {
-                        key::secp256k1::txs::transfer::Input::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockIn::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Input" or "platformvm::txs::StakeableLockIn"
-                packer.pack_u32(type_id_transferable_in)?;
-
-                match type_id_transferable_in {
-                    5 => {
-                        // "key::secp256k1::txs::transfer::Input"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        let transfer_input = transferable_input.transfer_input.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(transfer_input.amount)?;
-
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer.pack_u32(transfer_input.sig_indices.len() as u32)?;
-                        for idx in transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    21 => {
-                        // "platformvm::txs::StakeableLockIn"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        let stakeable_lock_in =
-                            transferable_input.stakeable_lock_in.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockIn.locktime" field
-                        packer.pack_u64(stakeable_lock_in.locktime)?;
-
-                        // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockIn"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        //
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(stakeable_lock_in.transfer_input.amount)?;
-                        //
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer
-                            .pack_u32(stakeable_lock_in.transfer_input.sig_indices.len() as u32)?;
-                        for idx in stakeable_lock_in.transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableInput",
-                                type_id_transferable_in
-                            ),
-                            retryable: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            packer.pack_u32(0_u32)?;
-        }
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.ImportTx" unsigned tx
-        // not other fields -- only hash "avm.ImportTx.*" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#ImportTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(fx_creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            let mut fx_cred = fx::Credential::default();
-            fx_cred.cred = cred;
-
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
-        }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+        let (fx_creds, metadata) = avm::txs::sign_tx(Self::type_id(), self, signers, &[]).await?;
+        self.fx_creds = fx_creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    /// Parses the signed tx bytes (e.g., "avm.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, fx_creds, metadata) = avm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.fx_creds = fx_creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        Ok(())
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        source_chain_id,
+        source_chain_transferable_inputs,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::import::test_import_tx_serialization_with_two_signers --exact --show-output
 /// ref. "avalanchego/vms/avm.TestImportTxSerialization"
 #[test]
@@ -455,4 +301,13 @@ fn test_import_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+
+    let parsed = crate::avm::txs::Transaction::unpack(&tx_bytes_with_signatures)
+        .expect("failed Transaction::unpack");
+    assert_eq!(parsed.type_id(), Tx::type_id());
+    assert_eq!(parsed, crate::avm::txs::Transaction::Import(tx));
 }
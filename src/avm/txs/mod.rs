@@ -1,9 +1,19 @@
+pub mod create_asset;
 pub mod export;
 pub mod fx;
 pub mod import;
+pub mod json;
+pub mod operation;
 pub mod vertex;
 
-use crate::{codec, errors::Result, hash, ids, key, txs};
+use crate::{
+    codec::{
+        self,
+        linear::{Pack, Unpack},
+    },
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, packer, platformvm, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// Base transaction.
@@ -70,75 +80,178 @@ impl Tx {
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.Tx" unsigned tx
-        // not other fields -- only hash "avm.Tx.UnsignedTx" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the second field in the struct
-        packer.pack_u32(fx_creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            let mut fx_cred = fx::Credential::default();
-            fx_cred.cred = cred;
-
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
+        let (fx_creds, metadata) = sign_tx(Self::type_id(), self, signers, &[]).await?;
+        self.fx_creds = fx_creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "avm.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, fx_creds, metadata) = unpack_signed_tx::<Self>(d)?;
+        tx.fx_creds = fx_creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(Tx, interface { base_tx });
+
+/// Decodes the signed tx of the type through "codec::X_CODEC", and returns
+/// the unsigned tx with its credentials and the metadata of the given bytes.
+/// Fails if the codec version is unknown, the type ID is not the one of
+/// the type, or bytes are left after decoding.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+pub fn unpack_signed_tx<U: Unpack + codec::linear::Interface>(
+    d: &[u8],
+) -> Result<(U, Vec<fx::Credential>, txs::Metadata)> {
+    let (_, (unsigned_tx, fx_creds, unsigned_tx_len)) =
+        codec::X_CODEC.unmarshal_with(d, |registry, packer| {
+            registry.verify_type_id(packer.unpack_u32()?, &U::interface_type_name())?;
+            let unsigned_tx = U::unpack_from(packer)?;
+
+            // all bytes before credentials are the unsigned tx bytes
+            let unsigned_tx_len = packer.get_offset();
+            let fx_creds = unpack_fx_credentials(packer)?;
+            Ok((unsigned_tx, fx_creds, unsigned_tx_len))
+        })?;
+
+    let metadata = txs::Metadata::new(&d[..unsigned_tx_len], d);
+    Ok((unsigned_tx, fx_creds, metadata))
+}
+
+/// Unpacks the credentials that follow the unsigned tx bytes.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx>
+pub fn unpack_fx_credentials(packer: &packer::Packer) -> Result<Vec<fx::Credential>> {
+    Vec::<fx::Credential>::unpack_from(packer)
+}
+
+/// Packs the credentials that follow the unsigned tx bytes.
+pub fn pack_fx_credentials(packer: &packer::Packer, fx_creds: &[fx::Credential]) -> Result<()> {
+    packer.pack_u32(fx_creds.len() as u32)?;
+    for fx_cred in fx_creds.iter() {
+        fx_cred.pack_to(packer)?;
+    }
+    Ok(())
+}
+
+/// Signs the unsigned tx with the signers, and returns the "Tx.Creds"
+/// with the metadata computed from the signed tx bytes. Each credential
+/// takes the fx ID at the same index of "fx_ids", so that it is packed
+/// with the credential type of the fx (defaults to "secp256k1fx").
+/// ref. "avalanchego/vms/avm/txs.Tx.SignSECP256K1Fx"
+/// ref. "avalanchego/vms/avm/txs.Tx.SignNFTFx"
+/// ref. "avalanchego/vms/avm/txs.Tx.SignPropertyFx"
+pub async fn sign_tx<U: Pack, T: key::secp256k1::SignOnly>(
+    type_id: u32,
+    unsigned_tx: &U,
+    signers: Vec<Vec<T>>,
+    fx_ids: &[ids::Id],
+) -> Result<(Vec<fx::Credential>, txs::Metadata)> {
+    let creds = txs::sign_credentials(type_id, unsigned_tx, &signers).await?;
+    let fx_creds: Vec<fx::Credential> = creds
+        .into_iter()
+        .enumerate()
+        .map(|(i, cred)| fx::Credential {
+            fx_id: fx_ids.get(i).copied().unwrap_or_else(ids::Id::empty),
+            cred,
+        })
+        .collect();
+    let metadata = txs::Metadata::compute(type_id, unsigned_tx, |packer| {
+        pack_fx_credentials(packer, &fx_creds)
+    })?;
+    Ok((fx_creds, metadata))
+}
+
+/// Represents any of the signed X-chain transactions,
+/// decoded based on the unsigned tx type ID in "codec::X_TYPES".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum Transaction {
+    Base(Tx),
+    CreateAsset(create_asset::Tx),
+    Operation(operation::Tx),
+    Import(import::Tx),
+    Export(export::Tx),
+}
+
+impl Transaction {
+    /// Parses the signed tx bytes by peeking the unsigned tx type,
+    /// resolved with the registry of the codec version in "codec::X_CODEC".
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let type_name = codec::X_CODEC.type_name_of(d)?;
+
+        if type_name == Tx::type_name() {
+            Ok(Transaction::Base(Tx::unpack(d)?))
+        } else if type_name == create_asset::Tx::type_name() {
+            Ok(Transaction::CreateAsset(create_asset::Tx::unpack(d)?))
+        } else if type_name == operation::Tx::type_name() {
+            Ok(Transaction::Operation(operation::Tx::unpack(d)?))
+        } else if type_name == import::Tx::type_name() {
+            Ok(Transaction::Import(import::Tx::unpack(d)?))
+        } else if type_name == export::Tx::type_name() {
+            Ok(Transaction::Export(export::Tx::unpack(d)?))
+        } else {
+            Err(Error::Other {
+                message: format!("unknown or unsupported X-chain tx type {}", type_name),
+                retryable: false,
+            })
         }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
+    }
+
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Transaction::Base(_) => Tx::type_id(),
+            Transaction::CreateAsset(_) => create_asset::Tx::type_id(),
+            Transaction::Operation(_) => operation::Tx::type_id(),
+            Transaction::Import(_) => import::Tx::type_id(),
+            Transaction::Export(_) => export::Tx::type_id(),
         }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    }
 
-        Ok(())
+    pub fn tx_id(&self) -> ids::Id {
+        match self {
+            Transaction::Base(tx) => tx.tx_id(),
+            Transaction::CreateAsset(tx) => tx.tx_id(),
+            Transaction::Operation(tx) => tx.tx_id(),
+            Transaction::Import(tx) => tx.tx_id(),
+            Transaction::Export(tx) => tx.tx_id(),
+        }
+    }
+
+    pub fn base_tx(&self) -> &txs::Tx {
+        match self {
+            Transaction::Base(tx) => &tx.base_tx,
+            Transaction::CreateAsset(tx) => &tx.base_tx,
+            Transaction::Operation(tx) => &tx.base_tx,
+            Transaction::Import(tx) => &tx.base_tx,
+            Transaction::Export(tx) => &tx.base_tx,
+        }
+    }
+
+    pub fn fx_creds(&self) -> &[fx::Credential] {
+        match self {
+            Transaction::Base(tx) => &tx.fx_creds,
+            Transaction::CreateAsset(tx) => &tx.fx_creds,
+            Transaction::Operation(tx) => &tx.fx_creds,
+            Transaction::Import(tx) => &tx.fx_creds,
+            Transaction::Export(tx) => &tx.fx_creds,
+        }
     }
 }
 
@@ -364,4 +477,141 @@ fn test_tx_serialization_with_two_signers() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx_with_two_signers);
+
+    // re-signing the decoded tx must reproduce the exact same bytes
+    let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key.clone(), test_key.clone()];
+    let keys2: Vec<key::secp256k1::private_key::Key> = vec![test_key.clone(), test_key];
+    let mut resigned_tx = Tx::new(parsed_tx.base_tx.clone());
+    ab!(resigned_tx.sign(vec![keys1, keys2])).expect("failed to sign");
+    assert_eq!(resigned_tx, parsed_tx);
+
+    let tx_hex = crate::formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+    let parsed = Transaction::from_hex(&tx_hex).expect("failed Transaction::from_hex");
+    assert_eq!(parsed.type_id(), Tx::type_id());
+    assert_eq!(parsed.tx_id(), tx_with_two_signers.tx_id());
+    assert_eq!(parsed, Transaction::Base(tx_with_two_signers));
+
+    // a truncated tx must fail to decode
+    assert!(Transaction::unpack(&tx_bytes_with_signatures[..100]).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::test_transaction_unpack --exact --show-output
+/// Decodes the signed txs from the avalanchego test vectors, encoded
+/// as the "avm.getTx" API returns with "hex" encoding, and checks the tx IDs
+/// that avalanchego computes for them.
+/// ref. "avalanchego/vms/avm/txs.TestBaseTxSerialization"
+/// ref. "avalanchego/vms/avm/txs.TestImportTxSerialization"
+/// ref. "avalanchego/vms/avm/txs.TestExportTxSerialization"
+#[test]
+fn test_transaction_unpack() {
+    const BASE_TX_HEX: &str = concat!(
+        "0x0000000000000000000a0504030201000000000000000000000000000000000000000000000000",
+        "00000000000001010203000000000000000000000000000000000000000000000000000000000000",
+        "000007000000000000303900000000000000000000000100000001fceda8f90fcb5d30614b99d79f",
+        "c4baa29307762600000001fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0efeeedecebeae9e8e7e6e5e4e3",
+        "e2e1e000000001010203000000000000000000000000000000000000000000000000000000000000",
+        "000005000000000000d431000000010000000200000004000102030000000200000009000000027d",
+        "898ee98af8335d37e6fada0cbb44a14405d3bb940dfc0d99a6d3ff5c715aff26d18484f29b289644",
+        "968fedffeb23e030665d736d94fc80bc735f51c806d743007d898ee98af8335d37e6fada0cbb44a1",
+        "4405d3bb940dfc0d99a6d3ff5c715aff26d18484f29b289644968fedffeb23e030665d736d94fc80",
+        "bc735f51c806d7430000000009000000027d898ee98af8335d37e6fada0cbb44a14405d3bb940dfc",
+        "0d99a6d3ff5c715aff26d18484f29b289644968fedffeb23e030665d736d94fc80bc735f51c806d7",
+        "43007d898ee98af8335d37e6fada0cbb44a14405d3bb940dfc0d99a6d3ff5c715aff26d18484f29b",
+        "289644968fedffeb23e030665d736d94fc80bc735f51c806d743000fa06be2",
+    );
+    const IMPORT_TX_HEX: &str = concat!(
+        "0x00000000000300000002ffffffffeeeeeeeeddddddddccccccccbbbbbbbbaaaaaaaa9999999988",
+        "888888000000000000000000000004000102031f8f9f0f1e8e9e0e2d7dadfd2c7cacfc3b6bbbeb3a",
+        "6abaea4959c9d94858c8d8000000010f2f4f6f8eaeceee0d2d4d6d8cacccec0b2b4b6b8aaacaea09",
+        "29496988a8c8e8000000001f3f5f7f9ebedefe1d3d5d7d9cbcdcfc1b3b5b7b9abadafa1939597998",
+        "b8d8f80000000500000000000003e800000001000000000000000200000009000000028cc7dc8c11",
+        "d3759e16a59fd29c64d71f9bad1a623398c7af6702c5e0758e62cf156d99f54e71b8f48b5bbf0c59",
+        "627934971a1f499b0a4fbf95fc3139464ea1af008cc7dc8c11d3759e16a59fd29c64d71f9bad1a62",
+        "3398c7af6702c5e0758e62cf156d99f54e71b8f48b5bbf0c59627934971a1f499b0a4fbf95fc3139",
+        "464ea1af0000000009000000028cc7dc8c11d3759e16a59fd29c64d71f9bad1a623398c7af6702c5",
+        "e0758e62cf156d99f54e71b8f48b5bbf0c59627934971a1f499b0a4fbf95fc3139464ea1af008cc7",
+        "dc8c11d3759e16a59fd29c64d71f9bad1a623398c7af6702c5e0758e62cf156d99f54e71b8f48b5b",
+        "bf0c59627934971a1f499b0a4fbf95fc3139464ea1af0096d1efd5",
+    );
+    const EXPORT_TX_HEX: &str = concat!(
+        "0x00000000000400000002ffffffffeeeeeeeeddddddddccccccccbbbbbbbbaaaaaaaa9999999988",
+        "88888800000000000000010f2f4f6f8eaeceee0d2d4d6d8cacccec0b2b4b6b8aaacaea0929496988",
+        "a8c8e8000000001f3f5f7f9ebedefe1d3d5d7d9cbcdcfc1b3b5b7b9abadafa1939597998b8d8f800",
+        "00000500000000000003e8000000010000000000000004000102031f8f9f0f1e8e9e0e2d7dadfd2c",
+        "7cacfc3b6bbbeb3a6abaea4959c9d94858c8d80000000000000002000000090000000261dd9bffc0",
+        "49956ed7f8cd92ecda036eacb8169e5383c03a2e885b5fc6ef2ebe5059728d0fa65966932888b456",
+        "3b777c59a58fe02af3cc3132effe7d3d9f14940161dd9bffc049956ed7f8cd92ecda036eacb8169e",
+        "5383c03a2e885b5fc6ef2ebe5059728d0fa65966932888b4563b777c59a58fe02af3cc3132effe7d",
+        "3d9f149401000000090000000261dd9bffc049956ed7f8cd92ecda036eacb8169e5383c03a2e885b",
+        "5fc6ef2ebe5059728d0fa65966932888b4563b777c59a58fe02af3cc3132effe7d3d9f14940161dd",
+        "9bffc049956ed7f8cd92ecda036eacb8169e5383c03a2e885b5fc6ef2ebe5059728d0fa659669328",
+        "88b4563b777c59a58fe02af3cc3132effe7d3d9f149401669556cd",
+    );
+
+    let tx = Transaction::from_hex(BASE_TX_HEX).expect("failed Transaction::from_hex");
+    assert_eq!(
+        tx.tx_id().to_string(),
+        "QnTUuie2qe6BKyYrC2jqd73bJ828QNhYnZbdA2HWsnVRPjBfV"
+    );
+    let base_tx = tx.base_tx();
+    assert_eq!(base_tx.network_id, 10);
+    assert_eq!(base_tx.memo, Some(vec![0x00, 0x01, 0x02, 0x03]));
+    let outs = base_tx.transferable_outputs.as_ref().unwrap();
+    assert_eq!(outs.len(), 1);
+    assert_eq!(outs[0].transfer_output.as_ref().unwrap().amount, 12345);
+    let ins = base_tx.transferable_inputs.as_ref().unwrap();
+    assert_eq!(ins.len(), 1);
+    assert_eq!(ins[0].utxo_id.output_index, 1);
+    assert_eq!(ins[0].transfer_input.as_ref().unwrap().amount, 54321);
+    assert_eq!(ins[0].transfer_input.as_ref().unwrap().sig_indices, vec![2]);
+    assert_eq!(tx.fx_creds().len(), 2);
+    assert_eq!(tx.fx_creds()[0].cred.signatures.len(), 2);
+
+    let tx = Transaction::from_hex(IMPORT_TX_HEX).expect("failed Transaction::from_hex");
+    assert_eq!(
+        tx.tx_id().to_string(),
+        "pCW7sVBytzdZ1WrqzGY1DvA2S9UaMr72xpUMxVyx1QHBARNYx"
+    );
+    match &tx {
+        Transaction::Import(tx) => {
+            assert_eq!(tx.base_tx.network_id, 2);
+            assert!(tx.base_tx.transferable_inputs.is_none());
+            let ins = tx.source_chain_transferable_inputs.as_ref().unwrap();
+            assert_eq!(ins.len(), 1);
+            assert_eq!(ins[0].transfer_input.as_ref().unwrap().amount, 1000);
+            assert_eq!(tx.fx_creds.len(), 2);
+        }
+        _ => panic!("unexpected tx {:?}", tx),
+    }
+
+    let tx = Transaction::from_hex(EXPORT_TX_HEX).expect("failed Transaction::from_hex");
+    assert_eq!(
+        tx.tx_id().to_string(),
+        "2oG52e7Cb7XF1yUzv3pRFndAypgbpswWRcSAKD5SH5VgaiTm5D"
+    );
+    match &tx {
+        Transaction::Export(tx) => {
+            assert_eq!(tx.base_tx.network_id, 2);
+            let ins = tx.base_tx.transferable_inputs.as_ref().unwrap();
+            assert_eq!(ins[0].transfer_input.as_ref().unwrap().amount, 1000);
+            assert!(tx.destination_chain_transferable_outputs.is_none());
+            assert_eq!(tx.fx_creds.len(), 2);
+        }
+        _ => panic!("unexpected tx {:?}", tx),
+    }
+
+    // unknown codec version
+    let mut b = platformvm::txs::decode_hex(BASE_TX_HEX).unwrap();
+    assert!(Transaction::unpack(&b).is_ok());
+    b[1] = 1;
+    assert!(Transaction::unpack(&b).is_err());
+    assert!(Tx::unpack(&b).is_err());
+
+    // unknown tx type ID
+    let mut b = platformvm::txs::decode_hex(BASE_TX_HEX).unwrap();
+    b[5] = 0xff;
+    assert!(Transaction::unpack(&b).is_err());
 }
@@ -0,0 +1,549 @@
+use std::cmp::Ordering;
+
+use crate::{
+    avm::{self, txs::fx},
+    codec::{
+        self,
+        linear::{Pack, Unpack},
+    },
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, packer, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx>
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#OperationTx>
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub operations: Vec<Operation>,
+
+    /// Credentials for the base tx inputs followed by the operations,
+    /// in the same order.
+    pub fx_creds: Vec<fx::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            operations: Vec::new(),
+            fx_creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "avm.OperationTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    /// Verifies the operations are well-formed, sorted and unique,
+    /// and no UTXO is consumed twice.
+    /// ref. "avalanchego/vms/avm/txs/executor.SyntacticVerifier.OperationTx"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.operations.is_empty() {
+            return Err(Error::Other {
+                message: "an operationTx must have at least one operation".to_string(), // ref. "errNoOperations"
+                retryable: false,
+            });
+        }
+
+        self.base_tx.verify_syntax()?;
+        for op in self.operations.iter() {
+            op.verify_syntax()?;
+        }
+        if !cmp_manager::is_sorted_and_unique(&self.operations) {
+            return Err(Error::Other {
+                message: "operations not sorted and unique".to_string(), // ref. "errOperationsNotSortedUnique"
+                retryable: false,
+            });
+        }
+
+        let mut utxo_ids: Vec<&txs::utxo::Id> = self
+            .base_tx
+            .transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .chain(self.operations.iter().flat_map(|op| op.utxo_ids.iter()))
+            .collect();
+        let n = utxo_ids.len();
+        utxo_ids.sort();
+        utxo_ids.dedup();
+        if utxo_ids.len() != n {
+            return Err(Error::Other {
+                message: "inputs attempt to double spend an input".to_string(), // ref. "errDoubleSpend"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the fx IDs of the credentials: the ones for the base tx inputs
+    /// are "secp256k1fx.Credential" with the empty fx ID, followed by the ones
+    /// of the operation fxs.
+    pub fn credential_fx_ids(&self, n_creds: usize) -> Vec<ids::Id> {
+        let n_inputs = n_creds.saturating_sub(self.operations.len());
+        let mut fx_ids = vec![ids::Id::empty(); n_inputs];
+        for op in self.operations.iter() {
+            fx_ids.push(match op.op.fx_index() {
+                fx::SECP256K1_FX_INDEX => ids::Id::empty(),
+                _ => op.op.fx_id(),
+            });
+        }
+        fx_ids
+    }
+
+    /// Returns the UTXO IDs that the credentials spend, in the same order of
+    /// "credential_fx_ids": the ones of the base tx inputs, followed by the
+    /// first UTXO of each operation (whose owners sign the operation).
+    pub fn credential_utxo_ids(&self) -> Vec<&txs::utxo::Id> {
+        self.base_tx
+            .transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .chain(self.operations.iter().filter_map(|op| op.utxo_ids.first()))
+            .collect()
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx.SignSECP256K1Fx>
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let fx_ids = self.credential_fx_ids(signers.len());
+        let (fx_creds, metadata) =
+            avm::txs::sign_tx(Self::type_id(), self, signers, &fx_ids).await?;
+        self.fx_creds = fx_creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "avm.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Parser>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, fx_creds, metadata) = avm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.fx_creds = fx_creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "avm.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        operations
+    }
+);
+
+/// Represents the operation on the UTXOs of the asset.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Operation>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct Operation {
+    /// "Operation.Asset" is embedded inline.
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    /// UTXOs consumed by the operation.
+    #[serde(rename = "inputIDs")]
+    pub utxo_ids: Vec<txs::utxo::Id>,
+    #[serde(rename = "operation")]
+    pub op: FxOperation,
+}
+
+impl Operation {
+    pub fn new(asset_id: ids::Id, utxo_ids: Vec<txs::utxo::Id>, op: FxOperation) -> Self {
+        Self {
+            asset_id,
+            utxo_ids,
+            op,
+        }
+    }
+
+    /// ref. "avalanchego/vms/avm/txs.Operation.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if !cmp_manager::is_sorted_and_unique(&self.utxo_ids) {
+            return Err(Error::Other {
+                message: "utxo IDs not sorted and unique".to_string(), // ref. "ErrNotSortedAndUniqueUTXOIDs"
+                retryable: false,
+            });
+        }
+        self.op.verify_syntax()
+    }
+
+    /// Returns the packed bytes to sort the operations.
+    fn bytes(&self) -> Vec<u8> {
+        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        self.pack_to(&packer).expect("failed to pack operation");
+        packer.take_bytes().to_vec()
+    }
+}
+
+// "Operation.FxID" is serialize:"false" thus derived from the operation type
+impl Pack for Operation {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        self.asset_id.pack_to(packer)?;
+        self.utxo_ids.pack_to(packer)?;
+        self.op.pack_to(packer)
+    }
+}
+
+impl Unpack for Operation {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let asset_id = ids::Id::unpack_from(packer)?;
+        let utxo_ids = Vec::<txs::utxo::Id>::unpack_from(packer)?;
+        let op = FxOperation::unpack_from(packer)?;
+        Ok(Self::new(asset_id, utxo_ids, op))
+    }
+}
+
+/// Sorts by the marshaled bytes.
+/// ref. "avalanchego/vms/avm/txs.SortOperations"
+impl Ord for Operation {
+    fn cmp(&self, other: &Operation) -> Ordering {
+        self.bytes().cmp(&other.bytes())
+    }
+}
+
+impl PartialOrd for Operation {
+    fn partial_cmp(&self, other: &Operation) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Operation {
+    fn eq(&self, other: &Operation) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Represents the feature extension operation,
+/// which is "fxs.FxOperation" interface in avalanchego.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/fxs#FxOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum FxOperation {
+    /// "*secp256k1fx.MintOperation" to mint the new supply.
+    Secp256k1Mint(key::secp256k1::txs::mint::Operation),
+    /// "*nftfx.MintOperation" to mint the NFTs of the group.
+    NftMint(avm::nftfx::MintOperation),
+    /// "*nftfx.TransferOperation" to transfer the NFT.
+    NftTransfer(avm::nftfx::TransferOperation),
+    /// "*propertyfx.MintOperation" to mint the property.
+    PropertyMint(avm::propertyfx::MintOperation),
+    /// "*propertyfx.BurnOperation" to burn the property.
+    PropertyBurn(avm::propertyfx::BurnOperation),
+}
+
+impl FxOperation {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            FxOperation::Secp256k1Mint(_) => key::secp256k1::txs::mint::Operation::type_id(),
+            FxOperation::NftMint(_) => avm::nftfx::MintOperation::type_id(),
+            FxOperation::NftTransfer(_) => avm::nftfx::TransferOperation::type_id(),
+            FxOperation::PropertyMint(_) => avm::propertyfx::MintOperation::type_id(),
+            FxOperation::PropertyBurn(_) => avm::propertyfx::BurnOperation::type_id(),
+        }
+    }
+
+    /// Returns the index of the feature extension that owns the operation.
+    pub fn fx_index(&self) -> u32 {
+        match self {
+            FxOperation::Secp256k1Mint(_) => fx::SECP256K1_FX_INDEX,
+            FxOperation::NftMint(_) | FxOperation::NftTransfer(_) => fx::NFT_FX_INDEX,
+            FxOperation::PropertyMint(_) | FxOperation::PropertyBurn(_) => fx::PROPERTY_FX_INDEX,
+        }
+    }
+
+    /// Returns the ID of the feature extension that owns the operation.
+    pub fn fx_id(&self) -> ids::Id {
+        match self {
+            FxOperation::Secp256k1Mint(_) => key::secp256k1::txs::fx_id(),
+            FxOperation::NftMint(_) | FxOperation::NftTransfer(_) => avm::nftfx::fx_id(),
+            FxOperation::PropertyMint(_) | FxOperation::PropertyBurn(_) => avm::propertyfx::fx_id(),
+        }
+    }
+
+    pub fn verify_syntax(&self) -> Result<()> {
+        match self {
+            FxOperation::Secp256k1Mint(op) => op.verify_syntax(),
+            FxOperation::NftMint(op) => op.verify_syntax(),
+            FxOperation::NftTransfer(op) => op.verify_syntax(),
+            FxOperation::PropertyMint(op) => op.verify_syntax(),
+            FxOperation::PropertyBurn(op) => op.verify_syntax(),
+        }
+    }
+}
+
+impl Pack for FxOperation {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        match self {
+            FxOperation::Secp256k1Mint(op) => codec::linear::pack_interface(op, packer),
+            FxOperation::NftMint(op) => codec::linear::pack_interface(op, packer),
+            FxOperation::NftTransfer(op) => codec::linear::pack_interface(op, packer),
+            FxOperation::PropertyMint(op) => codec::linear::pack_interface(op, packer),
+            FxOperation::PropertyBurn(op) => codec::linear::pack_interface(op, packer),
+        }
+    }
+}
+
+impl Unpack for FxOperation {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let type_id = packer.unpack_u32()?;
+        if codec::linear::is_type::<key::secp256k1::txs::mint::Operation>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(FxOperation::Secp256k1Mint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::nftfx::MintOperation>(packer.registry(), type_id) {
+            Ok(FxOperation::NftMint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::nftfx::TransferOperation>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(FxOperation::NftTransfer(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::propertyfx::MintOperation>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(FxOperation::PropertyMint(Unpack::unpack_from(packer)?))
+        } else if codec::linear::is_type::<avm::propertyfx::BurnOperation>(
+            packer.registry(),
+            type_id,
+        ) {
+            Ok(FxOperation::PropertyBurn(Unpack::unpack_from(packer)?))
+        } else {
+            Err(Error::Other {
+                message: format!("unexpected type ID {} for FxOperation", type_id),
+                retryable: false,
+            })
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::operation::test_operation_tx_serialization --exact --show-output
+#[test]
+fn test_operation_tx_serialization() {
+    use crate::ids::short;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let asset_id = ids::Id::from_slice(&[0x1f; 32]);
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xfc; 20])]);
+    let mint_utxo_id = txs::utxo::Id {
+        tx_id: ids::Id::from_slice(&[0x0e; 32]),
+        output_index: 1,
+        ..txs::utxo::Id::default()
+    };
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 2,
+            blockchain_id: ids::Id::from_slice(&[0xff; 32]),
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x0f; 32]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[0x2f; 32]),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        operations: vec![Operation::new(
+            asset_id,
+            vec![mint_utxo_id.clone()],
+            FxOperation::Secp256k1Mint(key::secp256k1::txs::mint::Operation {
+                mint_input: key::secp256k1::txs::Input::new(vec![0]),
+                mint_output: key::secp256k1::txs::mint::Output::new(owners.clone()),
+                transfer_output: key::secp256k1::txs::transfer::Output::new(500, owners),
+            }),
+        )],
+        ..Tx::default()
+    };
+    assert!(tx.verify_syntax().is_ok());
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+
+    // one credential for the input, and another for the operation
+    ab!(tx.sign(vec![vec![test_key.clone()], vec![test_key.clone()]])).expect("failed to sign");
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    assert_eq!(tx.fx_creds.len(), 2);
+
+    let op_packer = packer::Packer::new(1024, 0);
+    tx.operations.pack_to(&op_packer).unwrap();
+    let mut expected: Vec<u8> = vec![
+        // number of operations
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0x1f; 32]);
+    expected.extend_from_slice(&[
+        // number of UTXO IDs
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected.extend_from_slice(&[0x0e; 32]);
+    expected.extend_from_slice(&[
+        // output index
+        0x00, 0x00, 0x00, 0x01, //
+        // secp256k1fx.MintOperation type ID
+        0x00, 0x00, 0x00, 0x08, //
+    ]);
+    assert!(op_packer.take_bytes().starts_with(&expected));
+
+    // the unsigned tx ends with the operations
+    let unsigned = &tx_metadata.tx_bytes_with_no_signature;
+    assert_eq!(&unsigned[..6], &[0x00, 0x00, 0x00, 0x00, 0x00, 0x02]);
+    let parsed = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed, tx);
+    let parsed =
+        crate::avm::txs::Transaction::unpack(&tx_metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed.type_id(), Tx::type_id());
+    assert_eq!(parsed.tx_id(), tx.tx_id());
+    assert_eq!(parsed.fx_creds().len(), 2);
+
+    // consumes the same UTXO in the base tx and the operation
+    let mut double_spend = tx.clone();
+    double_spend.operations[0].utxo_ids = vec![txs::utxo::Id {
+        tx_id: ids::Id::from_slice(&[0x0f; 32]),
+        ..txs::utxo::Id::default()
+    }];
+    assert!(double_spend.verify_syntax().is_err());
+
+    let mut no_op = tx.clone();
+    no_op.operations.clear();
+    assert!(no_op.verify_syntax().is_err());
+
+    let mut duplicate_ops = tx.clone();
+    duplicate_ops.operations.push(tx.operations[0].clone());
+    assert!(duplicate_ops.verify_syntax().is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::operation::test_operation_tx_nft --exact --show-output
+#[test]
+fn test_operation_tx_nft() {
+    use crate::ids::short;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xfc; 20])]);
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 2,
+            blockchain_id: ids::Id::from_slice(&[0xff; 32]),
+            ..txs::Tx::default()
+        },
+        operations: vec![
+            Operation::new(
+                ids::Id::from_slice(&[0x1f; 32]),
+                vec![txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x0e; 32]),
+                    ..txs::utxo::Id::default()
+                }],
+                FxOperation::NftMint(avm::nftfx::MintOperation {
+                    mint_input: key::secp256k1::txs::Input::new(vec![0]),
+                    group_id: 1,
+                    payload: b"hello".to_vec(),
+                    outputs: vec![owners.clone()],
+                }),
+            ),
+            Operation::new(
+                ids::Id::from_slice(&[0x1f; 32]),
+                vec![txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x0e; 32]),
+                    output_index: 1,
+                    ..txs::utxo::Id::default()
+                }],
+                FxOperation::NftTransfer(avm::nftfx::TransferOperation::new(
+                    key::secp256k1::txs::Input::new(vec![0]),
+                    avm::nftfx::TransferOutput::new(1, b"world".to_vec(), owners),
+                )),
+            ),
+        ],
+        ..Tx::default()
+    };
+    tx.operations.sort();
+    assert!(tx.verify_syntax().is_ok());
+    assert_eq!(tx.operations[0].op.fx_index(), fx::NFT_FX_INDEX);
+    assert_eq!(
+        tx.credential_utxo_ids(),
+        vec![&tx.operations[0].utxo_ids[0], &tx.operations[1].utxo_ids[0]]
+    );
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .expect("failed to load private key");
+    ab!(tx.sign(vec![vec![test_key.clone()], vec![test_key]])).expect("failed to sign");
+    assert_eq!(tx.fx_creds.len(), 2);
+    assert_eq!(tx.fx_creds[0].fx_id, avm::nftfx::fx_id());
+
+    // the credentials of the nftfx operations are "nftfx.Credential"
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let signed = &tx_metadata.tx_bytes_with_signatures;
+    let creds = &signed[tx_metadata.tx_bytes_with_no_signature.len()..];
+    assert_eq!(
+        &creds[..8],
+        &[0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0e]
+    );
+
+    let parsed = Tx::unpack(signed).unwrap();
+    assert_eq!(parsed, tx);
+}
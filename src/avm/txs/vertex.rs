@@ -1,4 +1,9 @@
-use crate::{errors::Result, ids, packer::Packer, txs::raw};
+use crate::{
+    errors::Result,
+    ids,
+    packer::{reader::PackerReader, Packer},
+    txs::raw,
+};
 
 /// Vertex represents a set of transactions for Avalanche X-chain.
 ///
@@ -85,6 +90,63 @@ impl Packer {
     }
 }
 
+/// Borrowed view of "Vertex" whose transactions point into the input bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VertexRef<'a> {
+    pub codec_version: u16,
+    pub chain_id: ids::Id,
+    pub height: u64,
+    pub epoch: u32,
+    pub parent_ids: Vec<ids::Id>,
+    pub txs: Vec<&'a [u8]>,
+}
+
+impl VertexRef<'_> {
+    /// Copies the borrowed transactions into an owned "Vertex".
+    pub fn to_vertex(&self) -> Vertex {
+        Vertex {
+            codec_version: self.codec_version,
+            chain_id: self.chain_id,
+            height: self.height,
+            epoch: self.epoch,
+            parent_ids: self.parent_ids.clone(),
+            txs: self.txs.iter().map(|tx| tx.to_vec()).collect(),
+        }
+    }
+}
+
+impl<'a> PackerReader<'a> {
+    /// Unpacks the vertex without copying the transaction bytes.
+    ///
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/avalanche/vertex#Build>
+    pub fn unpack_vertex(&mut self) -> Result<VertexRef<'a>> {
+        let codec_version = self.unpack_u16()?;
+        let chain_id = ids::Id::from_slice(self.unpack_bytes(ids::LEN)?);
+
+        let height = self.unpack_u64()?;
+        let epoch = self.unpack_u32()?;
+
+        let parent_ids_size = self.unpack_u32()? as usize;
+        let d = self.unpack_bytes(parent_ids_size * ids::LEN)?;
+        let parent_ids: Vec<ids::Id> = d.chunks_exact(ids::LEN).map(ids::Id::from_slice).collect();
+
+        let txs_size = self.unpack_u32()?;
+        let mut txs: Vec<&'a [u8]> = Vec::new();
+        for _ in 0..txs_size {
+            txs.push(self.unpack_bytes_with_header()?);
+        }
+
+        Ok(VertexRef {
+            codec_version,
+            chain_id,
+            height,
+            epoch,
+            parent_ids,
+            txs,
+        })
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::txs::vertex::test_pack_and_unpack --exact --show-output
 #[test]
 fn test_pack_and_unpack() {
@@ -184,4 +246,12 @@ fn test_pack_and_unpack() {
 
     let vtx_unpacked = packer.unpack_vertex().unwrap();
     assert!(vtx == vtx_unpacked);
+
+    let mut reader = PackerReader::new(&b);
+    let vtx_ref = reader.unpack_vertex().unwrap();
+    assert_eq!(reader.remaining(), 0);
+    assert!(vtx == vtx_ref.to_vertex());
+    assert!(PackerReader::new(&b[..b.len() - 1])
+        .unpack_vertex()
+        .is_err());
 }
@@ -0,0 +1,439 @@
+use crate::{
+    codec::manager::Registry,
+    errors::{Error, Result},
+    ids::{self, node, short},
+    packer::Packer,
+};
+
+/// Encodes the value following the avalanchego linear codec rules:
+/// struct fields are written in the declared order, slices are prefixed
+/// with the "u32" length, and interface values are prefixed with the
+/// "u32" type ID (see "Interface").
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/linearcodec>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/reflectcodec>
+pub trait Pack {
+    fn pack_to(&self, packer: &Packer) -> Result<()>;
+
+    /// Packs the slice elements without the length prefix.
+    /// Overridden for "u8" to write the whole slice at once.
+    fn pack_slice(v: &[Self], packer: &Packer) -> Result<()>
+    where
+        Self: Sized,
+    {
+        for e in v.iter() {
+            e.pack_to(packer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the value in the same order as "Pack".
+pub trait Unpack: Sized {
+    fn unpack_from(packer: &Packer) -> Result<Self>;
+
+    /// Unpacks "n" slice elements whose length prefix is already consumed.
+    /// Overridden for "u8" to read the whole slice at once.
+    fn unpack_vec(n: usize, packer: &Packer) -> Result<Vec<Self>> {
+        let mut v: Vec<Self> = Vec::new();
+        for _ in 0..n {
+            v.push(Self::unpack_from(packer)?);
+        }
+        Ok(v)
+    }
+}
+
+/// Implemented by the types registered in "codec::P_TYPES" or "codec::X_TYPES".
+/// When the field is a Go interface (e.g., "fx.Owner", "verify.Verifiable"),
+/// the codec prefixes the value with this type ID.
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/linearcodec#Codec.RegisterType>
+pub trait Interface {
+    /// Returns the type ID in "codec::VERSION", used when
+    /// the packer has no type registry of the codec version.
+    fn interface_type_id() -> u32;
+
+    /// Returns the Go type name (e.g., "secp256k1fx.Credential"),
+    /// used to look up the type ID in the registry of each codec version.
+    fn interface_type_name() -> String;
+}
+
+/// Returns the type ID of the type in the registry of the codec version
+/// (e.g., "Packer::registry"), or in "codec::VERSION" if no registry is given.
+pub fn type_id_of<T: Interface>(registry: Option<&Registry>) -> Result<u32> {
+    match registry {
+        Some(registry) => registry.resolve_type_id(&T::interface_type_name()),
+        None => Ok(T::interface_type_id()),
+    }
+}
+
+/// Returns "true" if the type ID is the one of the type in the registry
+/// of the codec version, or in "codec::VERSION" if no registry is given.
+pub fn is_type<T: Interface>(registry: Option<&Registry>, type_id: u32) -> bool {
+    match registry {
+        Some(registry) => registry.type_name(type_id) == Some(T::interface_type_name().as_str()),
+        None => type_id == T::interface_type_id(),
+    }
+}
+
+/// Packs the value as a Go interface, prefixed with its type ID
+/// in the codec version of the packer.
+pub fn pack_interface<T: Pack + Interface>(v: &T, packer: &Packer) -> Result<()> {
+    packer.pack_u32(type_id_of::<T>(packer.registry())?)?;
+    v.pack_to(packer)
+}
+
+/// Unpacks the Go interface value, and returns an error if the type ID
+/// does not match the one of the type in the codec version of the packer.
+pub fn unpack_interface<T: Unpack + Interface>(packer: &Packer) -> Result<T> {
+    let type_id = packer.unpack_u32()?;
+    if !is_type::<T>(packer.registry(), type_id) {
+        return Err(Error::Other {
+            message: format!(
+                "unexpected type ID {} (expected type {})",
+                type_id,
+                T::interface_type_name()
+            ),
+            retryable: false,
+        });
+    }
+    T::unpack_from(packer)
+}
+
+/// Marshals the value with the codec version and the type ID.
+/// Returns the packer itself so that the following marshals can reuse.
+///
+/// ref. "avalanchego/codec.manager.Marshal"
+pub fn pack_with_header<T: Pack>(codec_version: u16, type_id: u32, v: &T) -> Result<Packer> {
+    // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
+    let packer = Packer::new((1 << 31) - 1, 128);
+
+    packer.pack_u16(codec_version)?;
+    packer.pack_u32(type_id)?;
+    v.pack_to(&packer)?;
+
+    Ok(packer)
+}
+
+/// Implements "Pack" and "Unpack" for the struct by listing its serialized
+/// fields in the codec order. Fields with Go tag `serialize:"false"` are simply
+/// left out, and reset with the inherent "default()" on unpack. Prefix the
+/// field with `#[interface]` when the Go field is an interface type, so that
+/// the type ID is encoded ahead of the value.
+///
+/// Pass `interface` after the type to also implement "Interface" with
+/// the inherent "type_id()" of the type.
+///
+/// ```ignore
+/// impl_linear_codec!(Tx, interface {
+///     base_tx,
+///     validator,
+///     #[interface]
+///     rewards_owner,
+/// });
+/// ```
+#[macro_export]
+macro_rules! impl_linear_codec {
+    (@pack $packer:ident, $v:expr) => {
+        $crate::codec::linear::Pack::pack_to(&$v, $packer)?
+    };
+    (@pack $packer:ident, $v:expr, interface) => {
+        $crate::codec::linear::pack_interface(&$v, $packer)?
+    };
+    (@unpack $packer:ident) => {
+        $crate::codec::linear::Unpack::unpack_from($packer)?
+    };
+    (@unpack $packer:ident, interface) => {
+        $crate::codec::linear::unpack_interface($packer)?
+    };
+    ($ty:ty, interface { $($body:tt)* }) => {
+        impl $crate::codec::linear::Interface for $ty {
+            fn interface_type_id() -> u32 {
+                <$ty>::type_id()
+            }
+
+            fn interface_type_name() -> String {
+                <$ty>::type_name()
+            }
+        }
+        $crate::impl_linear_codec!($ty { $($body)* });
+    };
+    ($ty:ty { $( $(#[$kind:ident])? $field:ident ),* $(,)? }) => {
+        impl $crate::codec::linear::Pack for $ty {
+            fn pack_to(&self, packer: &$crate::packer::Packer) -> $crate::errors::Result<()> {
+                $( $crate::impl_linear_codec!(@pack packer, self.$field $(, $kind)?); )*
+                Ok(())
+            }
+        }
+        impl $crate::codec::linear::Unpack for $ty {
+            #[allow(clippy::needless_update)]
+            fn unpack_from(packer: &$crate::packer::Packer) -> $crate::errors::Result<Self> {
+                $( let $field = $crate::impl_linear_codec!(@unpack packer $(, $kind)?); )*
+                Ok(Self {
+                    $( $field, )*
+                    ..<$ty>::default()
+                })
+            }
+        }
+    };
+}
+
+impl Pack for u8 {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_byte(*self)
+    }
+
+    fn pack_slice(v: &[Self], packer: &Packer) -> Result<()> {
+        packer.pack_bytes(v)
+    }
+}
+
+impl Unpack for u8 {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_byte()
+    }
+
+    fn unpack_vec(n: usize, packer: &Packer) -> Result<Vec<Self>> {
+        packer.unpack_bytes(n)
+    }
+}
+
+impl Pack for u16 {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_u16(*self)
+    }
+}
+
+impl Unpack for u16 {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_u16()
+    }
+}
+
+impl Pack for u32 {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_u32(*self)
+    }
+}
+
+impl Unpack for u32 {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_u32()
+    }
+}
+
+impl Pack for u64 {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_u64(*self)
+    }
+}
+
+impl Unpack for u64 {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_u64()
+    }
+}
+
+impl Pack for bool {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bool(*self)
+    }
+}
+
+impl Unpack for bool {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_bool()
+    }
+}
+
+/// Go "string" is prefixed with the "u16" length.
+impl Pack for String {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_str(self)
+    }
+}
+
+impl Unpack for String {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_str()
+    }
+}
+
+impl Pack for ids::Id {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bytes(self.as_ref())
+    }
+}
+
+impl Unpack for ids::Id {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let d = packer.unpack_bytes(ids::LEN)?;
+        Ok(Self::from_slice(&d))
+    }
+}
+
+impl Pack for short::Id {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bytes(self.as_ref())
+    }
+}
+
+impl Unpack for short::Id {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let d = packer.unpack_bytes(short::LEN)?;
+        Ok(Self::from_slice(&d))
+    }
+}
+
+impl Pack for node::Id {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_bytes(self.as_ref())
+    }
+}
+
+impl Unpack for node::Id {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let d = packer.unpack_bytes(node::LEN)?;
+        Ok(Self::from_slice(&d))
+    }
+}
+
+impl<T: Pack> Pack for Vec<T> {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_u32(self.len() as u32)?;
+        T::pack_slice(self, packer)
+    }
+}
+
+impl<T: Unpack> Unpack for Vec<T> {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let n = packer.unpack_u32()?;
+        T::unpack_vec(n as usize, packer)
+    }
+}
+
+/// "None" is packed as an empty slice, thus an empty slice is unpacked as "None".
+impl<T: Pack> Pack for Option<Vec<T>> {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        match self {
+            Some(v) => v.pack_to(packer),
+            None => packer.pack_u32(0),
+        }
+    }
+}
+
+impl<T: Unpack> Unpack for Option<Vec<T>> {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        let v = Vec::<T>::unpack_from(packer)?;
+        if v.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(v))
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- codec::linear::test_pack_unpack --exact --show-output
+#[test]
+fn test_pack_unpack() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Inner {
+        amount: u64,
+        addresses: Vec<short::Id>,
+    }
+    impl Inner {
+        fn default() -> Self {
+            Self {
+                amount: 0,
+                addresses: Vec::new(),
+            }
+        }
+        fn type_id() -> u32 {
+            7
+        }
+        fn type_name() -> String {
+            "secp256k1fx.TransferOutput".to_string()
+        }
+    }
+    impl_linear_codec!(Inner, interface { amount, addresses });
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Outer {
+        id: ids::Id,
+        name: String,
+        data: Vec<u8>,
+        inner: Inner,
+        owner: Inner,
+        memo: Option<Vec<u8>>,
+        skipped: u32,
+    }
+    impl Outer {
+        fn default() -> Self {
+            Self {
+                id: ids::Id::empty(),
+                name: String::new(),
+                data: Vec::new(),
+                inner: Inner::default(),
+                owner: Inner::default(),
+                memo: None,
+                skipped: 0,
+            }
+        }
+    }
+    impl_linear_codec!(Outer {
+        id,
+        name,
+        data,
+        inner,
+        #[interface]
+        owner,
+        memo,
+    });
+
+    let v = Outer {
+        id: ids::Id::from_slice(&[1, 2, 3]),
+        name: "ab".to_string(),
+        data: vec![0xff, 0xfe],
+        inner: Inner {
+            amount: 1,
+            addresses: vec![short::Id::from_slice(&[9])],
+        },
+        owner: Inner {
+            amount: 2,
+            addresses: Vec::new(),
+        },
+        memo: None,
+        skipped: 100,
+    };
+
+    let packer = Packer::new(1024, 0);
+    v.pack_to(&packer).unwrap();
+    let b = packer.take_bytes();
+
+    let mut expected: Vec<u8> = Vec::new();
+    expected.extend_from_slice(v.id.as_ref());
+    expected.extend_from_slice(&[0x00, 0x02, b'a', b'b']);
+    expected.extend_from_slice(&[0x00, 0x00, 0x00, 0x02, 0xff, 0xfe]);
+    expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+    expected.extend_from_slice(&[0, 0, 0, 1]);
+    expected.extend_from_slice(v.inner.addresses[0].as_ref());
+    // interface field is prefixed with the type ID
+    expected.extend_from_slice(&[0, 0, 0, 7]);
+    expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 2]);
+    expected.extend_from_slice(&[0, 0, 0, 0]);
+    // "None" is packed as an empty slice
+    expected.extend_from_slice(&[0, 0, 0, 0]);
+    assert_eq!(b.as_ref(), expected.as_slice());
+
+    let packer = Packer::load_bytes_for_unpack(1024, &b);
+    let decoded = Outer::unpack_from(&packer).unwrap();
+    assert_eq!(packer.get_offset(), b.len());
+    assert_eq!(decoded, Outer { skipped: 0, ..v });
+
+    // wrong type ID for the interface field
+    let mut corrupted = b.to_vec();
+    let n = corrupted.len();
+    corrupted[n - 20 + 3] = 8;
+    let packer = Packer::load_bytes_for_unpack(1024, &corrupted);
+    assert!(Outer::unpack_from(&packer).is_err());
+}
@@ -0,0 +1,500 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use crate::{
+    codec::linear::{self, Interface, Pack, Unpack},
+    errors::{Error, Result},
+    packer::Packer,
+};
+
+/// Default max size in bytes, with "math.MaxInt32" in Go.
+/// ref. "avalanchego/vms/platformvm/txs.Codec", "avalanchego/vms/avm.newCustomCodecs"
+pub const DEFAULT_MAX_SIZE: usize = (1 << 31) - 1;
+
+/// Type registry of a single codec version, which maps the Go type names
+/// to the type IDs (used for encoding Go interface type into a "struct").
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec#Registry>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registry {
+    /// Max size in bytes of the message encoded with this version,
+    /// including the codec version prefix.
+    pub max_size: usize,
+
+    type_ids: HashMap<String, u32>,
+    type_names: BTreeMap<u32, String>,
+    next_type_id: u32,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Registry {
+    pub fn default() -> Self {
+        Self::new(DEFAULT_MAX_SIZE)
+    }
+
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            type_ids: HashMap::new(),
+            type_names: BTreeMap::new(),
+            next_type_id: 0,
+        }
+    }
+
+    /// Creates a registry from the existing type map (e.g., "codec::P_TYPES").
+    pub fn from_types(max_size: usize, types: &HashMap<String, usize>) -> Result<Self> {
+        let mut registry = Self::new(max_size);
+        let mut sorted: Vec<(&String, &usize)> = types.iter().collect();
+        sorted.sort_by_key(|(_, type_id)| **type_id);
+        for (name, type_id) in sorted {
+            registry.register_with_id(name, *type_id as u32)?;
+        }
+        Ok(registry)
+    }
+
+    /// Registers the type with the next type ID, and returns the assigned ID.
+    /// ref. "avalanchego/codec/linearcodec.linearCodec.RegisterType"
+    pub fn register(&mut self, name: &str) -> Result<u32> {
+        let type_id = self.next_type_id;
+        self.register_with_id(name, type_id)?;
+        Ok(type_id)
+    }
+
+    /// Skips the next "n" type IDs, to keep the type IDs
+    /// consistent with the ones that are no longer registered.
+    /// ref. "avalanchego/codec/linearcodec.linearCodec.SkipRegistrations"
+    pub fn skip(&mut self, n: u32) {
+        self.next_type_id += n;
+    }
+
+    fn register_with_id(&mut self, name: &str, type_id: u32) -> Result<()> {
+        if self.type_ids.contains_key(name) {
+            return Err(Error::Other {
+                message: format!("type {} already registered", name),
+                retryable: false,
+            });
+        }
+        if let Some(existing) = self.type_names.get(&type_id) {
+            return Err(Error::Other {
+                message: format!("type ID {} already registered for {}", type_id, existing),
+                retryable: false,
+            });
+        }
+
+        self.type_ids.insert(name.to_string(), type_id);
+        self.type_names.insert(type_id, name.to_string());
+        if type_id >= self.next_type_id {
+            self.next_type_id = type_id + 1;
+        }
+        Ok(())
+    }
+
+    pub fn type_id(&self, name: &str) -> Option<u32> {
+        self.type_ids.get(name).copied()
+    }
+
+    pub fn type_name(&self, type_id: u32) -> Option<&str> {
+        self.type_names.get(&type_id).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.type_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.type_ids.is_empty()
+    }
+
+    /// Returns the type ID of the type name in this version,
+    /// or an error if the type is not registered.
+    pub fn resolve_type_id(&self, name: &str) -> Result<u32> {
+        self.type_id(name).ok_or_else(|| Error::Other {
+            message: format!("type {} not registered", name), // ref. "errMissingType"
+            retryable: false,
+        })
+    }
+
+    /// Returns the type name of the type ID in this version,
+    /// or an error if the type ID is not registered.
+    pub fn resolve_type_name(&self, type_id: u32) -> Result<&str> {
+        self.type_name(type_id).ok_or_else(|| Error::Other {
+            message: format!("type ID {} not registered", type_id), // ref. "errUnknownTypeID"
+            retryable: false,
+        })
+    }
+
+    /// Returns an error if the type ID is not the one registered
+    /// for the type name in this version.
+    pub fn verify_type_id(&self, type_id: u32, name: &str) -> Result<()> {
+        let actual = self.resolve_type_name(type_id)?;
+        if actual != name {
+            return Err(Error::Other {
+                message: format!(
+                    "unexpected type {} for type ID {} (expected {})",
+                    actual, type_id, name
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Manages the type registries of multiple codec versions,
+/// where every message is prefixed with the "u16" codec version.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/codec#Manager>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Manager {
+    /// Max size in bytes of any message, regardless of its version.
+    pub max_size: usize,
+    registries: BTreeMap<u16, Arc<Registry>>,
+}
+
+impl Default for Manager {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Manager {
+    pub fn default() -> Self {
+        Self::new(DEFAULT_MAX_SIZE)
+    }
+
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            max_size,
+            registries: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the type registry for the codec version, which resolves
+    /// the type IDs of the values and their nested interface fields.
+    /// ref. "avalanchego/codec.manager.RegisterCodec"
+    pub fn register(&mut self, version: u16, registry: Registry) -> Result<()> {
+        if self.registries.contains_key(&version) {
+            return Err(Error::Other {
+                message: format!("codec version {} already registered", version), // ref. "errDuplicatedVersion"
+                retryable: false,
+            });
+        }
+        self.registries.insert(version, Arc::new(registry));
+        Ok(())
+    }
+
+    /// Returns the registered codec versions in ascending order.
+    pub fn versions(&self) -> Vec<u16> {
+        self.registries.keys().copied().collect()
+    }
+
+    pub fn registry(&self, version: u16) -> Result<&Registry> {
+        self.shared_registry(version)
+            .map(|registry| registry.as_ref())
+    }
+
+    fn shared_registry(&self, version: u16) -> Result<&Arc<Registry>> {
+        self.registries.get(&version).ok_or_else(|| Error::Other {
+            message: format!("unknown codec version {}", version), // ref. "errUnknownVersion"
+            retryable: false,
+        })
+    }
+
+    /// Returns the max message size for the version, which is the smaller
+    /// one of the manager-wide limit and the per-version limit.
+    pub fn max_size(&self, version: u16) -> Result<usize> {
+        let registry = self.registry(version)?;
+        Ok(self.max_size.min(registry.max_size))
+    }
+
+    fn ensure_size(&self, version: u16, size: usize) -> Result<()> {
+        let max_size = self.max_size(version)?;
+        if size > max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "message size {} exceeds max size {} for codec version {}",
+                    size, max_size, version
+                ), // ref. "errMarshalTooBig", "errUnmarshalTooBig"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Encodes the value prefixed with the codec version, where the nested
+    /// interface fields are encoded with the type IDs of the version.
+    /// ref. "avalanchego/codec.manager.Marshal"
+    pub fn marshal<T: Pack>(&self, version: u16, v: &T) -> Result<Vec<u8>> {
+        let packer = self.new_packer(version)?;
+        packer.pack_u16(version)?;
+        v.pack_to(&packer)?;
+
+        let b = packer.take_bytes();
+        self.ensure_size(version, b.len())?;
+        Ok(b.to_vec())
+    }
+
+    /// Encodes the interface value prefixed with the codec version and its type ID,
+    /// where the type ID is the one registered for its type name in the version.
+    /// Fails if the type is not registered for the version.
+    pub fn marshal_interface<T: Pack + Interface>(&self, version: u16, v: &T) -> Result<Vec<u8>> {
+        let packer = self.new_packer(version)?;
+        packer.pack_u16(version)?;
+        linear::pack_interface(v, &packer)?;
+
+        let b = packer.take_bytes();
+        self.ensure_size(version, b.len())?;
+        Ok(b.to_vec())
+    }
+
+    /// Creates a packer with the registry of the codec version.
+    fn new_packer(&self, version: u16) -> Result<Packer> {
+        let max_size = self.max_size(version)?;
+
+        // ref. "constants.DefaultByteSliceCap" in Go
+        let packer = Packer::new(max_size, 128);
+        Ok(packer.with_registry(self.shared_registry(version)?.clone()))
+    }
+
+    /// Decodes the value, and returns its codec version, where the nested
+    /// interface fields are decoded with the type IDs of the version.
+    /// Fails if the version is not registered, the message exceeds
+    /// the max size of the version, or bytes are left after decoding.
+    /// ref. "avalanchego/codec.manager.Unmarshal"
+    pub fn unmarshal<T: Unpack>(&self, d: &[u8]) -> Result<(u16, T)> {
+        self.unmarshal_with(d, |_, packer| T::unpack_from(packer))
+    }
+
+    /// Decodes the interface value prefixed with its type ID,
+    /// and returns its codec version. Fails if the type ID is not
+    /// the one registered for the type name in the version.
+    pub fn unmarshal_interface<T: Unpack + Interface>(&self, d: &[u8]) -> Result<(u16, T)> {
+        self.unmarshal_with(d, |registry, packer| {
+            registry.verify_type_id(packer.unpack_u32()?, &T::interface_type_name())?;
+            T::unpack_from(packer)
+        })
+    }
+
+    /// Decodes the message with the function, which is given the registry
+    /// of the codec version and the packer positioned after the version prefix.
+    /// Fails if the version is not registered, the message exceeds the
+    /// max size of the version, or bytes are left after decoding.
+    pub fn unmarshal_with<T>(
+        &self,
+        d: &[u8],
+        f: impl FnOnce(&Registry, &Packer) -> Result<T>,
+    ) -> Result<(u16, T)> {
+        let (version, packer) = self.load(d)?;
+        let v = f(self.registry(version)?, &packer)?;
+        ensure_fully_unpacked(&packer)?;
+        Ok((version, v))
+    }
+
+    /// Returns the type name of the interface value that follows the codec
+    /// version prefix, as registered in the registry of the version.
+    pub fn type_name_of(&self, d: &[u8]) -> Result<String> {
+        let (version, packer) = self.load(d)?;
+        let type_id = packer.unpack_u32()?;
+        let name = self.registry(version)?.resolve_type_name(type_id)?;
+        Ok(name.to_string())
+    }
+
+    /// Reads the codec version prefix and validates the message size.
+    fn load(&self, d: &[u8]) -> Result<(u16, Packer)> {
+        if d.len() > self.max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "message size {} exceeds max size {}",
+                    d.len(),
+                    self.max_size
+                ), // ref. "errUnmarshalTooBig"
+                retryable: false,
+            });
+        }
+
+        let packer = Packer::load_bytes_for_unpack(d.len(), d);
+        let version = packer.unpack_u16()?;
+        self.ensure_size(version, d.len())?;
+        let packer = packer.with_registry(self.shared_registry(version)?.clone());
+        Ok((version, packer))
+    }
+}
+
+/// ref. "avalanchego/codec.errExtraSpace"
+fn ensure_fully_unpacked(packer: &Packer) -> Result<()> {
+    let (offset, n) = (packer.get_offset(), packer.bytes_len());
+    if offset != n {
+        return Err(Error::Other {
+            message: format!(
+                "trailing buffer space; unpacked {} bytes out of {} bytes",
+                offset, n
+            ),
+            retryable: false,
+        });
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- codec::manager::test_manager --exact --show-output
+#[test]
+fn test_manager() {
+    use crate::{codec, ids::short, key, platformvm};
+
+    let mut registry_v0 = Registry::from_types(DEFAULT_MAX_SIZE, &codec::P_TYPES).unwrap();
+    assert_eq!(registry_v0.len(), codec::P_TYPES.len());
+    assert_eq!(registry_v0.type_id("secp256k1fx.OutputOwners"), Some(11));
+    assert_eq!(
+        registry_v0.type_name(22),
+        Some("platformvm.StakeableLockOut")
+    );
+    assert!(registry_v0.register("signer.Empty").is_err());
+    assert_eq!(
+        registry_v0.register("platformvm.NewTx").unwrap(),
+        codec::P_TYPES.len() as u32
+    );
+
+    let mut registry_v1 = Registry::new(64);
+    registry_v1.skip(11);
+    assert_eq!(
+        registry_v1.register("secp256k1fx.OutputOwners").unwrap(),
+        11
+    );
+
+    let mut manager = Manager::default();
+    manager.register(0, registry_v0).unwrap();
+    manager.register(1, registry_v1).unwrap();
+    assert!(manager.register(1, Registry::default()).is_err());
+    assert_eq!(manager.versions(), vec![0, 1]);
+    assert_eq!(manager.max_size(1).unwrap(), 64);
+
+    let owners = key::secp256k1::txs::OutputOwners {
+        locktime: 1,
+        threshold: 1,
+        addresses: vec![short::Id::from_slice(&[1; 20])],
+    };
+    for version in [0, 1] {
+        let b = manager.marshal_interface(version, &owners).unwrap();
+        assert_eq!(&b[..2], &version.to_be_bytes());
+        let (v, decoded) = manager
+            .unmarshal_interface::<key::secp256k1::txs::OutputOwners>(&b)
+            .unwrap();
+        assert_eq!(v, version);
+        assert_eq!(decoded, owners);
+
+        let b = manager.marshal(version, &owners).unwrap();
+        let (v, decoded) = manager
+            .unmarshal::<key::secp256k1::txs::OutputOwners>(&b)
+            .unwrap();
+        assert_eq!(v, version);
+        assert_eq!(decoded, owners);
+
+        // trailing bytes
+        let mut b = b;
+        b.push(0);
+        assert!(manager
+            .unmarshal::<key::secp256k1::txs::OutputOwners>(&b)
+            .is_err());
+    }
+
+    // unknown version
+    assert!(manager.marshal(2, &owners).is_err());
+    let mut b = manager.marshal(0, &owners).unwrap();
+    b[1] = 2;
+    assert!(manager
+        .unmarshal::<key::secp256k1::txs::OutputOwners>(&b)
+        .is_err());
+
+    // exceeds the per-version max size
+    let large = key::secp256k1::txs::OutputOwners {
+        addresses: vec![short::Id::from_slice(&[1; 20]); 4],
+        ..owners.clone()
+    };
+    assert!(manager.marshal(1, &large).is_err());
+    let b = manager.marshal(0, &large).unwrap();
+    let mut b1 = b.clone();
+    b1[1] = 1;
+    assert!(manager
+        .unmarshal::<key::secp256k1::txs::OutputOwners>(&b1)
+        .is_err());
+
+    // new types in the version
+    let mut registry_v2 = Registry::new(DEFAULT_MAX_SIZE);
+    registry_v2.skip(codec::P_TYPES.len() as u32 + 1);
+    assert_eq!(
+        registry_v2.register("platformvm.NewerTx").unwrap(),
+        codec::P_TYPES.len() as u32 + 1
+    );
+    manager.register(2, registry_v2).unwrap();
+    assert!(manager.marshal_interface(2, &owners).is_err());
+
+    // version 0 type ID is not registered in version 2
+    let mut b_v2 = manager.marshal_interface(0, &owners).unwrap();
+    assert_eq!(&b_v2[2..6], &[0x00, 0x00, 0x00, 0x0b]);
+    b_v2[1] = 2;
+    assert!(manager
+        .unmarshal_interface::<key::secp256k1::txs::OutputOwners>(&b_v2)
+        .is_err());
+    assert!(manager.type_name_of(&b_v2).is_err());
+
+    // nested interface fields with the type IDs of the version
+    let mut registry_v3 = Registry::new(DEFAULT_MAX_SIZE);
+    registry_v3.skip(100);
+    let mut types: Vec<_> = codec::P_TYPES.iter().collect();
+    types.sort_by_key(|(_, type_id)| **type_id);
+    for (name, type_id) in types {
+        assert_eq!(registry_v3.register(name).unwrap(), 100 + *type_id as u32);
+    }
+    manager.register(3, registry_v3).unwrap();
+
+    let lock_out = platformvm::txs::StakeableLockOut {
+        locktime: 1,
+        transfer_output: key::secp256k1::txs::transfer::Output {
+            amount: 1,
+            output_owners: owners.clone(),
+        },
+    };
+    let b_v3 = manager.marshal_interface(3, &lock_out).unwrap();
+    assert_eq!(&b_v3[2..6], &122_u32.to_be_bytes());
+    assert_eq!(&b_v3[14..18], &107_u32.to_be_bytes());
+    let (v, decoded) = manager
+        .unmarshal_interface::<platformvm::txs::StakeableLockOut>(&b_v3)
+        .unwrap();
+    assert_eq!(v, 3);
+    assert_eq!(decoded, lock_out);
+
+    let b_v0 = manager.marshal(0, &lock_out).unwrap();
+    assert_eq!(&b_v0[10..14], &7_u32.to_be_bytes());
+    let mut b_v3 = manager.marshal(3, &lock_out).unwrap();
+    assert_eq!(&b_v3[10..14], &107_u32.to_be_bytes());
+    assert_eq!(
+        manager
+            .unmarshal::<platformvm::txs::StakeableLockOut>(&b_v3)
+            .unwrap(),
+        (3, lock_out.clone())
+    );
+    b_v3[1] = 0;
+    assert!(manager
+        .unmarshal::<platformvm::txs::StakeableLockOut>(&b_v3)
+        .is_err());
+
+    // type ID of another type in the version
+    let b_v2 = manager
+        .marshal_interface(0, &key::secp256k1::txs::Credential::default())
+        .unwrap();
+    assert!(manager
+        .unmarshal_interface::<key::secp256k1::txs::OutputOwners>(&b_v2)
+        .is_err());
+
+    // not registered type ID
+    let mut manager = Manager::new(1024);
+    manager.register(0, Registry::default()).unwrap();
+    assert!(manager.marshal_interface(0, &owners).is_err());
+    assert!(manager
+        .unmarshal::<key::secp256k1::txs::OutputOwners>(&b)
+        .is_ok());
+}
@@ -1,3 +1,5 @@
+pub mod linear;
+pub mod manager;
 pub mod serde;
 
 use std::collections::HashMap;
@@ -76,6 +78,38 @@ lazy_static! {
         m.insert("signer.Empty".to_string(), 27);
         m.insert("signer.ProofOfPossession".to_string(), 28);
 
+        m.insert("platformvm.BanffProposalBlock".to_string(), 29);
+        m.insert("platformvm.BanffAbortBlock".to_string(), 30);
+        m.insert("platformvm.BanffCommitBlock".to_string(), 31);
+        m.insert("platformvm.BanffStandardBlock".to_string(), 32);
+
+        // Durango additions
+        m.insert("platformvm.TransferSubnetOwnershipTx".to_string(), 33);
+        m.insert("platformvm.BaseTx".to_string(), 34);
+
+        m
+    };
+
+    /// Codec manager of the X-chain txs, with "X_TYPES" as "VERSION".
+    /// ref. "avalanchego/vms/avm/txs.Parser"
+    pub static ref X_CODEC: manager::Manager = {
+        let registry = manager::Registry::from_types(manager::DEFAULT_MAX_SIZE, &X_TYPES)
+            .expect("failed to register X_TYPES");
+        let mut m = manager::Manager::default();
+        m.register(VERSION, registry)
+            .expect("failed to register X-chain codec version");
+        m
+    };
+
+    /// Codec manager of the P-chain txs and blocks, with "P_TYPES" as "VERSION".
+    /// ref. "avalanchego/vms/platformvm/txs.Codec"
+    /// ref. "avalanchego/vms/platformvm/blocks.Codec"
+    pub static ref P_CODEC: manager::Manager = {
+        let registry = manager::Registry::from_types(manager::DEFAULT_MAX_SIZE, &P_TYPES)
+            .expect("failed to register P_TYPES");
+        let mut m = manager::Manager::default();
+        m.register(VERSION, registry)
+            .expect("failed to register P-chain codec version");
         m
     };
 }
@@ -15,6 +15,7 @@ lazy_static! {
         m.insert(3, "denali");
         m.insert(4, "everest");
         m.insert(5, "fuji");
+        m.insert(10, "testing");
         m.insert(12345, "local");
         m
     };
@@ -27,6 +28,8 @@ lazy_static! {
         m.insert("denali", 3);
         m.insert("everest", 4);
         m.insert("fuji", 5);
+        m.insert("testnet", 5);
+        m.insert("testing", 10);
         m.insert("local", 12345);
         m
     };
@@ -39,6 +42,7 @@ lazy_static! {
         m.insert(3, "denali");
         m.insert(4, "everest");
         m.insert(5, "fuji");
+        m.insert(10, "testing");
         m.insert(12345, "local");
         m
     };
@@ -51,6 +55,7 @@ lazy_static! {
         m.insert("denali", 3);
         m.insert("everest", 4);
         m.insert("fuji", 5);
+        m.insert("testing", 10);
         m.insert("local", 12345);
         m
     };
@@ -70,7 +70,6 @@ where
 
     /// Returns "None" if the threshold is NOT met.
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Spend>
-    /// TODO: support spend on "secp256k1fx::MintOutput"
     pub fn spend(
         &self,
         output: &key::secp256k1::txs::transfer::Output,
@@ -91,4 +90,16 @@ where
             keys,
         ))
     }
+
+    /// Spends the "secp256k1fx.MintOutput" to exercise the minting right.
+    /// Returns "None" if the threshold is NOT met.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Keychain.Spend>
+    pub fn spend_mint(
+        &self,
+        output: &key::secp256k1::txs::mint::Output,
+        time: u64,
+    ) -> Option<(key::secp256k1::txs::Input, Vec<T>)> {
+        let (sig_indices, keys) = self.match_threshold(&output.output_owners, time)?;
+        Some((key::secp256k1::txs::Input { sig_indices }, keys))
+    }
 }
@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+
+use crate::{codec, errors, impl_linear_codec, key};
+use serde::{Deserialize, Serialize};
+
+/// Represents the minting right of an asset, owned by the "output_owners".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOutput>
+#[derive(Debug, Serialize, Deserialize, Eq, Clone)]
+pub struct Output {
+    /// The custom de/serializer embeds "output_owners" at the top level as in avalanchego.
+    #[serde(flatten)]
+    pub output_owners: key::secp256k1::txs::OutputOwners,
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Output {
+    pub fn default() -> Self {
+        Self {
+            output_owners: key::secp256k1::txs::OutputOwners::default(),
+        }
+    }
+
+    pub fn new(output_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        Self { output_owners }
+    }
+
+    pub fn type_name() -> String {
+        "secp256k1fx.MintOutput".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/secp256k1fx.MintOutput.Verify"
+    pub fn verify_syntax(&self) -> errors::Result<()> {
+        self.output_owners.verify_syntax()
+    }
+}
+
+// "secp256k1fx.MintOutput.OutputOwners" is embedded inline
+impl_linear_codec!(Output, interface { output_owners });
+
+impl Ord for Output {
+    fn cmp(&self, other: &Output) -> Ordering {
+        self.output_owners.cmp(&(other.output_owners))
+    }
+}
+
+impl PartialOrd for Output {
+    fn partial_cmp(&self, other: &Output) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Output {
+    fn eq(&self, other: &Output) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Consumes the minting right to mint the new supply to the "transfer_output",
+/// while producing the "mint_output" to keep the minting right.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOperation>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Operation {
+    #[serde(rename = "mintInput")]
+    pub mint_input: key::secp256k1::txs::Input,
+    #[serde(rename = "mintOutput")]
+    pub mint_output: Output,
+    #[serde(rename = "transferOutput")]
+    pub transfer_output: key::secp256k1::txs::transfer::Output,
+}
+
+impl Default for Operation {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Operation {
+    pub fn default() -> Self {
+        Self {
+            mint_input: key::secp256k1::txs::Input::default(),
+            mint_output: Output::default(),
+            transfer_output: key::secp256k1::txs::transfer::Output::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "secp256k1fx.MintOperation".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/secp256k1fx.MintOperation.Verify"
+    pub fn verify_syntax(&self) -> errors::Result<()> {
+        self.mint_input.verify_syntax()?;
+        self.mint_output.verify_syntax()?;
+        self.transfer_output.verify_syntax()
+    }
+}
+
+// all fields are structs thus embedded inline without the type IDs
+impl_linear_codec!(
+    Operation,
+    interface {
+        mint_input,
+        mint_output,
+        transfer_output,
+    }
+);
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::mint::test_mint_output_codec --exact --show-output
+#[test]
+fn test_mint_output_codec() {
+    use crate::{ids::short, packer};
+
+    let out = Output::new(key::secp256k1::txs::OutputOwners::new(
+        1,
+        1,
+        &[short::Id::from_slice(&[0xaa; 20])],
+    ));
+    assert_eq!(Output::type_id(), 6);
+    assert!(out.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&out, &packer).unwrap();
+    let packed = packer.take_bytes();
+
+    let mut expected: Vec<u8> = vec![
+        // secp256k1fx.MintOutput type ID
+        0x00, 0x00, 0x00, 0x06, //
+        // locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, //
+        // threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xaa; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: Output = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(out, unpacked);
+
+    let json_encoded = serde_json::to_string(&out).unwrap();
+    let json_decoded: Output = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(out, json_decoded);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::mint::test_mint_operation_codec --exact --show-output
+#[test]
+fn test_mint_operation_codec() {
+    use crate::{ids::short, packer};
+
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0xbb; 20])]);
+    let op = Operation {
+        mint_input: key::secp256k1::txs::Input::new(vec![0]),
+        mint_output: Output::new(owners.clone()),
+        transfer_output: key::secp256k1::txs::transfer::Output::new(500, owners),
+    };
+    assert_eq!(Operation::type_id(), 8);
+    assert!(op.verify_syntax().is_ok());
+
+    let packer = packer::Packer::new(1024, 0);
+    codec::linear::pack_interface(&op, &packer).unwrap();
+    let packed = packer.take_bytes();
+
+    let mut expected: Vec<u8> = vec![
+        // secp256k1fx.MintOperation type ID
+        0x00, 0x00, 0x00, 0x08, //
+        // number of signature indices
+        0x00, 0x00, 0x00, 0x01, //
+        // signature index
+        0x00, 0x00, 0x00, 0x00, //
+        // mint output locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // mint output threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ];
+    expected.extend_from_slice(&[0xbb; 20]);
+    expected.extend_from_slice(&[
+        // transfer output amount
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xf4, //
+        // transfer output locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        // transfer output threshold
+        0x00, 0x00, 0x00, 0x01, //
+        // number of addresses
+        0x00, 0x00, 0x00, 0x01, //
+    ]);
+    expected.extend_from_slice(&[0xbb; 20]);
+    assert_eq!(&packed[..], &expected[..]);
+
+    let packer = packer::Packer::load_bytes_for_unpack(packed.len() + 1024, &packed);
+    let unpacked: Operation = codec::linear::unpack_interface(&packer).unwrap();
+    assert_eq!(op, unpacked);
+
+    let mut invalid = op.clone();
+    invalid.transfer_output.amount = 0;
+    assert!(invalid.verify_syntax().is_err());
+}
@@ -1,14 +1,23 @@
+pub mod mint;
 pub mod transfer;
 
 use std::cmp::Ordering;
 
 use crate::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
-    ids::short,
+    errors::{Error, Result},
+    ids::{self, short},
+    impl_linear_codec, key, packer,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+/// Returns the ID of the secp256k1fx feature extension.
+/// ref. "avalanchego/vms/secp256k1fx.ID"
+pub fn fx_id() -> ids::Id {
+    ids::Id::from_slice(b"secp256k1fx")
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/fxs#FxCredential>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/verify#Verifiable>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Credential>
@@ -45,6 +54,58 @@ impl Credential {
     pub fn type_id() -> u32 {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// Verifies the credential is well-formed.
+    /// ref. "avalanchego/vms/secp256k1fx.Credential.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        for sig in self.signatures.iter() {
+            if sig.len() != key::secp256k1::signature::LEN {
+                return Err(Error::Other {
+                    message: format!(
+                        "signature has {} bytes (expected {})",
+                        sig.len(),
+                        key::secp256k1::signature::LEN
+                    ),
+                    retryable: false,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Each signature is a fixed-size "[65]byte" thus not prefixed with the length.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Credential>
+impl codec::linear::Pack for Credential {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        packer.pack_u32(self.signatures.len() as u32)?;
+        for sig in self.signatures.iter() {
+            packer.pack_bytes(sig)?;
+        }
+        Ok(())
+    }
+}
+
+impl codec::linear::Unpack for Credential {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let sigs_len = packer.unpack_u32()?;
+        let mut signatures: Vec<Vec<u8>> = Vec::new();
+        for _ in 0..sigs_len {
+            let sig = packer.unpack_bytes(key::secp256k1::signature::LEN)?;
+            signatures.push(sig);
+        }
+        Ok(Self { signatures })
+    }
+}
+
+impl codec::linear::Interface for Credential {
+    fn interface_type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn interface_type_name() -> String {
+        Self::type_name()
+    }
 }
 
 impl Ord for Credential {
@@ -269,8 +330,43 @@ impl OutputOwners {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// Verifies the owners can spend the output, in the canonical form.
+    /// ref. "avalanchego/vms/secp256k1fx.OutputOwners.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.threshold as usize > self.addresses.len() {
+            return Err(Error::Other {
+                message: "output is unspendable".to_string(), // ref. "ErrOutputUnspendable"
+                retryable: false,
+            });
+        }
+        if self.threshold == 0 && !self.addresses.is_empty() {
+            return Err(Error::Other {
+                message: "output representation should be optimized".to_string(), // ref. "ErrOutputUnoptimized"
+                retryable: false,
+            });
+        }
+        if !cmp_manager::is_sorted_and_unique(&self.addresses) {
+            return Err(Error::Other {
+                message: "addresses not sorted and unique".to_string(), // ref. "ErrAddrsNotSortedUnique"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
 }
 
+// "OutputOwners" is embedded inline in "secp256k1fx.TransferOutput"
+// and only prefixed with the type ID when used as "fx.Owner"
+impl_linear_codec!(
+    OutputOwners,
+    interface {
+        locktime,
+        threshold,
+        addresses,
+    }
+);
+
 impl Ord for OutputOwners {
     fn cmp(&self, other: &OutputOwners) -> Ordering {
         self.locktime
@@ -394,8 +490,21 @@ impl Input {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// ref. "avalanchego/vms/secp256k1fx.Input.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if !cmp_manager::is_sorted_and_unique(&self.sig_indices) {
+            return Err(Error::Other {
+                message: "address indices not sorted and unique".to_string(), // ref. "ErrInputIndicesNotSortedUnique"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
 }
 
+impl_linear_codec!(Input, interface { sig_indices });
+
 impl Ord for Input {
     fn cmp(&self, other: &Input) -> Ordering {
         SigIndices::new(&self.sig_indices).cmp(&SigIndices::new(&other.sig_indices))
@@ -449,3 +558,34 @@ fn test_sort_inputs() {
     assert!(cmp_manager::is_sorted_and_unique(&sorted_inputs));
     assert_eq!(inputs, sorted_inputs);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::test_verify_syntax --exact --show-output
+#[test]
+fn test_verify_syntax() {
+    let addr1 = short::Id::from_slice(&[1]);
+    let addr2 = short::Id::from_slice(&[2]);
+
+    assert!(OutputOwners::new(0, 1, &[addr1.clone(), addr2.clone()])
+        .verify_syntax()
+        .is_ok());
+    assert!(OutputOwners::new(0, 0, &[]).verify_syntax().is_ok());
+    assert!(OutputOwners::new(0, 3, &[addr1.clone(), addr2.clone()])
+        .verify_syntax()
+        .is_err());
+    assert!(OutputOwners::new(0, 0, &[addr1.clone()])
+        .verify_syntax()
+        .is_err());
+    assert!(OutputOwners::new(0, 1, &[addr2.clone(), addr1.clone()])
+        .verify_syntax()
+        .is_err());
+    assert!(OutputOwners::new(0, 1, &[addr1.clone(), addr1.clone()])
+        .verify_syntax()
+        .is_err());
+
+    assert!(Input::new(vec![0, 1, 5]).verify_syntax().is_ok());
+    assert!(Input::new(vec![1, 0]).verify_syntax().is_err());
+    assert!(Input::new(vec![1, 1]).verify_syntax().is_err());
+
+    assert!(Credential::new(vec![vec![0; 65]]).verify_syntax().is_ok());
+    assert!(Credential::new(vec![vec![0; 64]]).verify_syntax().is_err());
+}
@@ -3,7 +3,7 @@ use std::{
     io::{self, Error, ErrorKind},
 };
 
-use crate::{codec, key};
+use crate::{codec, errors, impl_linear_codec, key};
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
@@ -47,6 +47,17 @@ impl Output {
     pub fn type_id() -> u32 {
         *(codec::X_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// ref. "avalanchego/vms/secp256k1fx.TransferOutput.Verify"
+    pub fn verify_syntax(&self) -> errors::Result<()> {
+        if self.amount == 0 {
+            return Err(errors::Error::Other {
+                message: "output has no value".to_string(), // ref. "ErrNoValueOutput"
+                retryable: false,
+            });
+        }
+        self.output_owners.verify_syntax()
+    }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::transfer::test_transfer_output_custom_de_serializer --exact --show-output
@@ -74,6 +85,15 @@ fn test_transfer_output_custom_de_serializer() {
     assert_eq!(d, json_decoded);
 }
 
+// "secp256k1fx.TransferOutput.OutputOwners" is embedded inline
+impl_linear_codec!(
+    Output,
+    interface {
+        amount,
+        output_owners,
+    }
+);
+
 impl Ord for Output {
     fn cmp(&self, other: &Output) -> Ordering {
         self.amount
@@ -272,6 +292,23 @@ impl Input {
         Ok(())
     }
 
+    /// ref. "avalanchego/vms/secp256k1fx.TransferInput.Verify"
+    pub fn verify_syntax(&self) -> errors::Result<()> {
+        if self.amount == 0 {
+            return Err(errors::Error::Other {
+                message: "input has no value".to_string(), // ref. "ErrNoValueInput"
+                retryable: false,
+            });
+        }
+        if !cmp_manager::is_sorted_and_unique(&self.sig_indices) {
+            return Err(errors::Error::Other {
+                message: "address indices not sorted and unique".to_string(), // ref. "ErrInputIndicesNotSortedUnique"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
     /// ref. "vms/secp256k1fx.Input.Cost"
     pub fn sig_costs(&self) -> u64 {
         let sigs = self.sig_indices.len();
@@ -279,6 +316,15 @@ impl Input {
     }
 }
 
+// "secp256k1fx.TransferInput.Input" is embedded inline
+impl_linear_codec!(
+    Input,
+    interface {
+        amount,
+        sig_indices
+    }
+);
+
 impl Ord for Input {
     fn cmp(&self, other: &Input) -> Ordering {
         self.amount
@@ -365,3 +411,22 @@ fn test_sort_transfer_inputs() {
     assert!(cmp_manager::is_sorted_and_unique(&sorted_inputs));
     assert_eq!(inputs, sorted_inputs);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::transfer::test_verify_syntax --exact --show-output
+#[test]
+fn test_verify_syntax() {
+    use crate::ids::short;
+
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[1])]);
+    assert!(Output::new(1, owners.clone()).verify_syntax().is_ok());
+    assert!(Output::new(0, owners).verify_syntax().is_err());
+    assert!(
+        Output::new(1, key::secp256k1::txs::OutputOwners::new(0, 2, &[]))
+            .verify_syntax()
+            .is_err()
+    );
+
+    assert!(Input::new(1, vec![0, 1]).verify_syntax().is_ok());
+    assert!(Input::new(0, vec![0]).verify_syntax().is_err());
+    assert!(Input::new(1, vec![1, 0]).verify_syntax().is_err());
+}
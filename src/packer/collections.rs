@@ -0,0 +1,229 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+    hash::Hash,
+};
+
+use crate::{
+    codec::linear::{Pack, Unpack},
+    errors::{Error, Result},
+    packer::Packer,
+};
+
+impl Packer {
+    /// Writes the slice prefixed with the "u32" length.
+    /// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal" for "reflect.Slice"
+    pub fn pack_slice<T: Pack>(&self, v: &[T]) -> Result<()> {
+        self.pack_u32(v.len() as u32)?;
+        T::pack_slice(v, self)
+    }
+
+    /// Unpacks the slice prefixed with the "u32" length.
+    pub fn unpack_slice<T: Unpack>(&self) -> Result<Vec<T>> {
+        let n = self.unpack_u32()?;
+        T::unpack_vec(n as usize, self)
+    }
+
+    /// Writes the fixed-length array without the length prefix.
+    /// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal" for "reflect.Array"
+    pub fn pack_fixed_array<T: Pack, const N: usize>(&self, v: &[T; N]) -> Result<()> {
+        T::pack_slice(v, self)
+    }
+
+    /// Unpacks the fixed-length array whose length is known from the type.
+    pub fn unpack_fixed_array<T: Unpack, const N: usize>(&self) -> Result<[T; N]> {
+        let v = T::unpack_vec(N, self)?;
+        v.try_into().map_err(|v: Vec<T>| Error::Other {
+            message: format!("unexpected array length {} (expected {})", v.len(), N),
+            retryable: false,
+        })
+    }
+
+    /// Writes the optional value with the "bool" prefix,
+    /// followed by the value only if it is "Some".
+    /// Go codec has no optional type, so this follows the "maybe.Maybe" encoding
+    /// (e.g., "avalanchego/x/merkledb" codec).
+    pub fn pack_option<T: Pack>(&self, v: &Option<T>) -> Result<()> {
+        match v {
+            Some(v) => {
+                self.pack_bool(true)?;
+                v.pack_to(self)
+            }
+            None => self.pack_bool(false),
+        }
+    }
+
+    /// Unpacks the optional value with the "bool" prefix.
+    pub fn unpack_option<T: Unpack>(&self) -> Result<Option<T>> {
+        if self.unpack_bool()? {
+            Ok(Some(T::unpack_from(self)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Writes the map entries prefixed with the "u32" length.
+    /// Each key is followed by its value, and entries are sorted by the
+    /// packed key bytes, so that the same map always encodes the same bytes.
+    /// ref. "avalanchego/codec/reflectcodec.genericCodec.marshal" for "reflect.Map"
+    pub fn pack_map<'a, K, V, I>(&self, entries: I) -> Result<()>
+    where
+        K: Pack + 'a,
+        V: Pack + 'a,
+        I: IntoIterator<Item = (&'a K, &'a V)>,
+    {
+        let mut packed: Vec<(Vec<u8>, &V)> = Vec::new();
+        for (k, v) in entries {
+            let key_packer = Packer::new(self.max_size, 0);
+            k.pack_to(&key_packer)?;
+            packed.push((key_packer.take_bytes().to_vec(), v));
+        }
+        packed.sort_by(|a, b| a.0.cmp(&b.0));
+
+        self.pack_u32(packed.len() as u32)?;
+        for (k, v) in packed.iter() {
+            self.pack_bytes(k)?;
+            v.pack_to(self)?;
+        }
+        Ok(())
+    }
+
+    /// Unpacks the map entries in the canonical order.
+    /// Returns an error if the packed keys are not strictly increasing,
+    /// which rejects both unsorted and duplicate keys.
+    /// ref. "avalanchego/codec/reflectcodec.genericCodec.unmarshal" for "reflect.Map"
+    pub fn unpack_map<K: Unpack, V: Unpack>(&self) -> Result<Vec<(K, V)>> {
+        let n = self.unpack_u32()?;
+
+        let mut entries: Vec<(K, V)> = Vec::new();
+        let mut prev_key: Option<Vec<u8>> = None;
+        for _ in 0..n {
+            let start = self.get_offset();
+            let k = K::unpack_from(self)?;
+            let key = self.copy_range(start, self.get_offset());
+            if let Some(prev) = &prev_key {
+                if prev >= &key {
+                    return Err(Error::Other {
+                        message: "map keys are not sorted or have duplicates".to_string(),
+                        retryable: false,
+                    });
+                }
+            }
+            prev_key = Some(key);
+
+            let v = V::unpack_from(self)?;
+            entries.push((k, v));
+        }
+        Ok(entries)
+    }
+
+    /// Copies the bytes between the two offsets.
+    fn copy_range(&self, start: usize, end: usize) -> Vec<u8> {
+        // "BytesMut" does not implement "Copy" so take/update/set it back
+        let b = self.bytes.take();
+        let v = Vec::from(&b[start..end]);
+        self.bytes.set(b);
+        v
+    }
+}
+
+impl<T: Pack, const N: usize> Pack for [T; N] {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_fixed_array(self)
+    }
+}
+
+impl<T: Unpack, const N: usize> Unpack for [T; N] {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        packer.unpack_fixed_array()
+    }
+}
+
+impl<K: Pack, V: Pack> Pack for BTreeMap<K, V> {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_map(self.iter())
+    }
+}
+
+impl<K: Unpack + Ord, V: Unpack> Unpack for BTreeMap<K, V> {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        Ok(packer.unpack_map()?.into_iter().collect())
+    }
+}
+
+impl<K: Pack, V: Pack> Pack for HashMap<K, V> {
+    fn pack_to(&self, packer: &Packer) -> Result<()> {
+        packer.pack_map(self.iter())
+    }
+}
+
+impl<K: Unpack + Eq + Hash, V: Unpack> Unpack for HashMap<K, V> {
+    fn unpack_from(packer: &Packer) -> Result<Self> {
+        Ok(packer.unpack_map()?.into_iter().collect())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::collections::test_collections --exact --show-output
+#[test]
+fn test_collections() {
+    let packer = Packer::new(1024, 0);
+
+    packer.pack_slice(&[1_u16, 2]).unwrap();
+    packer.pack_fixed_array(&[3_u32, 4]).unwrap();
+    packer.pack_option(&Some(5_u8)).unwrap();
+    packer.pack_option::<u8>(&None).unwrap();
+    packer.pack_slice(&[vec![6_u8], vec![7_u8, 8_u8]]).unwrap();
+
+    // keys are sorted by the packed bytes, so "b" (length 1) comes before "aa"
+    let mut m: HashMap<String, u8> = HashMap::new();
+    m.insert("aa".to_string(), 9);
+    m.insert("b".to_string(), 10);
+    m.insert("ab".to_string(), 11);
+    packer.pack_map(m.iter()).unwrap();
+
+    let b = packer.take_bytes();
+    let expected: Vec<u8> = vec![
+        0, 0, 0, 2, 0, 1, 0, 2, // slice
+        0, 0, 0, 3, 0, 0, 0, 4, // fixed array
+        1, 5, 0, // options
+        0, 0, 0, 2, 0, 0, 0, 1, 6, 0, 0, 0, 2, 7, 8, // nested slices
+        0, 0, 0, 3, // map length
+        0, 1, b'b', 10, //
+        0, 2, b'a', b'a', 9, //
+        0, 2, b'a', b'b', 11,
+    ];
+    assert_eq!(b.as_ref(), expected.as_slice());
+
+    let packer = Packer::load_bytes_for_unpack(1024, &b);
+    assert_eq!(packer.unpack_slice::<u16>().unwrap(), vec![1, 2]);
+    assert_eq!(packer.unpack_fixed_array::<u32, 2>().unwrap(), [3, 4]);
+    assert_eq!(packer.unpack_option::<u8>().unwrap(), Some(5));
+    assert_eq!(packer.unpack_option::<u8>().unwrap(), None);
+    assert_eq!(
+        packer.unpack_slice::<Vec<u8>>().unwrap(),
+        vec![vec![6], vec![7, 8]]
+    );
+    assert_eq!(HashMap::<String, u8>::unpack_from(&packer).unwrap(), m);
+    assert_eq!(packer.get_offset(), b.len());
+
+    // same bytes regardless of the map type
+    let bm: BTreeMap<String, u8> = m.clone().into_iter().collect();
+    let packer = Packer::new(1024, 0);
+    bm.pack_to(&packer).unwrap();
+    let b2 = packer.take_bytes();
+    assert_eq!(b2.as_ref(), &expected[34..]);
+
+    // unsorted keys
+    let unsorted: Vec<u8> = vec![0, 0, 0, 2, 0, 2, b'a', b'a', 9, 0, 1, b'b', 10];
+    let packer = Packer::load_bytes_for_unpack(1024, &unsorted);
+    assert!(BTreeMap::<String, u8>::unpack_from(&packer).is_err());
+
+    // duplicate keys
+    let duplicate: Vec<u8> = vec![0, 0, 0, 2, 0, 1, b'b', 9, 0, 1, b'b', 10];
+    let packer = Packer::load_bytes_for_unpack(1024, &duplicate);
+    assert!(BTreeMap::<String, u8>::unpack_from(&packer).is_err());
+
+    // invalid optional prefix
+    let packer = Packer::load_bytes_for_unpack(1024, &[2, 1]);
+    assert!(packer.unpack_option::<u8>().is_err());
+}
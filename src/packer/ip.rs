@@ -119,6 +119,7 @@ fn test_pack_and_unpack() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     packer
@@ -142,6 +143,7 @@ fn test_pack_and_unpack() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_ip().unwrap();
     assert_eq!(packer.get_offset(), IP_LEN);
@@ -172,6 +174,7 @@ fn test_packs_and_unpacks() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     packer
@@ -201,6 +204,7 @@ fn test_packs_and_unpacks() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_ips().unwrap();
     assert_eq!(packer.get_offset(), packer::U32_LEN + IP_LEN * 3);
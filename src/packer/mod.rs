@@ -1,8 +1,18 @@
+pub mod collections;
 pub mod ip;
+pub mod reader;
+pub mod stream;
 
-use std::{cell::Cell, u16};
+#[cfg(feature = "packer_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "packer_async")))]
+pub mod stream_async;
 
-use crate::errors::{Error, Result};
+use std::{cell::Cell, sync::Arc, u16};
+
+use crate::{
+    codec::manager::Registry,
+    errors::{Error, Result},
+};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
 pub const MAX_STR_LEN: u16 = u16::MAX - 1;
@@ -46,6 +56,10 @@ pub struct Packer {
     header: bool,
     /// offset that is being written to in the byte array
     offset: Cell<usize>,
+    /// Type registry of the codec version being packed or unpacked,
+    /// to resolve the type IDs of the nested interface fields.
+    /// If "None", the type IDs of "codec::VERSION" are used.
+    registry: Option<Arc<Registry>>,
 }
 
 impl Packer {
@@ -56,6 +70,7 @@ impl Packer {
             bytes,
             header: false,
             offset: Cell::new(0),
+            registry: None,
         }
     }
 
@@ -70,6 +85,7 @@ impl Packer {
             bytes,
             header: true,
             offset,
+            registry: None,
         }
     }
 
@@ -81,6 +97,7 @@ impl Packer {
             bytes: Cell::new(BytesMut::from(b)),
             header: false,
             offset: Cell::new(b.len()),
+            registry: None,
         }
     }
 
@@ -92,9 +109,23 @@ impl Packer {
             bytes: Cell::new(BytesMut::from(b)),
             header: false,
             offset: Cell::new(0),
+            registry: None,
         }
     }
 
+    /// Sets the type registry of the codec version, which the nested
+    /// interface fields are packed and unpacked with.
+    #[must_use]
+    pub fn with_registry(mut self, registry: Arc<Registry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Returns the type registry of the codec version, if set.
+    pub fn registry(&self) -> Option<&Registry> {
+        self.registry.as_deref()
+    }
+
     /// Returns the current bytes array as an immutable bytes array.
     /// If the packer header is set to "true", the first 4-byte represents
     /// the message length in the big-endian order. The returned bytes length
@@ -515,6 +546,31 @@ impl Packer {
         Ok(v)
     }
 
+    /// Unpacks with the borrowed reader over the bytes from the "offset" position,
+    /// and advances the offset as much as the reader has read.
+    /// Useful to share the decoders of "reader::PackerReader" with "Packer".
+    pub fn unpack_with_reader<T>(
+        &self,
+        f: impl FnOnce(&mut reader::PackerReader) -> Result<T>,
+    ) -> Result<T> {
+        let offset = self.get_offset();
+        let b = self.bytes.take();
+
+        let mut reader = reader::PackerReader::new(&b[offset.min(b.len())..]);
+        if let Some(registry) = &self.registry {
+            reader = reader.with_registry(registry.clone());
+        }
+        let res = f(&mut reader);
+        let n = reader.get_offset();
+
+        // remember to put it back -- "take" leaves the field as "Default::default()"
+        self.bytes.set(b);
+
+        let v = res?;
+        self.set_offset(offset + n);
+        Ok(v)
+    }
+
     /// Writes the "u8" slice from the offset and increments the offset as much.
     /// The first 4-byte is used for encoding length header.
     /// ref. "avalanchego/utils/wrappers.Packer.PackBytes"
@@ -633,6 +689,7 @@ fn test_expand() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(2),
+        registry: None,
     };
     assert!(packer.expand(1).is_err());
 
@@ -643,6 +700,7 @@ fn test_expand() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.expand(1).unwrap();
     assert_eq!(packer.bytes_len(), 3);
@@ -709,6 +767,7 @@ fn test_unpack_byte() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_byte().unwrap();
     assert_eq!(b, 1);
@@ -726,6 +785,7 @@ fn test_pack_u16() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.pack_u16(0x0102).unwrap();
     assert_eq!(packer.bytes_len(), U16_LEN);
@@ -752,6 +812,7 @@ fn test_unpack_u16() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_u16().unwrap();
     assert_eq!(b, 0x0102);
@@ -769,6 +830,7 @@ fn test_pack_u16_short() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     packer.pack_u16(17).unwrap();
@@ -786,6 +848,7 @@ fn test_pack_u16_short() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_u16().unwrap();
     assert_eq!(b, 17);
@@ -806,6 +869,7 @@ fn test_pack_u32() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.pack_u32(0x01020304).unwrap();
     assert_eq!(packer.bytes_len(), U32_LEN);
@@ -828,6 +892,7 @@ fn test_unpack_u32() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     assert_eq!(packer.unpack_u32().unwrap(), 0x01020304);
     assert_eq!(packer.get_offset(), U32_LEN);
@@ -843,6 +908,7 @@ fn test_pack_u64() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.pack_u64(0x0102030405060708).unwrap();
     assert_eq!(packer.bytes_len(), U64_LEN);
@@ -874,6 +940,7 @@ fn test_unpack_u64() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     assert_eq!(packer.unpack_u64().unwrap(), 0x0102030405060708);
     assert_eq!(packer.get_offset(), U64_LEN);
@@ -890,6 +957,7 @@ fn test_pack_bool() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.pack_bool(false).unwrap();
     packer.pack_bool(true).unwrap();
@@ -909,6 +977,7 @@ fn test_pack_bool() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     assert_eq!(packer.unpack_bool().unwrap(), false);
     assert_eq!(packer.unpack_bool().unwrap(), true);
@@ -934,6 +1003,7 @@ fn test_unpack_bool() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     assert!(packer.unpack_bool().unwrap());
     assert_eq!(packer.get_offset(), BOOL_LEN);
@@ -949,6 +1019,7 @@ fn test_pack_bytes() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     let s = "Avax";
@@ -984,6 +1055,7 @@ fn test_unpack_bytes() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_bytes(4).unwrap();
     assert_eq!(&b[..], b"Avax");
@@ -1000,6 +1072,7 @@ fn test_pack_bytes_with_header() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     let s = "Avax";
@@ -1033,6 +1106,7 @@ fn test_unpack_bytes_with_header() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_bytes_with_header().unwrap();
     assert_eq!(&b[..], b"Avax");
@@ -1049,6 +1123,7 @@ fn test_pack_2d_bytes() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     // first 4-byte is for length
@@ -1099,6 +1174,7 @@ fn test_unpack_2d_bytes() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_2d_bytes(4).unwrap();
     assert_eq!(
@@ -1120,6 +1196,7 @@ fn test_pack_2d_bytes_with_header() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     // first 4-byte is for length
@@ -1172,6 +1249,7 @@ fn test_unpack_2d_bytes_with_header() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_2d_bytes_with_header().unwrap();
     assert_eq!(
@@ -1195,6 +1273,7 @@ fn test_pack_2d_bytes_with_header_123() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer.pack_2d_bytes_with_header(Vec::from(vec![])).unwrap();
     assert_eq!(packer.bytes_len(), 4);
@@ -1206,6 +1285,7 @@ fn test_pack_2d_bytes_with_header_123() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer
         .pack_2d_bytes_with_header(Vec::from(vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]]))
@@ -1223,6 +1303,7 @@ fn test_pack_2d_bytes_with_header_123() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_2d_bytes_with_header().unwrap();
     assert_eq!(&b[..], Vec::from(vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]]));
@@ -1234,6 +1315,7 @@ fn test_pack_2d_bytes_with_header_123() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     packer
         .pack_2d_bytes_with_header(Vec::from(vec![
@@ -1260,6 +1342,7 @@ fn test_pack_2d_bytes_with_header_123() {
         bytes: Cell::new(b),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
     let b = packer.unpack_2d_bytes_with_header().unwrap();
     assert_eq!(
@@ -1281,6 +1364,7 @@ fn test_pack_str() {
         bytes: Cell::new(BytesMut::with_capacity(0)),
         header: false,
         offset: Cell::new(0),
+        registry: None,
     };
 
     let s = "Avax";
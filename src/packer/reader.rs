@@ -0,0 +1,176 @@
+use std::{str, sync::Arc};
+
+use crate::{
+    codec::manager::Registry,
+    errors::{Error, Result},
+};
+
+/// Borrowed, read-only counterpart of "Packer" for decoding.
+/// Unlike "Packer::unpack_bytes" that copies each field into a new "Vec",
+/// the "unpack_*" methods return slices into the input bytes, so decoding
+/// does not allocate unless the caller takes ownership.
+///
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/wrappers#Packer>
+#[derive(Debug, Clone)]
+pub struct PackerReader<'a> {
+    bytes: &'a [u8],
+    /// offset that is being read from in the byte array
+    offset: usize,
+    /// Same as "Packer::registry".
+    registry: Option<Arc<Registry>>,
+}
+
+impl<'a> PackerReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            offset: 0,
+            registry: None,
+        }
+    }
+
+    /// Same as "Packer::with_registry".
+    #[must_use]
+    pub fn with_registry(mut self, registry: Arc<Registry>) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Returns the type registry of the codec version, if set.
+    pub fn registry(&self) -> Option<&Registry> {
+        self.registry.as_deref()
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn bytes_len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the number of bytes not read yet.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    /// Returns an error if the reader has insufficient length for the input size.
+    /// ref. "avalanchego/utils/wrappers.Packer.CheckSpace"
+    fn check_remaining_unpack(&self, bytes_to_read: usize) -> Result<()> {
+        let needed_size = self.offset + bytes_to_read;
+        let bytes_n = self.bytes.len();
+        if needed_size > bytes_n {
+            return Err(Error::Other {
+                message: format!(
+                    "bad length to read; offset + bytes ({}) to read exceeds current total bytes size {}",
+                    needed_size, bytes_n
+                ), // ref. "errBadLength"
+                retryable: false,
+            });
+        };
+        Ok(())
+    }
+
+    /// Unpacks the "u8" fixed-size array from the offset without copying.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackFixedBytes"
+    pub fn unpack_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        self.check_remaining_unpack(n)?;
+
+        let bytes: &'a [u8] = self.bytes;
+        let v = &bytes[self.offset..self.offset + n];
+        self.offset += n;
+        Ok(v)
+    }
+
+    /// Unpacks the fixed-size array, such as IDs.
+    pub fn unpack_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let b = self.unpack_bytes(N)?;
+        let mut v = [0u8; N];
+        v.copy_from_slice(b);
+        Ok(v)
+    }
+
+    pub fn unpack_byte(&mut self) -> Result<u8> {
+        Ok(self.unpack_array::<1>()?[0])
+    }
+
+    pub fn unpack_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.unpack_array()?))
+    }
+
+    pub fn unpack_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.unpack_array()?))
+    }
+
+    pub fn unpack_u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.unpack_array()?))
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBool"
+    pub fn unpack_bool(&mut self) -> Result<bool> {
+        match self.unpack_byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::Other {
+                message: "unexpected value when unpacking bool".to_string(), // ref. "errBadBool"
+                retryable: false,
+            }),
+        }
+    }
+
+    /// Unpacks the "u8" slice prefixed with the "u32" length without copying.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBytes"
+    pub fn unpack_bytes_with_header(&mut self) -> Result<&'a [u8]> {
+        let n = self.unpack_u32()?;
+        self.unpack_bytes(n as usize)
+    }
+
+    /// Unpacks str prefixed with the "u16" length without copying.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackStr"
+    pub fn unpack_str(&mut self) -> Result<&'a str> {
+        let n = self.unpack_u16()?;
+        let d = self.unpack_bytes(n as usize)?;
+        str::from_utf8(d).map_err(|e| Error::Other {
+            message: format!("failed str::from_utf8 {}", e),
+            retryable: false,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::reader::test_reader --exact --show-output
+#[test]
+fn test_reader() {
+    use crate::packer::Packer;
+
+    let packer = Packer::new(1024, 0);
+    packer.pack_byte(0x01).unwrap();
+    packer.pack_u16(0x0203).unwrap();
+    packer.pack_u32(0x04050607).unwrap();
+    packer.pack_u64(0x08090a0b0c0d0e0f).unwrap();
+    packer.pack_bool(true).unwrap();
+    packer.pack_bytes_with_header(&[0xaa, 0xbb]).unwrap();
+    packer.pack_str("avax").unwrap();
+    let b = packer.take_bytes();
+
+    let mut reader = PackerReader::new(&b);
+    assert_eq!(reader.unpack_byte().unwrap(), 0x01);
+    assert_eq!(reader.unpack_u16().unwrap(), 0x0203);
+    assert_eq!(reader.unpack_u32().unwrap(), 0x04050607);
+    assert_eq!(reader.unpack_u64().unwrap(), 0x08090a0b0c0d0e0f);
+    assert!(reader.unpack_bool().unwrap());
+
+    let d = reader.unpack_bytes_with_header().unwrap();
+    assert_eq!(d, &[0xaa, 0xbb]);
+    // borrowed from the input bytes, not copied
+    assert!(b.as_ptr_range().contains(&d.as_ptr()));
+
+    assert_eq!(reader.unpack_str().unwrap(), "avax");
+    assert_eq!(reader.get_offset(), b.len());
+    assert_eq!(reader.remaining(), 0);
+    assert!(reader.unpack_byte().is_err());
+
+    let mut reader = PackerReader::new(&[0x02]);
+    assert!(reader.unpack_bool().is_err());
+    let mut reader = PackerReader::new(&[0x00, 0x00, 0x00, 0x05, 0x01]);
+    assert!(reader.unpack_bytes_with_header().is_err());
+}
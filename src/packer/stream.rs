@@ -0,0 +1,470 @@
+use std::io::{self, Read, Write};
+
+use crate::{
+    codec::linear::{Pack, Unpack},
+    errors::{Error, Result},
+    packer::{Packer, MAX_STR_LEN},
+};
+
+/// Initial number of bytes to read ahead when unpacking a value whose
+/// encoded size is not known up front (see "Reader::read_unpackable").
+pub(crate) const READ_AHEAD_CHUNK_SIZE: usize = 4096;
+
+/// Streaming counterpart of "Packer" that packs directly into "std::io::Write"
+/// (e.g., file, socket), with the same wire format and the same bounds checks.
+/// "max_size" limits the total number of bytes written by this writer.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/wrappers#Packer>
+#[derive(Debug)]
+pub struct Writer<W> {
+    inner: W,
+    /// largest allowed number of bytes to write
+    max_size: usize,
+    /// number of bytes written so far
+    offset: usize,
+}
+
+impl<W: Write> Writer<W> {
+    pub fn new(inner: W, max_size: usize) -> Self {
+        Self {
+            inner,
+            max_size,
+            offset: 0,
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        self.inner.flush().map_err(|e| io_error("flush", e))
+    }
+
+    /// Returns an error if writing "n" bytes exceeds "max_size".
+    /// ref. "avalanchego/utils/wrappers.Packer.Expand"
+    fn expand(&self, n: usize) -> Result<()> {
+        let needed_size = self.offset + n;
+        if needed_size > self.max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "needed_size {} exceeds max_size {}",
+                    needed_size, self.max_size
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// Writes the "u8" fixed-size array and increments the offset as much.
+    /// ref. "avalanchego/utils/wrappers.Packer.PackFixedBytes"
+    pub fn pack_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.expand(v.len())?;
+        self.inner
+            .write_all(v)
+            .map_err(|e| io_error("write_all", e))?;
+        self.offset += v.len();
+        Ok(())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackByte"
+    pub fn pack_byte(&mut self, v: u8) -> Result<()> {
+        self.pack_bytes(&[v])
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackShort"
+    pub fn pack_u16(&mut self, v: u16) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackInt"
+    pub fn pack_u32(&mut self, v: u32) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackLong"
+    pub fn pack_u64(&mut self, v: u64) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackBool"
+    pub fn pack_bool(&mut self, v: bool) -> Result<()> {
+        self.pack_byte(u8::from(v))
+    }
+
+    /// Writes the "u8" slice prefixed with the "u32" length.
+    /// ref. "avalanchego/utils/wrappers.Packer.PackBytes"
+    pub fn pack_bytes_with_header(&mut self, v: &[u8]) -> Result<()> {
+        self.pack_u32(v.len() as u32)?;
+        self.pack_bytes(v)
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.Pack2DByteSlice"
+    pub fn pack_2d_bytes_with_header(&mut self, v: &[Vec<u8>]) -> Result<()> {
+        self.pack_u32(v.len() as u32)?;
+        for vv in v.iter() {
+            self.pack_bytes_with_header(vv)?;
+        }
+        Ok(())
+    }
+
+    /// Writes str prefixed with the "u16" length.
+    /// ref. "avalanchego/utils/wrappers.Packer.PackStr"
+    pub fn pack_str(&mut self, v: &str) -> Result<()> {
+        if v.len() > MAX_STR_LEN as usize {
+            return Err(Error::Other {
+                message: format!("str {} > max_size {}", v.len(), MAX_STR_LEN),
+                retryable: false,
+            });
+        }
+        self.pack_u16(v.len() as u16)?;
+        self.pack_bytes(v.as_bytes())
+    }
+
+    /// Packs a single value (e.g., a block) and writes its bytes, so that
+    /// an archive of many values never has to be held in memory at once.
+    pub fn write_packable<T: Pack>(&mut self, v: &T) -> Result<()> {
+        let packer = Packer::new(self.max_size - self.offset, 128);
+        v.pack_to(&packer)?;
+        self.pack_bytes(&packer.take_bytes())
+    }
+}
+
+/// Streaming counterpart of "Packer" that unpacks directly from "std::io::Read",
+/// with the same wire format and the same bounds checks.
+/// "max_size" limits the total number of bytes read by this reader, so that
+/// a corrupted length header cannot make it allocate unbounded memory.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/wrappers#Packer>
+#[derive(Debug)]
+pub struct Reader<R> {
+    inner: R,
+    /// largest allowed number of bytes to read
+    max_size: usize,
+    /// number of bytes read so far
+    offset: usize,
+    /// bytes read ahead from "inner" but not yet unpacked
+    pending: Vec<u8>,
+}
+
+impl<R: Read> Reader<R> {
+    pub fn new(inner: R, max_size: usize) -> Self {
+        Self {
+            inner,
+            max_size,
+            offset: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the underlying reader.
+    /// Bytes read ahead by "read_unpackable" but not yet unpacked are dropped.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns an error if reading "n" bytes exceeds "max_size".
+    /// ref. "avalanchego/utils/wrappers.Packer.CheckSpace"
+    fn check_remaining_unpack(&self, bytes_to_read: usize) -> Result<()> {
+        let needed_size = self.offset + bytes_to_read;
+        if needed_size > self.max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "bad length to read; offset + bytes ({}) to read exceeds max_size {}",
+                    needed_size, self.max_size
+                ), // ref. "errBadLength"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.check_remaining_unpack(buf.len())?;
+        let pending = self.take_pending(buf.len());
+        let n = pending.len();
+        buf[..n].copy_from_slice(&pending);
+        self.inner
+            .read_exact(&mut buf[n..])
+            .map_err(|e| io_error("read_exact", e))?;
+        self.offset += buf.len();
+        Ok(())
+    }
+
+    /// Unpacks the "u8" fixed-size array and advances the offset.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackFixedBytes"
+    pub fn unpack_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        self.check_remaining_unpack(n)?;
+
+        // do not trust "n" for the allocation, read up to "n" bytes instead
+        let mut v = self.take_pending(n);
+        (&mut self.inner)
+            .take((n - v.len()) as u64)
+            .read_to_end(&mut v)
+            .map_err(|e| io_error("read_to_end", e))?;
+        if v.len() != n {
+            return Err(io_error(
+                "read_to_end",
+                io::Error::from(io::ErrorKind::UnexpectedEof),
+            ));
+        }
+
+        self.offset += n;
+        Ok(v)
+    }
+
+    /// Removes and returns up to "n" bytes that were read ahead.
+    fn take_pending(&mut self, n: usize) -> Vec<u8> {
+        let n = n.min(self.pending.len());
+        self.pending.drain(..n).collect()
+    }
+
+    /// Reads and unpacks a single value (e.g., a block) written by "write_packable".
+    /// The encoded size is not known up front, so it reads ahead in growing chunks
+    /// (bounded by "max_size") until the value unpacks, and keeps the remaining
+    /// bytes for the next call.
+    pub fn read_unpackable<T: Unpack>(&mut self) -> Result<T> {
+        let mut chunk_size = READ_AHEAD_CHUNK_SIZE;
+        loop {
+            let packer = Packer::load_bytes_for_unpack(self.pending.len(), &self.pending);
+            let err = match T::unpack_from(&packer) {
+                Ok(v) => {
+                    let n = packer.get_offset();
+                    self.pending.drain(..n);
+                    self.offset += n;
+                    return Ok(v);
+                }
+                Err(e) => e,
+            };
+
+            // the value may be incomplete, so read more unless the stream
+            // is exhausted or "max_size" is reached
+            let limit = self.max_size - self.offset - self.pending.len();
+            if limit == 0 {
+                return Err(err);
+            }
+            let read = (&mut self.inner)
+                .take(chunk_size.min(limit) as u64)
+                .read_to_end(&mut self.pending)
+                .map_err(|e| io_error("read_to_end", e))?;
+            if read == 0 {
+                return Err(err);
+            }
+            chunk_size = chunk_size.saturating_mul(2);
+        }
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackByte"
+    pub fn unpack_byte(&mut self) -> Result<u8> {
+        let mut b = [0u8; 1];
+        self.read_exact(&mut b)?;
+        Ok(b[0])
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackShort"
+    pub fn unpack_u16(&mut self) -> Result<u16> {
+        let mut b = [0u8; 2];
+        self.read_exact(&mut b)?;
+        Ok(u16::from_be_bytes(b))
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackInt"
+    pub fn unpack_u32(&mut self) -> Result<u32> {
+        let mut b = [0u8; 4];
+        self.read_exact(&mut b)?;
+        Ok(u32::from_be_bytes(b))
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackLong"
+    pub fn unpack_u64(&mut self) -> Result<u64> {
+        let mut b = [0u8; 8];
+        self.read_exact(&mut b)?;
+        Ok(u64::from_be_bytes(b))
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBool"
+    pub fn unpack_bool(&mut self) -> Result<bool> {
+        match self.unpack_byte()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::Other {
+                message: "unexpected value when unpacking bool".to_string(), // ref. "errBadBool"
+                retryable: false,
+            }),
+        }
+    }
+
+    /// Unpacks the "u8" slice prefixed with the "u32" length.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBytes"
+    pub fn unpack_bytes_with_header(&mut self) -> Result<Vec<u8>> {
+        let n = self.unpack_u32()?;
+        self.unpack_bytes(n as usize)
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.Unpack2DByteSlice"
+    pub fn unpack_2d_bytes_with_header(&mut self) -> Result<Vec<Vec<u8>>> {
+        let total = self.unpack_u32()?;
+        let mut rs: Vec<Vec<u8>> = Vec::new();
+        for _ in 0..total {
+            rs.push(self.unpack_bytes_with_header()?);
+        }
+        Ok(rs)
+    }
+
+    /// Unpacks str prefixed with the "u16" length.
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackStr"
+    pub fn unpack_str(&mut self) -> Result<String> {
+        let n = self.unpack_u16()?;
+        let d = self.unpack_bytes(n as usize)?;
+        String::from_utf8(d).map_err(|e| Error::Other {
+            message: format!("failed String::from_utf8 {}", e),
+            retryable: false,
+        })
+    }
+}
+
+pub(crate) fn io_error(op: &str, e: io::Error) -> Error {
+    Error::Other {
+        message: format!("failed {} '{}'", op, e),
+        retryable: false,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::stream::test_stream --exact --show-output
+#[test]
+fn test_stream() {
+    use crate::packer::Packer;
+
+    let packer = Packer::new(1024, 0);
+    packer.pack_byte(0x01).unwrap();
+    packer.pack_u16(0x0203).unwrap();
+    packer.pack_u32(0x04050607).unwrap();
+    packer.pack_u64(0x08090a0b0c0d0e0f).unwrap();
+    packer.pack_bool(true).unwrap();
+    packer.pack_bytes_with_header(&[0xaa, 0xbb]).unwrap();
+    packer
+        .pack_2d_bytes_with_header(vec![vec![0x01], vec![0x02, 0x03]])
+        .unwrap();
+    packer.pack_str("avax").unwrap();
+    let expected = packer.take_bytes();
+
+    let mut writer = Writer::new(Vec::new(), 1024);
+    writer.pack_byte(0x01).unwrap();
+    writer.pack_u16(0x0203).unwrap();
+    writer.pack_u32(0x04050607).unwrap();
+    writer.pack_u64(0x08090a0b0c0d0e0f).unwrap();
+    writer.pack_bool(true).unwrap();
+    writer.pack_bytes_with_header(&[0xaa, 0xbb]).unwrap();
+    writer
+        .pack_2d_bytes_with_header(&[vec![0x01], vec![0x02, 0x03]])
+        .unwrap();
+    writer.pack_str("avax").unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.get_offset(), expected.len());
+    let b = writer.into_inner();
+    assert_eq!(b, expected.to_vec());
+
+    let mut reader = Reader::new(io::Cursor::new(&b), 1024);
+    assert_eq!(reader.unpack_byte().unwrap(), 0x01);
+    assert_eq!(reader.unpack_u16().unwrap(), 0x0203);
+    assert_eq!(reader.unpack_u32().unwrap(), 0x04050607);
+    assert_eq!(reader.unpack_u64().unwrap(), 0x08090a0b0c0d0e0f);
+    assert!(reader.unpack_bool().unwrap());
+    assert_eq!(reader.unpack_bytes_with_header().unwrap(), vec![0xaa, 0xbb]);
+    assert_eq!(
+        reader.unpack_2d_bytes_with_header().unwrap(),
+        vec![vec![0x01], vec![0x02, 0x03]]
+    );
+    assert_eq!(reader.unpack_str().unwrap(), "avax");
+    assert_eq!(reader.get_offset(), b.len());
+    assert!(reader.unpack_byte().is_err());
+
+    // exceeds "max_size"
+    let mut writer = Writer::new(Vec::new(), 3);
+    writer.pack_u16(1).unwrap();
+    assert!(writer.pack_u16(1).is_err());
+    assert_eq!(writer.get_offset(), 2);
+
+    // corrupted length header must not be trusted
+    let mut reader = Reader::new(io::Cursor::new(&[0xff, 0xff, 0xff, 0xff, 0x01]), 1024);
+    assert!(reader.unpack_bytes_with_header().is_err());
+    let mut reader = Reader::new(io::Cursor::new(&[0x00, 0x00, 0x00, 0x05, 0x01]), 1024);
+    assert!(reader.unpack_bytes_with_header().is_err());
+    let mut reader = Reader::new(io::Cursor::new(&[0x02]), 1024);
+    assert!(reader.unpack_bool().is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- packer::stream::test_stream_packable --exact --show-output
+#[test]
+fn test_stream_packable() {
+    use crate::{
+        codec, ids,
+        platformvm::blocks::{commit, standard, Block, CommonBlock},
+    };
+
+    let blk1 = Block::BanffCommit(commit::BanffBlock {
+        time: 1689689216,
+        apricot_block: commit::ApricotBlock {
+            common: CommonBlock::new(ids::Id::from_slice(&[1; 32]), 10),
+        },
+    });
+    let blk2 = Block::BanffStandard(standard::BanffBlock {
+        time: 1689689217,
+        apricot_block: standard::ApricotBlock {
+            common: CommonBlock::new(blk1.id().unwrap(), 11),
+            transactions: Vec::new(),
+        },
+    });
+
+    // each streamed block is byte-identical to "Block::pack"
+    let mut writer = Writer::new(Vec::new(), 1024);
+    writer.pack_u16(codec::VERSION).unwrap();
+    writer.write_packable(&blk1).unwrap();
+    let b = writer.into_inner();
+    assert_eq!(b, blk1.pack().unwrap());
+
+    let mut writer = Writer::new(Vec::new(), 1024);
+    for blk in [&blk1, &blk2] {
+        writer.pack_u16(codec::VERSION).unwrap();
+        writer.write_packable(blk).unwrap();
+    }
+    let b = writer.into_inner();
+    let mut expected = blk1.pack().unwrap();
+    expected.extend(blk2.pack().unwrap());
+    assert_eq!(b, expected);
+
+    // the first read reads ahead into the second block
+    let mut reader = Reader::new(io::Cursor::new(&b), 1024);
+    for blk in [&blk1, &blk2] {
+        assert_eq!(reader.unpack_u16().unwrap(), codec::VERSION);
+        assert_eq!(&reader.read_unpackable::<Block>().unwrap(), blk);
+    }
+    assert_eq!(reader.get_offset(), b.len());
+    assert!(reader.unpack_byte().is_err());
+
+    // truncated block
+    let mut reader = Reader::new(io::Cursor::new(&b[..b.len() - 1]), 1024);
+    reader.unpack_u16().unwrap();
+    assert_eq!(reader.read_unpackable::<Block>().unwrap(), blk1);
+    reader.unpack_u16().unwrap();
+    assert!(reader.read_unpackable::<Block>().is_err());
+
+    // exceeds "max_size"
+    let mut writer = Writer::new(Vec::new(), 8);
+    assert!(writer.write_packable(&blk1).is_err());
+    let mut reader = Reader::new(io::Cursor::new(&b), 8);
+    reader.unpack_u16().unwrap();
+    assert!(reader.read_unpackable::<Block>().is_err());
+}
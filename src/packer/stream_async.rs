@@ -0,0 +1,406 @@
+use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::{
+    codec::linear::{Pack, Unpack},
+    errors::{Error, Result},
+    packer::{
+        stream::{io_error, READ_AHEAD_CHUNK_SIZE},
+        Packer, MAX_STR_LEN,
+    },
+};
+
+/// Async counterpart of "packer::stream::Writer" over "tokio::io::AsyncWrite".
+#[derive(Debug)]
+pub struct AsyncWriter<W> {
+    inner: W,
+    /// largest allowed number of bytes to write
+    max_size: usize,
+    /// number of bytes written so far
+    offset: usize,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWriter<W> {
+    pub fn new(inner: W, max_size: usize) -> Self {
+        Self {
+            inner,
+            max_size,
+            offset: 0,
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    pub async fn flush(&mut self) -> Result<()> {
+        self.inner.flush().await.map_err(|e| io_error("flush", e))
+    }
+
+    /// Returns an error if writing "n" bytes exceeds "max_size".
+    /// ref. "avalanchego/utils/wrappers.Packer.Expand"
+    fn expand(&self, n: usize) -> Result<()> {
+        let needed_size = self.offset + n;
+        if needed_size > self.max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "needed_size {} exceeds max_size {}",
+                    needed_size, self.max_size
+                ),
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackFixedBytes"
+    pub async fn pack_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.expand(v.len())?;
+        self.inner
+            .write_all(v)
+            .await
+            .map_err(|e| io_error("write_all", e))?;
+        self.offset += v.len();
+        Ok(())
+    }
+
+    pub async fn pack_byte(&mut self, v: u8) -> Result<()> {
+        self.pack_bytes(&[v]).await
+    }
+
+    pub async fn pack_u16(&mut self, v: u16) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes()).await
+    }
+
+    pub async fn pack_u32(&mut self, v: u32) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes()).await
+    }
+
+    pub async fn pack_u64(&mut self, v: u64) -> Result<()> {
+        self.pack_bytes(&v.to_be_bytes()).await
+    }
+
+    pub async fn pack_bool(&mut self, v: bool) -> Result<()> {
+        self.pack_byte(u8::from(v)).await
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackBytes"
+    pub async fn pack_bytes_with_header(&mut self, v: &[u8]) -> Result<()> {
+        self.pack_u32(v.len() as u32).await?;
+        self.pack_bytes(v).await
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.Pack2DByteSlice"
+    pub async fn pack_2d_bytes_with_header(&mut self, v: &[Vec<u8>]) -> Result<()> {
+        self.pack_u32(v.len() as u32).await?;
+        for vv in v.iter() {
+            self.pack_bytes_with_header(vv).await?;
+        }
+        Ok(())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.PackStr"
+    pub async fn pack_str(&mut self, v: &str) -> Result<()> {
+        if v.len() > MAX_STR_LEN as usize {
+            return Err(Error::Other {
+                message: format!("str {} > max_size {}", v.len(), MAX_STR_LEN),
+                retryable: false,
+            });
+        }
+        self.pack_u16(v.len() as u16).await?;
+        self.pack_bytes(v.as_bytes()).await
+    }
+
+    /// Packs a single value (e.g., a block) and writes its bytes, so that
+    /// an archive of many values never has to be held in memory at once.
+    pub async fn write_packable<T: Pack>(&mut self, v: &T) -> Result<()> {
+        let packer = Packer::new(self.max_size - self.offset, 128);
+        v.pack_to(&packer)?;
+        self.pack_bytes(&packer.take_bytes()).await
+    }
+}
+
+/// Async counterpart of "packer::stream::Reader" over "tokio::io::AsyncRead".
+#[derive(Debug)]
+pub struct AsyncReader<R> {
+    inner: R,
+    /// largest allowed number of bytes to read
+    max_size: usize,
+    /// number of bytes read so far
+    offset: usize,
+    /// bytes read ahead from "inner" but not yet unpacked
+    pending: Vec<u8>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncReader<R> {
+    pub fn new(inner: R, max_size: usize) -> Self {
+        Self {
+            inner,
+            max_size,
+            offset: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the underlying reader.
+    /// Bytes read ahead by "read_unpackable" but not yet unpacked are dropped.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.CheckSpace"
+    fn check_remaining_unpack(&self, bytes_to_read: usize) -> Result<()> {
+        let needed_size = self.offset + bytes_to_read;
+        if needed_size > self.max_size {
+            return Err(Error::Other {
+                message: format!(
+                    "bad length to read; offset + bytes ({}) to read exceeds max_size {}",
+                    needed_size, self.max_size
+                ), // ref. "errBadLength"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    async fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.check_remaining_unpack(buf.len())?;
+        let pending = self.take_pending(buf.len());
+        let n = pending.len();
+        buf[..n].copy_from_slice(&pending);
+        self.inner
+            .read_exact(&mut buf[n..])
+            .await
+            .map_err(|e| io_error("read_exact", e))?;
+        self.offset += buf.len();
+        Ok(())
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackFixedBytes"
+    pub async fn unpack_bytes(&mut self, n: usize) -> Result<Vec<u8>> {
+        self.check_remaining_unpack(n)?;
+
+        // do not trust "n" for the allocation, read up to "n" bytes instead
+        let mut v = self.take_pending(n);
+        (&mut self.inner)
+            .take((n - v.len()) as u64)
+            .read_to_end(&mut v)
+            .await
+            .map_err(|e| io_error("read_to_end", e))?;
+        if v.len() != n {
+            return Err(io_error(
+                "read_to_end",
+                io::Error::from(io::ErrorKind::UnexpectedEof),
+            ));
+        }
+
+        self.offset += n;
+        Ok(v)
+    }
+
+    /// Removes and returns up to "n" bytes that were read ahead.
+    fn take_pending(&mut self, n: usize) -> Vec<u8> {
+        let n = n.min(self.pending.len());
+        self.pending.drain(..n).collect()
+    }
+
+    /// Reads and unpacks a single value (e.g., a block) written by "write_packable".
+    /// The encoded size is not known up front, so it reads ahead in growing chunks
+    /// (bounded by "max_size") until the value unpacks, and keeps the remaining
+    /// bytes for the next call.
+    pub async fn read_unpackable<T: Unpack>(&mut self) -> Result<T> {
+        let mut chunk_size = READ_AHEAD_CHUNK_SIZE;
+        loop {
+            let packer = Packer::load_bytes_for_unpack(self.pending.len(), &self.pending);
+            let err = match T::unpack_from(&packer) {
+                Ok(v) => {
+                    let n = packer.get_offset();
+                    self.pending.drain(..n);
+                    self.offset += n;
+                    return Ok(v);
+                }
+                Err(e) => e,
+            };
+
+            // the value may be incomplete, so read more unless the stream
+            // is exhausted or "max_size" is reached
+            let limit = self.max_size - self.offset - self.pending.len();
+            if limit == 0 {
+                return Err(err);
+            }
+            let read = (&mut self.inner)
+                .take(chunk_size.min(limit) as u64)
+                .read_to_end(&mut self.pending)
+                .await
+                .map_err(|e| io_error("read_to_end", e))?;
+            if read == 0 {
+                return Err(err);
+            }
+            chunk_size = chunk_size.saturating_mul(2);
+        }
+    }
+
+    pub async fn unpack_byte(&mut self) -> Result<u8> {
+        let mut b = [0u8; 1];
+        self.read_exact(&mut b).await?;
+        Ok(b[0])
+    }
+
+    pub async fn unpack_u16(&mut self) -> Result<u16> {
+        let mut b = [0u8; 2];
+        self.read_exact(&mut b).await?;
+        Ok(u16::from_be_bytes(b))
+    }
+
+    pub async fn unpack_u32(&mut self) -> Result<u32> {
+        let mut b = [0u8; 4];
+        self.read_exact(&mut b).await?;
+        Ok(u32::from_be_bytes(b))
+    }
+
+    pub async fn unpack_u64(&mut self) -> Result<u64> {
+        let mut b = [0u8; 8];
+        self.read_exact(&mut b).await?;
+        Ok(u64::from_be_bytes(b))
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBool"
+    pub async fn unpack_bool(&mut self) -> Result<bool> {
+        match self.unpack_byte().await? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::Other {
+                message: "unexpected value when unpacking bool".to_string(), // ref. "errBadBool"
+                retryable: false,
+            }),
+        }
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackBytes"
+    pub async fn unpack_bytes_with_header(&mut self) -> Result<Vec<u8>> {
+        let n = self.unpack_u32().await?;
+        self.unpack_bytes(n as usize).await
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.Unpack2DByteSlice"
+    pub async fn unpack_2d_bytes_with_header(&mut self) -> Result<Vec<Vec<u8>>> {
+        let total = self.unpack_u32().await?;
+        let mut rs: Vec<Vec<u8>> = Vec::new();
+        for _ in 0..total {
+            rs.push(self.unpack_bytes_with_header().await?);
+        }
+        Ok(rs)
+    }
+
+    /// ref. "avalanchego/utils/wrappers.Packer.UnpackStr"
+    pub async fn unpack_str(&mut self) -> Result<String> {
+        let n = self.unpack_u16().await?;
+        let d = self.unpack_bytes(n as usize).await?;
+        String::from_utf8(d).map_err(|e| Error::Other {
+            message: format!("failed String::from_utf8 {}", e),
+            retryable: false,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features packer_async -- packer::stream_async::test_stream_async --exact --show-output
+#[tokio::test]
+async fn test_stream_async() {
+    use crate::packer::Packer;
+
+    let packer = Packer::new(1024, 0);
+    packer.pack_byte(0x01).unwrap();
+    packer.pack_u16(0x0203).unwrap();
+    packer.pack_u32(0x04050607).unwrap();
+    packer.pack_u64(0x08090a0b0c0d0e0f).unwrap();
+    packer.pack_bool(false).unwrap();
+    packer
+        .pack_2d_bytes_with_header(vec![vec![0x01], vec![0x02, 0x03]])
+        .unwrap();
+    packer.pack_str("avax").unwrap();
+    let expected = packer.take_bytes();
+
+    let mut writer = AsyncWriter::new(Vec::new(), 1024);
+    writer.pack_byte(0x01).await.unwrap();
+    writer.pack_u16(0x0203).await.unwrap();
+    writer.pack_u32(0x04050607).await.unwrap();
+    writer.pack_u64(0x08090a0b0c0d0e0f).await.unwrap();
+    writer.pack_bool(false).await.unwrap();
+    writer
+        .pack_2d_bytes_with_header(&[vec![0x01], vec![0x02, 0x03]])
+        .await
+        .unwrap();
+    writer.pack_str("avax").await.unwrap();
+    writer.flush().await.unwrap();
+    let b = writer.into_inner();
+    assert_eq!(b, expected.to_vec());
+
+    let mut reader = AsyncReader::new(&b[..], 1024);
+    assert_eq!(reader.unpack_byte().await.unwrap(), 0x01);
+    assert_eq!(reader.unpack_u16().await.unwrap(), 0x0203);
+    assert_eq!(reader.unpack_u32().await.unwrap(), 0x04050607);
+    assert_eq!(reader.unpack_u64().await.unwrap(), 0x08090a0b0c0d0e0f);
+    assert!(!reader.unpack_bool().await.unwrap());
+    assert_eq!(
+        reader.unpack_2d_bytes_with_header().await.unwrap(),
+        vec![vec![0x01], vec![0x02, 0x03]]
+    );
+    assert_eq!(reader.unpack_str().await.unwrap(), "avax");
+    assert_eq!(reader.get_offset(), b.len());
+    assert!(reader.unpack_byte().await.is_err());
+
+    // exceeds "max_size"
+    let mut reader = AsyncReader::new(&b[..], 2);
+    assert!(reader.unpack_u32().await.is_err());
+    let mut writer = AsyncWriter::new(Vec::new(), 2);
+    assert!(writer.pack_u32(1).await.is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features packer_async -- packer::stream_async::test_stream_async_packable --exact --show-output
+#[tokio::test]
+async fn test_stream_async_packable() {
+    use crate::{
+        codec, ids,
+        platformvm::blocks::{commit, Block, CommonBlock},
+    };
+
+    let mut blks = Vec::new();
+    for height in 10..13 {
+        blks.push(Block::BanffCommit(commit::BanffBlock {
+            time: 1689689216 + height,
+            apricot_block: commit::ApricotBlock {
+                common: CommonBlock::new(ids::Id::from_slice(&[1; 32]), height),
+            },
+        }));
+    }
+
+    let mut writer = AsyncWriter::new(Vec::new(), 1024);
+    let mut expected = Vec::new();
+    for blk in blks.iter() {
+        writer.pack_u16(codec::VERSION).await.unwrap();
+        writer.write_packable(blk).await.unwrap();
+        expected.extend(blk.pack().unwrap());
+    }
+    let b = writer.into_inner();
+    assert_eq!(b, expected);
+
+    let mut reader = AsyncReader::new(b.as_slice(), 1024);
+    for blk in blks.iter() {
+        assert_eq!(reader.unpack_u16().await.unwrap(), codec::VERSION);
+        assert_eq!(&reader.read_unpackable::<Block>().await.unwrap(), blk);
+    }
+    assert_eq!(reader.get_offset(), b.len());
+    assert!(reader.unpack_byte().await.is_err());
+}
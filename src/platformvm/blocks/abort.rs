@@ -0,0 +1,72 @@
+use crate::{codec, impl_linear_codec, platformvm::blocks::CommonBlock};
+use serde::{Deserialize, Serialize};
+
+/// Rejects the proposal of the parent block.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotAbortBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApricotBlock {
+    pub common: CommonBlock,
+}
+
+impl Default for ApricotBlock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl ApricotBlock {
+    pub fn default() -> Self {
+        Self {
+            common: CommonBlock::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.AbortBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl_linear_codec!(ApricotBlock, interface { common });
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffAbortBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct BanffBlock {
+    /// Block timestamp in unix seconds.
+    pub time: u64,
+    pub apricot_block: ApricotBlock,
+}
+
+impl Default for BanffBlock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl BanffBlock {
+    pub fn default() -> Self {
+        Self {
+            time: 0,
+            apricot_block: ApricotBlock::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.BanffAbortBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl_linear_codec!(
+    BanffBlock,
+    interface {
+        time,
+        apricot_block
+    }
+);
@@ -0,0 +1,59 @@
+use crate::{
+    codec::{
+        self,
+        linear::{Interface, Pack, Unpack},
+    },
+    errors::Result,
+    packer,
+    platformvm::{blocks::CommonBlock, txs::Transaction},
+};
+use serde::{Deserialize, Serialize};
+
+/// Includes the atomic tx (i.e., "ImportTx", "ExportTx").
+/// Removed in Banff, where the atomic txs are included in the standard block.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotAtomicBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApricotBlock {
+    pub common: CommonBlock,
+    pub tx: Transaction,
+}
+
+impl ApricotBlock {
+    pub fn new(common: CommonBlock, tx: Transaction) -> Self {
+        Self { common, tx }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.AtomicBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+// "Transaction" has no default, thus not using "impl_linear_codec"
+impl Interface for ApricotBlock {
+    fn interface_type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn interface_type_name() -> String {
+        Self::type_name()
+    }
+}
+
+impl Pack for ApricotBlock {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        self.common.pack_to(packer)?;
+        self.tx.pack_to(packer)
+    }
+}
+
+impl Unpack for ApricotBlock {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let common = CommonBlock::unpack_from(packer)?;
+        let tx = Transaction::unpack_from(packer)?;
+        Ok(Self { common, tx })
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    codec::{
+        self,
+        linear::{Interface, Pack, Unpack},
+    },
+    errors::Result,
+    packer,
+    platformvm::{blocks::CommonBlock, txs::Transaction},
+};
+use serde::{Deserialize, Serialize};
+
+/// Includes the proposal tx (e.g., "AddValidatorTx", "RewardValidatorTx"),
+/// whose child is either the commit or the abort block.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotProposalBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApricotBlock {
+    pub common: CommonBlock,
+    pub tx: Transaction,
+}
+
+impl ApricotBlock {
+    pub fn new(common: CommonBlock, tx: Transaction) -> Self {
+        Self { common, tx }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.ProposalBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+// "Transaction" has no default, thus not using "impl_linear_codec"
+impl Interface for ApricotBlock {
+    fn interface_type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn interface_type_name() -> String {
+        Self::type_name()
+    }
+}
+
+impl Pack for ApricotBlock {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        self.common.pack_to(packer)?;
+        self.tx.pack_to(packer)
+    }
+}
+
+impl Unpack for ApricotBlock {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let common = CommonBlock::unpack_from(packer)?;
+        let tx = Transaction::unpack_from(packer)?;
+        Ok(Self { common, tx })
+    }
+}
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffProposalBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct BanffBlock {
+    /// Block timestamp in unix seconds.
+    pub time: u64,
+    /// Currently unused, but encoded so that adding the decision txs
+    /// to the proposal block does not require a codec change.
+    pub transactions: Vec<Transaction>,
+    pub apricot_block: ApricotBlock,
+}
+
+impl BanffBlock {
+    pub fn new(time: u64, apricot_block: ApricotBlock) -> Self {
+        Self {
+            time,
+            transactions: Vec::new(),
+            apricot_block,
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.BanffProposalBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl Interface for BanffBlock {
+    fn interface_type_id() -> u32 {
+        Self::type_id()
+    }
+
+    fn interface_type_name() -> String {
+        Self::type_name()
+    }
+}
+
+impl Pack for BanffBlock {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        self.time.pack_to(packer)?;
+        self.transactions.pack_to(packer)?;
+        self.apricot_block.pack_to(packer)
+    }
+}
+
+impl Unpack for BanffBlock {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let time = u64::unpack_from(packer)?;
+        let transactions = Vec::<Transaction>::unpack_from(packer)?;
+        let apricot_block = ApricotBlock::unpack_from(packer)?;
+        Ok(Self {
+            time,
+            transactions,
+            apricot_block,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    codec, impl_linear_codec,
+    platformvm::{blocks::CommonBlock, txs::Transaction},
+};
+use serde::{Deserialize, Serialize};
+
+/// Includes the decision txs (e.g., "CreateChainTx", "ImportTx").
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#ApricotStandardBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApricotBlock {
+    pub common: CommonBlock,
+    pub transactions: Vec<Transaction>,
+}
+
+impl Default for ApricotBlock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl ApricotBlock {
+    pub fn default() -> Self {
+        Self {
+            common: CommonBlock::default(),
+            transactions: Vec::new(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.StandardBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl_linear_codec!(
+    ApricotBlock,
+    interface {
+        common,
+        transactions
+    }
+);
+
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#BanffStandardBlock>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct BanffBlock {
+    /// Block timestamp in unix seconds.
+    pub time: u64,
+    pub apricot_block: ApricotBlock,
+}
+
+impl Default for BanffBlock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl BanffBlock {
+    pub fn default() -> Self {
+        Self {
+            time: 0,
+            apricot_block: ApricotBlock::default(),
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.BanffStandardBlock".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+}
+
+impl_linear_codec!(
+    BanffBlock,
+    interface {
+        time,
+        apricot_block
+    }
+);
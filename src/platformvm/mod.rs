@@ -1,3 +1,6 @@
+pub mod blocks;
+pub mod reward;
+pub mod staking;
 pub mod txs;
 
 use crate::ids;
@@ -0,0 +1,292 @@
+//! Staking reward calculator to estimate the validator/delegator rewards offline.
+use std::time::Duration;
+
+use crate::{
+    errors::{Error, Result},
+    units,
+};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+/// Denominator for the consumption rates, the delegation fee
+/// and the uptime requirement (i.e., 100% is 1,000,000).
+/// ref. "avalanchego/vms/platformvm/reward.PercentDenominator"
+pub const PERCENT_DENOMINATOR: u64 = 1_000_000;
+
+/// Represents the reward parameters.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/reward#Config>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Config {
+    /// Maximum consumption rate over "PERCENT_DENOMINATOR",
+    /// applied to the staker with the maximum staking duration.
+    pub max_consumption_rate: u64,
+    /// Minimum consumption rate over "PERCENT_DENOMINATOR",
+    /// applied to the staker with zero staking duration.
+    pub min_consumption_rate: u64,
+    /// Period over which the rewards are minted.
+    pub minting_period: Duration,
+    /// Maximum supply in nano-AVAX.
+    pub supply_cap: u64,
+}
+
+impl Config {
+    /// ref. "avalanchego/genesis.MainnetParams"
+    pub fn mainnet() -> Self {
+        Self {
+            max_consumption_rate: 120_000, // 12%
+            min_consumption_rate: 100_000, // 10%
+            minting_period: Duration::from_secs(365 * 24 * 60 * 60),
+            supply_cap: 720 * units::MEGA_AVAX,
+        }
+    }
+
+    /// ref. "avalanchego/genesis.FujiParams"
+    pub fn fuji() -> Self {
+        Self::mainnet()
+    }
+
+    /// ref. "avalanchego/genesis.LocalParams"
+    pub fn local() -> Self {
+        Self::mainnet()
+    }
+
+    /// Returns the config for the network Id, where any network other than
+    /// mainnet and fuji uses the local network config.
+    pub fn for_network(network_id: u32) -> Self {
+        match network_id {
+            1 => Self::mainnet(),
+            5 => Self::fuji(),
+            _ => Self::local(),
+        }
+    }
+}
+
+/// Computes the staking rewards.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/reward#Calculator>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Calculator {
+    pub config: Config,
+}
+
+impl Calculator {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Returns the expected reward in nano-AVAX for the stake amount
+    /// over the staking duration, given the current supply.
+    /// The consumption rate linearly increases from the minimum to the maximum
+    /// as the staking duration gets closer to the minting period:
+    ///
+    /// reward = (supply_cap - current_supply) * (staked_amount / current_supply)
+    ///          * consumption_rate * (staked_duration / minting_period)
+    ///
+    /// The staking duration is capped at the minting period.
+    /// Returns an error if the config makes the intermediate products
+    /// overflow U256, or its minting period is zero.
+    ///
+    /// ref. "avalanchego/vms/platformvm/reward.calculator.Calculate"
+    pub fn calculate(
+        &self,
+        staked_duration: Duration,
+        staked_amount: u64,
+        current_supply: u64,
+    ) -> Result<u64> {
+        if current_supply >= self.config.supply_cap || current_supply == 0 {
+            return Ok(0);
+        }
+        let remaining_supply = self.config.supply_cap - current_supply;
+
+        // the staking duration is bounded by the maximum stake duration
+        // which never exceeds the minting period, so cap it to keep
+        // the products below within U256 for any duration
+        // (only the config may overflow them)
+        let staked_duration = staked_duration.min(self.config.minting_period);
+
+        // durations in nanoseconds same as "time.Duration"
+        // to round the same as avalanchego
+        let staked_duration = U256::from(staked_duration.as_nanos());
+        let minting_period = U256::from(self.config.minting_period.as_nanos());
+
+        let max_sub_min_consumption_rate = U256::from(
+            self.config
+                .max_consumption_rate
+                .saturating_sub(self.config.min_consumption_rate),
+        );
+        let overflow = || Error::Other {
+            message: format!(
+                "reward calculation overflows with the config {:?}",
+                self.config
+            ),
+            retryable: false,
+        };
+
+        let adjusted_consumption_rate_numerator = max_sub_min_consumption_rate
+            .checked_mul(staked_duration)
+            .zip(U256::from(self.config.min_consumption_rate).checked_mul(minting_period))
+            .and_then(|(a, b)| a.checked_add(b))
+            .ok_or_else(overflow)?;
+        let adjusted_consumption_rate_denominator = minting_period
+            .checked_mul(U256::from(PERCENT_DENOMINATOR))
+            .ok_or_else(overflow)?;
+
+        let reward = U256::from(remaining_supply)
+            .checked_mul(adjusted_consumption_rate_numerator)
+            .and_then(|v| v.checked_mul(U256::from(staked_amount)))
+            .and_then(|v| v.checked_mul(staked_duration))
+            .and_then(|v| v.checked_div(adjusted_consumption_rate_denominator))
+            .and_then(|v| v.checked_div(U256::from(current_supply)))
+            .and_then(|v| v.checked_div(minting_period))
+            .ok_or_else(overflow)?;
+
+        if reward > U256::from(remaining_supply) {
+            Ok(remaining_supply)
+        } else {
+            Ok(reward.as_u64())
+        }
+    }
+}
+
+/// Splits the reward into the portion of the shares (e.g., the delegation fee
+/// to the validator) and the remainder (e.g., to the delegator).
+/// ref. "avalanchego/vms/platformvm/reward.Split"
+pub fn split(total_amount: u64, shares: u32) -> (u64, u64) {
+    let remainder_shares = PERCENT_DENOMINATOR.saturating_sub(shares as u64);
+
+    // delay rounding as long as possible for small numbers
+    let remainder_amount = match remainder_shares.checked_mul(total_amount) {
+        Some(v) => v / PERCENT_DENOMINATOR,
+        None => remainder_shares * (total_amount / PERCENT_DENOMINATOR),
+    };
+    (total_amount - remainder_amount, remainder_amount)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::reward::test_calculate --exact --show-output
+#[test]
+fn test_calculate() {
+    // ref. "avalanchego/vms/platformvm/reward.TestRewards"
+    let calculator = Calculator::new(Config::mainnet());
+    let min_duration = Duration::from_secs(24 * 60 * 60);
+    let max_duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+    let tests = [
+        // (720M - 360M) * (1M / 360M) * 12%
+        (
+            max_duration,
+            units::MEGA_AVAX,
+            360 * units::MEGA_AVAX,
+            120 * units::KILO_AVAX,
+        ),
+        // (720M - 400M) * (1M / 400M) * 12%
+        (
+            max_duration,
+            units::MEGA_AVAX,
+            400 * units::MEGA_AVAX,
+            96 * units::KILO_AVAX,
+        ),
+        // (720M - 400M) * (2M / 400M) * 12%
+        (
+            max_duration,
+            2 * units::MEGA_AVAX,
+            400 * units::MEGA_AVAX,
+            192 * units::KILO_AVAX,
+        ),
+        // (720M - 720M) * (1M / 720M) * 12%
+        (max_duration, units::MEGA_AVAX, 720 * units::MEGA_AVAX, 0),
+        // (720M - 360M) * (1M / 360M) * (10% + 2% * min / max) * min / max
+        (
+            min_duration,
+            units::MEGA_AVAX,
+            360 * units::MEGA_AVAX,
+            274122724713,
+        ),
+        (
+            min_duration,
+            units::MEGA_AVAX,
+            400 * units::MEGA_AVAX,
+            219298179771,
+        ),
+        (
+            min_duration,
+            2 * units::MEGA_AVAX,
+            400 * units::MEGA_AVAX,
+            438596359542,
+        ),
+        (min_duration, units::MEGA_AVAX, 720 * units::MEGA_AVAX, 0),
+    ];
+    for (duration, stake_amount, current_supply, expected) in tests.iter() {
+        assert_eq!(
+            calculator
+                .calculate(*duration, *stake_amount, *current_supply)
+                .unwrap(),
+            *expected
+        );
+    }
+
+    // longer staking yields more rewards than re-staking the shorter periods
+    let short = calculator
+        .calculate(min_duration, units::KILO_AVAX, 400 * units::MEGA_AVAX)
+        .unwrap();
+    let long = calculator
+        .calculate(max_duration, units::KILO_AVAX, 400 * units::MEGA_AVAX)
+        .unwrap();
+    assert!(long > short * 365);
+
+    // never exceeds the remaining supply
+    assert_eq!(
+        calculator
+            .calculate(max_duration, u64::MAX, 719 * units::MEGA_AVAX)
+            .unwrap(),
+        units::MEGA_AVAX
+    );
+
+    // the duration longer than the minting period is capped, without overflows
+    assert_eq!(
+        calculator
+            .calculate(Duration::MAX, units::MEGA_AVAX, 360 * units::MEGA_AVAX)
+            .unwrap(),
+        calculator
+            .calculate(max_duration, units::MEGA_AVAX, 360 * units::MEGA_AVAX)
+            .unwrap()
+    );
+    assert_eq!(
+        calculator.calculate(Duration::MAX, u64::MAX, 1).unwrap(),
+        calculator.calculate(max_duration, u64::MAX, 1).unwrap()
+    );
+
+    // overflows with the unbounded config
+    let calculator = Calculator::new(Config {
+        max_consumption_rate: u64::MAX,
+        min_consumption_rate: 0,
+        minting_period: Duration::MAX,
+        supply_cap: u64::MAX,
+    });
+    assert!(calculator.calculate(Duration::MAX, u64::MAX, 1).is_err());
+    let calculator = Calculator::new(Config {
+        minting_period: Duration::ZERO,
+        ..Config::mainnet()
+    });
+    assert!(calculator
+        .calculate(max_duration, units::KILO_AVAX, 400 * units::MEGA_AVAX)
+        .is_err());
+
+    assert_eq!(Config::for_network(5), Config::mainnet());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::reward::test_split --exact --show-output
+#[test]
+fn test_split() {
+    let tests = [
+        (1000, PERCENT_DENOMINATOR as u32, (1000, 0)),
+        (1000, PERCENT_DENOMINATOR as u32 / 2, (500, 500)),
+        (1, PERCENT_DENOMINATOR as u32 - 1, (1, 0)),
+        (1, 1, (1, 0)),
+        (1, 0, (0, 1)),
+        (10, PERCENT_DENOMINATOR as u32 / 10, (1, 9)),
+        (2, PERCENT_DENOMINATOR as u32 / 2, (1, 1)),
+        (u64::MAX, PERCENT_DENOMINATOR as u32, (u64::MAX, 0)),
+    ];
+    for (total_amount, shares, expected) in tests.iter() {
+        assert_eq!(split(*total_amount, *shares), *expected);
+    }
+}
@@ -0,0 +1,366 @@
+//! Primary network staking rules to validate staker transactions
+//! before issuance, rather than learning the mistakes from node errors.
+use crate::{
+    errors::{Error, Result},
+    platformvm::{
+        reward::PERCENT_DENOMINATOR,
+        txs::{
+            add_delegator, add_permissionless_delegator, add_permissionless_validator,
+            add_validator, transform_subnet, Validator,
+        },
+    },
+    units,
+};
+use serde::{Deserialize, Serialize};
+
+/// Maximum duration that the staker start time can be ahead of the current time.
+/// ref. "avalanchego/vms/platformvm/txs/executor.MaxFutureStartTime"
+pub const MAX_FUTURE_START_TIME: u64 = 14 * 24 * 60 * 60;
+
+/// Represents the staking rules of the primary network
+/// (or the permissionless subnet).
+/// ref. "avalanchego/genesis.StakingConfig"
+/// ref. <https://docs.avax.network/nodes/validate/how-to-stake>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Rules {
+    /// Minimum stake in nano-AVAX to be a validator.
+    pub min_validator_stake: u64,
+    /// Maximum stake in nano-AVAX of a validator (including delegations).
+    pub max_validator_stake: u64,
+    /// Minimum stake in nano-AVAX to be a delegator.
+    pub min_delegator_stake: u64,
+    /// Minimum delegation fee over "PERCENT_DENOMINATOR" (e.g., 20,000 is 2%).
+    pub min_delegation_fee: u32,
+    /// Minimum staking duration in seconds.
+    pub min_stake_duration: u64,
+    /// Maximum staking duration in seconds.
+    pub max_stake_duration: u64,
+    /// Maximum delegations a validator can receive, as the factor of its own stake.
+    pub max_validator_weight_factor: u64,
+}
+
+impl Rules {
+    /// ref. "avalanchego/genesis.MainnetParams"
+    pub fn mainnet() -> Self {
+        Self {
+            min_validator_stake: 2 * units::KILO_AVAX,
+            max_validator_stake: 3 * units::MEGA_AVAX,
+            min_delegator_stake: 25 * units::AVAX,
+            min_delegation_fee: 20_000, // 2%
+            min_stake_duration: 14 * 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+            max_validator_weight_factor: 5,
+        }
+    }
+
+    /// ref. "avalanchego/genesis.FujiParams"
+    pub fn fuji() -> Self {
+        Self {
+            min_validator_stake: units::AVAX,
+            max_validator_stake: 3 * units::MEGA_AVAX,
+            min_delegator_stake: units::AVAX,
+            min_delegation_fee: 20_000, // 2%
+            min_stake_duration: 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+            max_validator_weight_factor: 5,
+        }
+    }
+
+    /// ref. "avalanchego/genesis.LocalParams"
+    pub fn local() -> Self {
+        Self {
+            min_validator_stake: 2 * units::KILO_AVAX,
+            max_validator_stake: 3 * units::MEGA_AVAX,
+            min_delegator_stake: 25 * units::AVAX,
+            min_delegation_fee: 20_000, // 2%
+            min_stake_duration: 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+            max_validator_weight_factor: 5,
+        }
+    }
+
+    /// Returns the rules for the network Id, where any network other than
+    /// mainnet and fuji uses the local network rules.
+    /// ref. "avalanchego/genesis.GetStakingConfig"
+    pub fn for_network(network_id: u32) -> Self {
+        match network_id {
+            1 => Self::mainnet(),
+            5 => Self::fuji(),
+            _ => Self::local(),
+        }
+    }
+
+    /// Returns the rules only for the known networks (mainnet, fuji and local),
+    /// since the custom networks may define their own staking parameters.
+    pub fn for_known_network(network_id: u32) -> Option<Self> {
+        match network_id {
+            1 => Some(Self::mainnet()),
+            5 => Some(Self::fuji()),
+            12345 => Some(Self::local()),
+            _ => None,
+        }
+    }
+
+    /// Returns the rules of the permissionless subnet.
+    pub fn from_transform_subnet(tx: &transform_subnet::Tx) -> Self {
+        Self {
+            min_validator_stake: tx.min_validator_stake,
+            max_validator_stake: tx.max_validator_stake,
+            min_delegator_stake: tx.min_delegator_stake,
+            min_delegation_fee: tx.min_delegation_fee,
+            min_stake_duration: tx.min_stake_duration as u64,
+            max_stake_duration: tx.max_stake_duration as u64,
+            max_validator_weight_factor: tx.max_validator_weight_factor as u64,
+        }
+    }
+
+    /// Returns the maximum total weight (own stake + delegations) of the validator.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyAddDelegatorTx"
+    pub fn max_validator_weight(&self, validator_stake: u64) -> u64 {
+        validator_stake
+            .saturating_mul(self.max_validator_weight_factor)
+            .min(self.max_validator_stake)
+    }
+
+    /// Verifies the "AddValidatorTx" against the rules,
+    /// given the current time in unix seconds.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyAddValidatorTx"
+    pub fn verify_add_validator(&self, tx: &add_validator::Tx, now_unix: u64) -> Result<()> {
+        self.verify_validator(&tx.validator, tx.shares, now_unix)
+    }
+
+    /// Verifies the "AddPermissionlessValidatorTx" against the rules,
+    /// given the current time in unix seconds. Use "from_transform_subnet"
+    /// rules for the permissionless subnet validator.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyAddPermissionlessValidatorTx"
+    pub fn verify_add_permissionless_validator(
+        &self,
+        tx: &add_permissionless_validator::Tx,
+        now_unix: u64,
+    ) -> Result<()> {
+        self.verify_validator(&tx.validator, tx.delegation_shares, now_unix)
+    }
+
+    /// Verifies the "AddDelegatorTx" against the rules,
+    /// given the current time in unix seconds.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyAddDelegatorTx"
+    pub fn verify_add_delegator(&self, tx: &add_delegator::Tx, now_unix: u64) -> Result<()> {
+        self.verify_delegator(&tx.validator, now_unix)
+    }
+
+    /// Verifies the "AddPermissionlessDelegatorTx" against the rules,
+    /// given the current time in unix seconds.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyAddPermissionlessDelegatorTx"
+    pub fn verify_add_permissionless_delegator(
+        &self,
+        tx: &add_permissionless_delegator::Tx,
+        now_unix: u64,
+    ) -> Result<()> {
+        self.verify_delegator(&tx.validator, now_unix)
+    }
+
+    fn verify_validator(&self, validator: &Validator, shares: u32, now_unix: u64) -> Result<()> {
+        let err = if shares as u64 > PERCENT_DENOMINATOR {
+            Some("a staker can only require at most 100% reward".to_string()) // ref. "errTooManyShares"
+        } else if shares < self.min_delegation_fee {
+            Some(format!(
+                "staker charges an insufficient delegation fee {} (minimum {})",
+                shares, self.min_delegation_fee
+            )) // ref. "ErrInsufficientDelegationFee"
+        } else if validator.weight < self.min_validator_stake {
+            Some(format!(
+                "weight of this validator is too low {} (minimum {})",
+                validator.weight, self.min_validator_stake
+            )) // ref. "ErrWeightTooSmall"
+        } else if validator.weight > self.max_validator_stake {
+            Some(format!(
+                "weight of this validator is too large {} (maximum {})",
+                validator.weight, self.max_validator_stake
+            )) // ref. "ErrWeightTooLarge"
+        } else {
+            None
+        };
+        if let Some(message) = err {
+            return Err(Error::Other {
+                message,
+                retryable: false,
+            });
+        }
+
+        self.verify_staking_period(validator, now_unix)
+    }
+
+    fn verify_delegator(&self, validator: &Validator, now_unix: u64) -> Result<()> {
+        if validator.weight < self.min_delegator_stake {
+            return Err(Error::Other {
+                message: format!(
+                    "weight of this delegator is too low {} (minimum {})",
+                    validator.weight, self.min_delegator_stake
+                ), // ref. "ErrWeightTooSmall"
+                retryable: false,
+            });
+        }
+
+        self.verify_staking_period(validator, now_unix)
+    }
+
+    /// Since Durango, the node ignores the start time and the staking starts
+    /// on acceptance, where only the end time and the duration matter, thus
+    /// the start time checks are only an approximation of the pre-Durango rules.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.verifyStakerStartTime"
+    fn verify_staking_period(&self, validator: &Validator, now_unix: u64) -> Result<()> {
+        let err = if validator.start >= validator.end {
+            Some("staking period must end after it starts".to_string()) // ref. "errStartAfterEndTime"
+        } else if validator.start <= now_unix {
+            Some(format!(
+                "staker start time {} is not after the current time {}",
+                validator.start, now_unix
+            )) // ref. "ErrTimestampNotInFuture"
+        } else if validator.start > now_unix + MAX_FUTURE_START_TIME {
+            Some(format!(
+                "staker is attempting to start staking too far in the future {} (current time {})",
+                validator.start, now_unix
+            )) // ref. "ErrFutureStakeTime"
+        } else if validator.end - validator.start < self.min_stake_duration {
+            Some(format!(
+                "staking period is too short {} seconds (minimum {})",
+                validator.end - validator.start,
+                self.min_stake_duration
+            )) // ref. "ErrStakeTooShort"
+        } else if validator.end - validator.start > self.max_stake_duration {
+            Some(format!(
+                "staking period is too long {} seconds (maximum {})",
+                validator.end - validator.start,
+                self.max_stake_duration
+            )) // ref. "ErrStakeTooLong"
+        } else {
+            None
+        };
+        if let Some(message) = err {
+            return Err(Error::Other {
+                message,
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::staking::test_rules --exact --show-output
+#[test]
+fn test_rules() {
+    use crate::ids::node;
+
+    let now = 1_700_000_000_u64;
+    let day = 24 * 60 * 60;
+
+    let rules = Rules::for_network(1);
+    assert_eq!(rules, Rules::mainnet());
+    assert_eq!(Rules::for_network(5), Rules::fuji());
+    assert_eq!(Rules::for_network(12345), Rules::local());
+    assert_eq!(Rules::for_network(1000000), Rules::local());
+    assert_eq!(Rules::for_known_network(1), Some(Rules::mainnet()));
+    assert_eq!(Rules::for_known_network(12345), Some(Rules::local()));
+    assert_eq!(Rules::for_known_network(1000000), None);
+
+    let tx = add_validator::Tx {
+        validator: Validator {
+            node_id: node::Id::from_slice(&[1; 20]),
+            start: now + 60,
+            end: now + 60 + 14 * day,
+            weight: 2 * units::KILO_AVAX,
+        },
+        shares: 20_000,
+        ..add_validator::Tx::default()
+    };
+    assert!(rules.verify_add_validator(&tx, now).is_ok());
+
+    let mut invalid = tx.clone();
+    invalid.validator.weight = units::KILO_AVAX;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+    // fuji allows the smaller stake and the shorter period
+    assert!(Rules::fuji().verify_add_validator(&invalid, now).is_ok());
+    invalid.validator.end = invalid.validator.start + day;
+    assert!(Rules::fuji().verify_add_validator(&invalid, now).is_ok());
+    assert!(Rules::fuji()
+        .verify_add_validator(&invalid, invalid.validator.start)
+        .is_err());
+
+    let mut invalid = tx.clone();
+    invalid.validator.weight = 3 * units::MEGA_AVAX + 1;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+
+    let mut invalid = tx.clone();
+    invalid.shares = 19_999;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+    invalid.shares = PERCENT_DENOMINATOR as u32 + 1;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+
+    let mut invalid = tx.clone();
+    invalid.validator.end = invalid.validator.start + 14 * day - 1;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+    invalid.validator.end = invalid.validator.start + 365 * day + 1;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+
+    let mut invalid = tx.clone();
+    invalid.validator.start = now + MAX_FUTURE_START_TIME + 1;
+    invalid.validator.end = invalid.validator.start + 14 * day;
+    assert!(rules.verify_add_validator(&invalid, now).is_err());
+
+    let tx = add_permissionless_validator::Tx {
+        validator: tx.validator.clone(),
+        delegation_shares: 20_000,
+        ..add_permissionless_validator::Tx::default()
+    };
+    assert!(rules.verify_add_permissionless_validator(&tx, now).is_ok());
+
+    let tx = add_delegator::Tx {
+        validator: Validator {
+            weight: 25 * units::AVAX,
+            ..tx.validator.clone()
+        },
+        ..add_delegator::Tx::default()
+    };
+    assert!(rules.verify_add_delegator(&tx, now).is_ok());
+    let mut invalid = tx.clone();
+    invalid.validator.weight = 25 * units::AVAX - 1;
+    assert!(rules.verify_add_delegator(&invalid, now).is_err());
+
+    let tx = add_permissionless_delegator::Tx {
+        validator: tx.validator.clone(),
+        ..add_permissionless_delegator::Tx::default()
+    };
+    assert!(rules.verify_add_permissionless_delegator(&tx, now).is_ok());
+
+    assert_eq!(
+        rules.max_validator_weight(2 * units::KILO_AVAX),
+        10 * units::KILO_AVAX
+    );
+    assert_eq!(
+        rules.max_validator_weight(units::MEGA_AVAX),
+        3 * units::MEGA_AVAX
+    );
+
+    let subnet_rules = Rules::from_transform_subnet(&transform_subnet::Tx {
+        min_validator_stake: 10,
+        max_validator_stake: 100,
+        min_delegator_stake: 1,
+        min_delegation_fee: 0,
+        min_stake_duration: day as u32,
+        max_stake_duration: 30 * day as u32,
+        max_validator_weight_factor: 2,
+        ..transform_subnet::Tx::default()
+    });
+    let tx = add_permissionless_validator::Tx {
+        validator: Validator {
+            weight: 50,
+            end: now + 60 + day,
+            ..tx.validator.clone()
+        },
+        ..add_permissionless_validator::Tx::default()
+    };
+    assert!(subnet_rules
+        .verify_add_permissionless_validator(&tx, now)
+        .is_ok());
+    assert!(rules.verify_add_permissionless_validator(&tx, now).is_err());
+}
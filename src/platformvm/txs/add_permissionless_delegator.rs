@@ -0,0 +1,424 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Delegates the stake to a primary network or a permissionless subnet validator.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AddPermissionlessDelegatorTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    pub validator: platformvm::txs::Validator,
+
+    /// ID of the subnet this validator is validating.
+    /// ref. "github.com/ava-labs/avalanchego/utils/constants.PrimaryNetworkID" (ids.Empty).
+    pub subnet_id: ids::Id,
+
+    pub stake_transferable_outputs: Option<Vec<txs::transferable::Output>>,
+    pub rewards_owner: key::secp256k1::txs::OutputOwners,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            validator: platformvm::txs::Validator::default(),
+            subnet_id: ids::Id::empty(), // primary network
+            stake_transferable_outputs: None,
+            rewards_owner: key::secp256k1::txs::OutputOwners::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.AddPermissionlessDelegatorTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Verifies the tx is well-formed, and the stake adds up to the delegation weight.
+    /// ref. "avalanchego/vms/platformvm/txs.AddPermissionlessDelegatorTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.base_tx.verify_syntax()?;
+        self.validator.verify_syntax()?;
+        self.rewards_owner.verify_syntax()?;
+
+        let total_stake = platformvm::txs::verify_stake_outputs(
+            self.stake_transferable_outputs
+                .as_deref()
+                .unwrap_or_default(),
+        )?;
+        if total_stake != self.validator.weight {
+            return Err(Error::Other {
+                message: format!(
+                    "delegator weight mismatch: {} != {}",
+                    total_stake, self.validator.weight
+                ), // ref. "errDelegatorWeightMismatch"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly + Clone>(
+        &mut self,
+        signers: Vec<Vec<T>>,
+    ) -> Result<()> {
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        validator,
+        subnet_id,
+        stake_transferable_outputs,
+        #[interface]
+        rewards_owner,
+    }
+);
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_permissionless_delegator::test_add_permissionless_delegator_tx_serialization_with_one_signer --exact --show-output
+#[test]
+fn test_add_permissionless_delegator_tx_serialization_with_one_signer() {
+    use crate::ids::{node, short};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let asset_id = ids::Id::from_slice(&[0x88; 32]);
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x65; 20])]);
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1000000,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[0x78; 32]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id,
+                stakeable_lock_in: Some(platformvm::txs::StakeableLockIn {
+                    locktime: 0x63c91062,
+                    transfer_input: key::secp256k1::txs::transfer::Input::new(2000, vec![0]),
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        validator: platformvm::txs::Validator {
+            node_id: node::Id::from_slice(&[0x9c; 20]),
+            start: 0x623d7267,
+            end: 0x63c91062,
+            weight: 2000,
+        },
+        subnet_id: ids::Id::from_slice(&[0x01; 32]),
+        stake_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id,
+            stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                locktime: 0x63c91062,
+                transfer_output: key::secp256k1::txs::transfer::Output::new(2000, owners.clone()),
+            }),
+            ..txs::transferable::Output::default()
+        }]),
+        rewards_owner: owners,
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    tx.verify_syntax().expect("failed verify_syntax");
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let b = &tx_metadata.tx_bytes_with_no_signature;
+    // codec version, type ID, network ID
+    assert_eq!(
+        &b[..10],
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x0f, 0x42, 0x40]
+    );
+
+    // subnet ID follows the validator (node ID, start, end, weight)
+    // after the base tx (network ID, blockchain ID, 0 outputs, 1 input, empty memo)
+    let base_tx_len = 4 + 32 + 4 + 4 + (32 + 4 + 32 + 4 + 8 + 4 + 8 + 4 + 4) + 4;
+    let subnet_id_offset = 6 + base_tx_len + 20 + 8 * 3;
+    assert_eq!(&b[subnet_id_offset..subnet_id_offset + 32], &[0x01; 32]);
+
+    let parsed_tx = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_permissionless_delegator::test_add_permissionless_delegator_tx_serialization_primary_network --exact --show-output
+#[test]
+fn test_add_permissionless_delegator_tx_serialization_primary_network() {
+    use crate::{
+        ids::{node, short},
+        units,
+    };
+    use std::str::FromStr;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // ref. "avalanchego/vms/platformvm/txs.TestAddPermissionlessPrimaryDelegatorSerialization"
+    let avax_asset_id =
+        ids::Id::from_str("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z").unwrap();
+    let addr = short::Id::from_slice(&[
+        0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x44, 0x55, //
+        0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, //
+    ]);
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &[addr]);
+    let tx_id = ids::Id::from_slice(&[
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+    ]);
+    let node_id = node::Id::from_slice(&[
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x11, 0x22, //
+        0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x11, 0x22, 0x33, 0x44, //
+    ]);
+    let amount = 2 * units::KILO_AVAX;
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 1,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id,
+                    output_index: 1,
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: avax_asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input::new(amount, vec![1])),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        validator: platformvm::txs::Validator {
+            node_id,
+            start: 12345,
+            end: 12345 + 200 * 24 * 60 * 60,
+            weight: amount,
+        },
+        stake_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id: avax_asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+                amount,
+                owners.clone(),
+            )),
+            ..txs::transferable::Output::default()
+        }]),
+        rewards_owner: owners,
+        ..Tx::default()
+    };
+
+    let expected_unsigned_bytes: &[u8] = &[
+        // codec version
+        0x00, 0x00, //
+        //
+        // platformvm.AddPermissionlessDelegatorTx type ID
+        0x00, 0x00, 0x00, 0x1a, //
+        //
+        // network id
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // blockchain id
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // outs.len()
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // ins.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "ins[0]" TransferableInput.utxo_id.tx_id
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, //
+        //
+        // "ins[0]" TransferableInput.utxo_id.output_index
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "ins[0]" TransferableInput.asset_id
+        0x21, 0xe6, 0x73, 0x17, 0xcb, 0xc4, 0xbe, 0x2a, //
+        0xeb, 0x00, 0x67, 0x7a, 0xd6, 0x46, 0x27, 0x78, //
+        0xa8, 0xf5, 0x22, 0x74, 0xb9, 0xd6, 0x05, 0xdf, //
+        0x25, 0x91, 0xb2, 0x30, 0x27, 0xa8, 0x7d, 0xff, //
+        //
+        // "ins[0]" secp256k1fx.TransferInput type ID
+        0x00, 0x00, 0x00, 0x05, //
+        //
+        // "ins[0]" TransferableInput.input.key::secp256k1::txs::transfer::Input.amount
+        0x00, 0x00, 0x01, 0xd1, 0xa9, 0x4a, 0x20, 0x00, //
+        //
+        // "ins[0]" TransferableInput.input.key::secp256k1::txs::transfer::Input.sig_indices.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "ins[0]" TransferableInput.input.key::secp256k1::txs::transfer::Input.sig_indices[0]
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // memo.len()
+        0x00, 0x00, 0x00, 0x00, //
+        //
+        // validator.node_id
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, //
+        0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, //
+        0x11, 0x22, 0x33, 0x44, //
+        //
+        // validator.start
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x39, //
+        //
+        // validator.end
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x07, 0xdc, 0x39, //
+        //
+        // validator.weight
+        0x00, 0x00, 0x01, 0xd1, 0xa9, 0x4a, 0x20, 0x00, //
+        //
+        // subnet_id (primary network)
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // stake_outs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "stake_outs[0]" TransferableOutput.asset_id
+        0x21, 0xe6, 0x73, 0x17, 0xcb, 0xc4, 0xbe, 0x2a, //
+        0xeb, 0x00, 0x67, 0x7a, 0xd6, 0x46, 0x27, 0x78, //
+        0xa8, 0xf5, 0x22, 0x74, 0xb9, 0xd6, 0x05, 0xdf, //
+        0x25, 0x91, 0xb2, 0x30, 0x27, 0xa8, 0x7d, 0xff, //
+        //
+        // "stake_outs[0]" secp256k1fx.TransferOutput type ID
+        0x00, 0x00, 0x00, 0x07, //
+        //
+        // "stake_outs[0]" TransferableOutput.out.key::secp256k1::txs::transfer::Output.amount
+        0x00, 0x00, 0x01, 0xd1, 0xa9, 0x4a, 0x20, 0x00, //
+        //
+        // "stake_outs[0]" TransferableOutput.out.key::secp256k1::txs::transfer::Output.output_owners.locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // "stake_outs[0]" TransferableOutput.out.key::secp256k1::txs::transfer::Output.output_owners.threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "stake_outs[0]" TransferableOutput.out.key::secp256k1::txs::transfer::Output.output_owners.addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // "stake_outs[0]" TransferableOutput.out.key::secp256k1::txs::transfer::Output.output_owners.addrs[0]
+        0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, //
+        0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, //
+        0xcc, 0xdd, 0xee, 0xff, //
+        //
+        // rewards_owner secp256k1fx.OutputOwners type ID
+        0x00, 0x00, 0x00, 0x0b, //
+        //
+        // rewards_owner.locktime
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        //
+        // rewards_owner.threshold
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // rewards_owner.addrs.len()
+        0x00, 0x00, 0x00, 0x01, //
+        //
+        // rewards_owner.addrs[0]
+        0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, //
+        0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, //
+        0xcc, 0xdd, 0xee, 0xff, //
+    ];
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    tx.verify_syntax().expect("failed verify_syntax");
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    assert_eq!(
+        tx_metadata.tx_bytes_with_no_signature,
+        expected_unsigned_bytes
+    );
+
+    let parsed_tx = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+}
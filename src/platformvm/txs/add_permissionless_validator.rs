@@ -1,7 +1,9 @@
 use crate::{
     codec,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    ids, impl_linear_codec, key, packer,
+    platformvm::{self, reward::PERCENT_DENOMINATOR},
+    txs,
 };
 use serde::{Deserialize, Serialize};
 
@@ -90,242 +92,156 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Verifies the tx is well-formed, and the stake adds up to the validator weight.
+    /// Only the primary network validators register a BLS key.
+    /// ref. "avalanchego/vms/platformvm/txs.AddPermissionlessValidatorTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.validator.node_id.is_empty() {
+            return Err(Error::Other {
+                message: "empty nodeID is not allowed".to_string(), // ref. "errEmptyNodeID"
+                retryable: false,
+            });
+        }
+        if self.delegation_shares as u64 > PERCENT_DENOMINATOR {
+            return Err(Error::Other {
+                message: format!(
+                    "a staker can only require at most {} shares from delegators",
+                    PERCENT_DENOMINATOR
+                ), // ref. "errTooManyShares"
+                retryable: false,
+            });
+        }
+        self.base_tx.verify_syntax()?;
+        self.validator.verify_syntax()?;
+        self.validator_rewards_owner.verify_syntax()?;
+        self.delegator_rewards_owner.verify_syntax()?;
+
+        let is_primary_network = self.subnet_id.is_empty();
+        if self.signer.is_some() != is_primary_network {
+            return Err(Error::Other {
+                message: format!(
+                    "invalid signer: has_key={} != is_primary_network={}",
+                    self.signer.is_some(),
+                    is_primary_network
+                ), // ref. "errInvalidSigner"
+                retryable: false,
+            });
+        }
+        if let Some(signer) = &self.signer {
+            let verified = signer.verify().map_err(|e| Error::Other {
+                message: format!("failed ProofOfPossession.verify '{}'", e),
+                retryable: false,
+            })?;
+            if !verified {
+                return Err(Error::Other {
+                    message: "invalid proof of possession".to_string(), // ref. "errInvalidProofOfPossession"
+                    retryable: false,
+                });
+            }
+        }
+
+        let total_stake = platformvm::txs::verify_stake_outputs(
+            self.stake_transferable_outputs
+                .as_deref()
+                .unwrap_or_default(),
+        )?;
+        if total_stake != self.validator.weight {
+            return Err(Error::Other {
+                message: format!(
+                    "validator weight mismatch: {} != {}",
+                    total_stake, self.validator.weight
+                ), // ref. "errValidatorWeightMismatch"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly + Clone>(
         &mut self,
         signers: Vec<Vec<T>>,
     ) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let unsigned_tx_bytes = packer.take_bytes();
-        packer.set_bytes(&unsigned_tx_bytes);
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // pack the second field "validator" in the struct
-        packer.pack_bytes(self.validator.node_id.as_ref())?;
-        packer.pack_u64(self.validator.start)?;
-        packer.pack_u64(self.validator.end)?;
-        packer.pack_u64(self.validator.weight)?;
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        // pack the third field "subnet_id" in the struct
-        packer.pack_bytes(self.subnet_id.as_ref())?;
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
 
-        // pack the fourth field "signer"
-        if let Some(signer) = &self.signer {
-            let type_id_signer: u32 = 28;
-            packer.pack_u32(type_id_signer)?;
+/// "signer.Signer" is an interface, where "None" is encoded as "signer.Empty"
+/// for the non-primary network validators.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/signer#ProofOfPossession>
+impl codec::linear::Pack for Option<key::bls::ProofOfPossession> {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        if let Some(signer) = self {
+            packer.pack_u32(*(codec::P_TYPES.get("signer.ProofOfPossession").unwrap()) as u32)?;
             packer.pack_bytes(&signer.public_key)?;
             packer.pack_bytes(&signer.proof_of_possession)?;
         } else {
-            // empty signer for non-primary network
-            let type_id_signer: u32 = 27;
-            packer.pack_u32(type_id_signer)?;
-        }
-
-        // pack the third field "stake" in the struct
-        if let Some(stake_transferable_outputs) = &self.stake_transferable_outputs {
-            packer.pack_u32(stake_transferable_outputs.len() as u32)?;
-
-            for transferable_output in stake_transferable_outputs.iter() {
-                // "TransferableOutput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableOutput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                if transferable_output.transfer_output.is_none()
-                    && transferable_output.stakeable_lock_out.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_out = {
-                    if transferable_output.transfer_output.is_some() {
-                        key::secp256k1::txs::transfer::Output::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockOut::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(type_id_transferable_out)?;
-
-                match type_id_transferable_out {
-                    7 => {
-                        // "key::secp256k1::txs::transfer::Output"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(transfer_output.amount)?;
-
-                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferOutput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        packer.pack_u64(transfer_output.output_owners.locktime)?;
-                        packer.pack_u32(transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
-                        for addr in transfer_output.output_owners.addresses.iter() {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    22 => {
-                        // "platformvm::txs::StakeableLockOut"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        let stakeable_lock_out =
-                            transferable_output.stakeable_lock_out.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                        packer.pack_u64(stakeable_lock_out.locktime)?;
-
-                        // secp256k1fx.TransferOutput type ID
-                        packer.pack_u32(7)?;
-
-                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockOut"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        //
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
-                        packer
-                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
-                        packer
-                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(
-                            stakeable_lock_out
-                                .transfer_output
-                                .output_owners
-                                .addresses
-                                .len() as u32,
-                        )?;
-                        for addr in stakeable_lock_out
-                            .transfer_output
-                            .output_owners
-                            .addresses
-                            .iter()
-                        {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableOutput",
-                                type_id_transferable_out
-                            ),
-                            retryable: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            packer.pack_u32(0_u32)?;
-        }
-
-        // pack the fourth field "reward_owner" in the struct
-        // not embedded thus encode struct type id
-        let output_owners_type_id = key::secp256k1::txs::OutputOwners::type_id();
-        packer.pack_u32(output_owners_type_id)?;
-        packer.pack_u64(self.validator_rewards_owner.locktime)?;
-        packer.pack_u32(self.validator_rewards_owner.threshold)?;
-        packer.pack_u32(self.validator_rewards_owner.addresses.len() as u32)?;
-        for addr in self.validator_rewards_owner.addresses.iter() {
-            packer.pack_bytes(addr.as_ref())?;
+            packer.pack_u32(*(codec::P_TYPES.get("signer.Empty").unwrap()) as u32)?;
         }
+        Ok(())
+    }
+}
 
-        packer.pack_u32(output_owners_type_id)?;
-        packer.pack_u64(self.delegator_rewards_owner.locktime)?;
-        packer.pack_u32(self.delegator_rewards_owner.threshold)?;
-        packer.pack_u32(self.delegator_rewards_owner.addresses.len() as u32)?;
-        for addr in self.delegator_rewards_owner.addresses.iter() {
-            packer.pack_bytes(addr.as_ref())?;
-        }
-
-        // pack the fifth field "shares" in the struct
-        packer.pack_u32(self.delegation_shares)?;
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.AddPermissionlessValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.AddPermissionlessValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddPermissionlessValidatorTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            // add a new credential to "Tx"
-            self.creds.push(cred);
+impl codec::linear::Unpack for Option<key::bls::ProofOfPossession> {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let type_id_signer = packer.unpack_u32()?;
+        if type_id_signer == *(codec::P_TYPES.get("signer.Empty").unwrap()) as u32 {
+            return Ok(None);
         }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
+        if type_id_signer != *(codec::P_TYPES.get("signer.ProofOfPossession").unwrap()) as u32 {
+            return Err(Error::Other {
+                message: format!("unexpected type ID {} for signer.Signer", type_id_signer),
+                retryable: false,
+            });
         }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
-
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
 
-        Ok(())
+        let public_key = packer.unpack_bytes(key::bls::public_key::LEN)?;
+        let proof_of_possession = packer.unpack_bytes(key::bls::signature::LEN)?;
+        Ok(Some(key::bls::ProofOfPossession {
+            public_key,
+            proof_of_possession,
+            ..key::bls::ProofOfPossession::default()
+        }))
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        validator,
+        subnet_id,
+        signer,
+        stake_transferable_outputs,
+        #[interface]
+        validator_rewards_owner,
+        #[interface]
+        delegator_rewards_owner,
+        delegation_shares,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_permissionless_validator::test_add_permissionless_validator_tx_serialization_with_one_signer --exact --show-output
 #[test]
 fn test_add_permissionless_validator_tx_serialization_with_one_signer() {
@@ -454,6 +370,9 @@ fn test_add_permissionless_validator_tx_serialization_with_one_signer() {
     .expect("failed to load private key");
     let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key];
     let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![keys1];
+    // only the primary network validators register a BLS key,
+    // so this subnet validator is syntactically invalid but still serializable
+    assert!(tx.verify_syntax().is_err());
     ab!(tx.sign(signers)).expect("failed to sign");
     let tx_metadata = tx.base_tx.metadata.clone().unwrap();
     let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
@@ -672,4 +591,8 @@ fn test_add_permissionless_validator_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
 }
@@ -1,4 +1,8 @@
-use crate::{codec, errors::Result, hash, ids, key, platformvm, txs};
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, platformvm, txs,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -22,6 +26,12 @@ impl Validator {
     }
 }
 
+// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#SubnetValidator>
+impl_linear_codec!(Validator {
+    validator,
+    subnet_id,
+});
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#AddSubnetValidatorTx>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
@@ -82,100 +92,57 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// ref. "avalanchego/vms/platformvm/txs.AddSubnetValidatorTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.validator.subnet_id.is_empty() {
+            return Err(Error::Other {
+                message: "can't add primary network validator with AddSubnetValidatorTx"
+                    .to_string(), // ref. "errAddPrimaryNetworkValidator"
+                retryable: false,
+            });
+        }
+        self.base_tx.verify_syntax()?;
+        self.validator.validator.verify_syntax()?;
+        self.subnet_auth.verify_syntax()
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let unsigned_tx_bytes = packer.take_bytes();
-        packer.set_bytes(&unsigned_tx_bytes);
-
-        // pack the second field "validator" in the struct
-        packer.pack_bytes(self.validator.validator.node_id.as_ref())?;
-        packer.pack_u64(self.validator.validator.start)?;
-        packer.pack_u64(self.validator.validator.end)?;
-        packer.pack_u64(self.validator.validator.weight)?;
-        packer.pack_bytes(self.validator.subnet_id.as_ref())?;
-
-        // pack the third field "subnet_auth" in the struct
-        let subnet_auth_type_id = key::secp256k1::txs::Input::type_id();
-        packer.pack_u32(subnet_auth_type_id)?;
-        packer.pack_u32(self.subnet_auth.sig_indices.len() as u32)?;
-        for sig_idx in self.subnet_auth.sig_indices.iter() {
-            packer.pack_u32(*sig_idx)?;
-        }
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.AddValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.AddValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddValidatorTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        Ok(())
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        validator,
+        #[interface]
+        subnet_auth,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_subnet_validator::test_add_subnet_validator_tx_serialization_with_one_signer --exact --show-output
 #[test]
 fn test_add_subnet_validator_tx_serialization_with_one_signer() {
@@ -265,6 +232,7 @@ fn test_add_subnet_validator_tx_serialization_with_one_signer() {
     let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key.clone()];
     let keys2: Vec<key::secp256k1::private_key::Key> = vec![test_key];
     let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![keys1, keys2];
+    tx.verify_syntax().expect("failed verify_syntax");
     ab!(tx.sign(signers)).expect("failed to sign");
     let tx_metadata = tx.base_tx.metadata.clone().unwrap();
     let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
@@ -426,4 +394,8 @@ fn test_add_subnet_validator_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
 }
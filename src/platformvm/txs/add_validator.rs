@@ -1,7 +1,9 @@
 use crate::{
     codec,
     errors::{Error, Result},
-    hash, ids, key, platformvm, txs,
+    ids, impl_linear_codec, key,
+    platformvm::{self, reward::PERCENT_DENOMINATOR},
+    txs,
 };
 use serde::{Deserialize, Serialize};
 
@@ -69,220 +71,81 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
+    /// Verifies the tx is well-formed, and the stake adds up to the validator weight.
+    /// ref. "avalanchego/vms/platformvm/txs.AddValidatorTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.shares as u64 > PERCENT_DENOMINATOR {
+            return Err(Error::Other {
+                message: format!(
+                    "a staker can only require at most {} shares from delegators",
+                    PERCENT_DENOMINATOR
+                ), // ref. "errTooManyShares"
+                retryable: false,
+            });
+        }
+        self.base_tx.verify_syntax()?;
+        self.validator.verify_syntax()?;
+        self.rewards_owner.verify_syntax()?;
+
+        let total_stake = platformvm::txs::verify_stake_outputs(
+            self.stake_transferable_outputs
+                .as_deref()
+                .unwrap_or_default(),
+        )?;
+        if total_stake != self.validator.weight {
+            return Err(Error::Other {
+                message: format!(
+                    "validator weight mismatch: {} != {}",
+                    total_stake, self.validator.weight
+                ), // ref. "errValidatorWeightMismatch"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
     pub async fn sign<T: key::secp256k1::SignOnly + Clone>(
         &mut self,
         signers: Vec<Vec<T>>,
     ) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let unsigned_tx_bytes = packer.take_bytes();
-        packer.set_bytes(&unsigned_tx_bytes);
-
-        // pack the second field "validator" in the struct
-        packer.pack_bytes(self.validator.node_id.as_ref())?;
-        packer.pack_u64(self.validator.start)?;
-        packer.pack_u64(self.validator.end)?;
-        packer.pack_u64(self.validator.weight)?;
-
-        // pack the third field "stake" in the struct
-        if self.stake_transferable_outputs.is_some() {
-            let stake_transferable_outputs = self.stake_transferable_outputs.as_ref().unwrap();
-            packer.pack_u32(stake_transferable_outputs.len() as u32)?;
-
-            for transferable_output in stake_transferable_outputs.iter() {
-                // "TransferableOutput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableOutput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                if transferable_output.transfer_output.is_none()
-                    && transferable_output.stakeable_lock_out.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_out = {
-                    if transferable_output.transfer_output.is_some() {
-                        key::secp256k1::txs::transfer::Output::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockOut::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(type_id_transferable_out)?;
-
-                match type_id_transferable_out {
-                    7 => {
-                        // "key::secp256k1::txs::transfer::Output"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(transfer_output.amount)?;
-
-                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferOutput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        packer.pack_u64(transfer_output.output_owners.locktime)?;
-                        packer.pack_u32(transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
-                        for addr in transfer_output.output_owners.addresses.iter() {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    22 => {
-                        // "platformvm::txs::StakeableLockOut"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        let stakeable_lock_out =
-                            transferable_output.stakeable_lock_out.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                        packer.pack_u64(stakeable_lock_out.locktime)?;
-
-                        // secp256k1fx.TransferOutput type ID
-                        packer.pack_u32(7)?;
-
-                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockOut"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        //
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
-                        packer
-                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
-                        packer
-                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(
-                            stakeable_lock_out
-                                .transfer_output
-                                .output_owners
-                                .addresses
-                                .len() as u32,
-                        )?;
-                        for addr in stakeable_lock_out
-                            .transfer_output
-                            .output_owners
-                            .addresses
-                            .iter()
-                        {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableOutput",
-                                type_id_transferable_out
-                            ),
-                            retryable: false,
-                        });
-                    }
-                }
-            }
-        } else {
-            packer.pack_u32(0_u32)?;
-        }
-
-        // pack the fourth field "reward_owner" in the struct
-        // not embedded thus encode struct type id
-        let output_owners_type_id = key::secp256k1::txs::OutputOwners::type_id();
-        packer.pack_u32(output_owners_type_id)?;
-        packer.pack_u64(self.rewards_owner.locktime)?;
-        packer.pack_u32(self.rewards_owner.threshold)?;
-        packer.pack_u32(self.rewards_owner.addresses.len() as u32)?;
-        for addr in self.rewards_owner.addresses.iter() {
-            packer.pack_bytes(addr.as_ref())?;
-        }
-
-        // pack the fifth field "shares" in the struct
-        packer.pack_u32(self.shares)?;
-
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.AddValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.AddValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddValidatorTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
-
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        Ok(())
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        validator,
+        stake_transferable_outputs,
+        #[interface]
+        rewards_owner,
+        shares,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::add_validator::test_add_validator_tx_serialization_with_one_signer --exact --show-output
 #[test]
 fn test_add_validator_tx_serialization_with_one_signer() {
@@ -389,6 +252,7 @@ fn test_add_validator_tx_serialization_with_one_signer() {
     .expect("failed to load private key");
     let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key];
     let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![keys1];
+    tx.verify_syntax().expect("failed verify_syntax");
     ab!(tx.sign(signers)).expect("failed to sign");
     let tx_metadata = tx.base_tx.metadata.clone().unwrap();
     let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
@@ -562,4 +426,8 @@ fn test_add_validator_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
 }
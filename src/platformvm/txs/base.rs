@@ -0,0 +1,148 @@
+use crate::{codec, errors::Result, ids, impl_linear_codec, key, platformvm, txs};
+use serde::{Deserialize, Serialize};
+
+/// Transfers the assets within the P-chain, without crossing the chains.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#BaseTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.BaseTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/platformvm/txs.BaseTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        self.base_tx.verify_syntax()
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(Tx, interface { base_tx });
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::base::test_base_tx_serialization_with_one_signer --exact --show-output
+#[test]
+fn test_base_tx_serialization_with_one_signer() {
+    use crate::ids::short;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let asset_id = ids::Id::from_slice(&[0x88; 32]);
+    let mut tx = Tx::new(txs::Tx {
+        network_id: 10,
+        transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+                900,
+                key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x44; 20])]),
+            )),
+            ..txs::transferable::Output::default()
+        }]),
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[0x78; 32]),
+                ..txs::utxo::Id::default()
+            },
+            asset_id,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input::new(1000, vec![0])),
+            ..txs::transferable::Input::default()
+        }]),
+        ..txs::Tx::default()
+    });
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    tx.verify_syntax().expect("failed verify_syntax");
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    // same layout as "avax.BaseTx" with the P-chain type ID
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let packer = tx.base_tx.pack(codec::VERSION, 0x22).unwrap();
+    assert_eq!(
+        tx_metadata.tx_bytes_with_no_signature,
+        packer.take_bytes().to_vec()
+    );
+
+    let parsed_tx = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+}
@@ -1,9 +1,21 @@
-use crate::{codec, errors::Result, hash, ids, key, txs};
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, platformvm, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#CreateChainTx>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+/// Maximum length of the chain name.
+/// ref. "avalanchego/vms/platformvm/txs.MaxNameLen"
+pub const MAX_NAME_LEN: usize = 128;
+
+/// Maximum size of the genesis data in bytes.
+/// ref. "avalanchego/vms/platformvm/txs.MaxGenesisLen"
+pub const MAX_GENESIS_LEN: usize = 1024 * 1024;
+
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Tx {
     /// The transaction ID is empty for unsigned tx
@@ -69,119 +81,79 @@ impl Tx {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
 
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash
-    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#Tx.Sign
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let base = packer.take_bytes();
-        packer.set_bytes(&base);
-
-        // pack the second field "subnet_id" in the struct
-        packer.pack_bytes(self.subnet_id.as_ref())?;
-
-        // pack the third field "chain_name" in the struct
-        packer.pack_str(&self.chain_name)?;
-
-        // pack the fourth field "vm_id" in the struct
-        packer.pack_bytes(self.vm_id.as_ref())?;
-
-        // pack the fifth field "fx_ids" in the struct
-        if self.fx_ids.is_some() {
-            let fx_ids = self.fx_ids.as_ref().unwrap();
-            packer.pack_u32(fx_ids.len() as u32)?;
-            for fx_id in fx_ids.iter() {
-                packer.pack_bytes(fx_id.as_ref())?;
-            }
+    /// ref. "avalanchego/vms/platformvm/txs.CreateChainTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        let err = if self.subnet_id.is_empty() {
+            Some("new blockchain can't be validated by primary network") // ref. "ErrCantValidatePrimaryNetwork"
+        } else if self.chain_name.len() > MAX_NAME_LEN {
+            Some("name too long") // ref. "errNameTooLong"
+        } else if self.vm_id.is_empty() {
+            Some("invalid VM ID") // ref. "errInvalidVMID"
+        } else if !cmp_manager::is_sorted_and_unique(self.fx_ids.as_deref().unwrap_or_default()) {
+            Some("feature extensions IDs must be sorted and unique") // ref. "errFxIDsNotSortedAndUnique"
+        } else if self.genesis_data.len() > MAX_GENESIS_LEN {
+            Some("genesis too long") // ref. "errGenesisTooLong"
+        } else if !self
+            .chain_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ')
+        {
+            Some("illegal name character") // ref. "errIllegalNameCharacter"
         } else {
-            packer.pack_u32(0_u32)?;
-        }
-
-        // pack the sixth field "genesis_data" in the struct
-        // []byte is reflected as "reflect.Slice" in avalanchego
-        // thus encode its length
-        packer.pack_u32(self.genesis_data.len() as u32)?;
-        packer.pack_bytes(&self.genesis_data)?;
-
-        // pack the seventh field "subnet_auth" in the struct
-        let subnet_auth_type_id = key::secp256k1::txs::Input::type_id();
-        packer.pack_u32(subnet_auth_type_id)?;
-        packer.pack_u32(self.subnet_auth.sig_indices.len() as u32)?;
-        for sig_idx in self.subnet_auth.sig_indices.iter() {
-            packer.pack_u32(*sig_idx)?;
+            None
+        };
+        if let Some(message) = err {
+            return Err(Error::Other {
+                message: message.to_string(),
+                retryable: false,
+            });
         }
 
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.AddValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.AddValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddValidatorTx
-        let tx_bytes_hash = hash::sha256(&tx_bytes_with_no_signature);
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await?;
-                sigs.push(Vec::from(sig));
-            }
-
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
-
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
+        self.base_tx.verify_syntax()?;
+        self.subnet_auth.verify_syntax()
+    }
 
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id = hash::sha256(&tx_bytes_with_signatures);
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
 
-        Ok(())
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
     }
 }
 
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        subnet_id,
+        chain_name,
+        vm_id,
+        fx_ids,
+        genesis_data,
+        #[interface]
+        subnet_auth,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::create_chain::test_create_chain_tx_serialization_with_one_signer --exact --show-output
 #[test]
 fn test_create_chain_tx_serialization_with_one_signer() {
@@ -381,6 +353,7 @@ fn test_create_chain_tx_serialization_with_one_signer() {
     let keys1: Vec<key::secp256k1::private_key::Key> = vec![test_key.clone()];
     let keys2: Vec<key::secp256k1::private_key::Key> = vec![test_key];
     let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![keys1, keys2];
+    tx.verify_syntax().expect("failed verify_syntax");
     ab!(tx.sign(signers)).expect("failed to sign");
     let tx_metadata = tx.base_tx.metadata.clone().unwrap();
     let tx_bytes_with_signatures = tx_metadata.tx_bytes_with_signatures;
@@ -649,4 +622,12 @@ fn test_create_chain_tx_serialization_with_one_signer() {
         expected_signed_bytes,
         &tx_bytes_with_signatures
     ));
+
+    let mut invalid_tx = tx.clone();
+    invalid_tx.chain_name = "subnet-evm".to_string();
+    assert!(invalid_tx.verify_syntax().is_err());
+
+    let parsed_tx = Tx::unpack(&tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
 }
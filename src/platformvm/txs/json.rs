@@ -0,0 +1,790 @@
+//! P-chain transactions in the avalanchego JSON encoding
+//! (i.e., "platform.getTx" with "encoding=json").
+use crate::{
+    codec,
+    errors::Result,
+    ids::{self, node},
+    key, packer, platformvm,
+    txs::{
+        self,
+        json::{
+            decode_hex_nc, encode_hex_nc, tx_id, BaseTxJson, Context, CredentialJson, InputJson,
+            Json, OutputJson, OutputOwnersJson, SigIndicesJson, SignedTxJson,
+        },
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// ref. "avalanchego/vms/platformvm/txs.Validator"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidatorJson {
+    #[serde(rename = "nodeID")]
+    pub node_id: node::Id,
+    pub start: u64,
+    pub end: u64,
+    pub weight: u64,
+}
+
+impl ValidatorJson {
+    pub fn new(v: &platformvm::txs::Validator) -> Self {
+        Self {
+            node_id: v.node_id,
+            start: v.start,
+            end: v.end,
+            weight: v.weight,
+        }
+    }
+
+    pub fn parse(&self) -> platformvm::txs::Validator {
+        platformvm::txs::Validator {
+            node_id: self.node_id,
+            start: self.start,
+            end: self.end,
+            weight: self.weight,
+        }
+    }
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.SubnetValidator"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubnetValidatorJson {
+    #[serde(flatten)]
+    pub validator: ValidatorJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+}
+
+/// "signer.ProofOfPossession" in JSON.
+/// ref. "avalanchego/vms/platformvm/signer.ProofOfPossession.MarshalJSON"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProofOfPossessionJson {
+    #[serde(rename = "publicKey")]
+    pub public_key: String,
+    #[serde(rename = "proofOfPossession")]
+    pub proof_of_possession: String,
+}
+
+/// "signer.Signer" interface in JSON, where "signer.Empty" is encoded as "{}".
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SignerJson {
+    ProofOfPossession(ProofOfPossessionJson),
+    Empty {},
+}
+
+impl SignerJson {
+    pub fn new(signer: &Option<key::bls::ProofOfPossession>) -> Self {
+        match signer {
+            Some(pop) => SignerJson::ProofOfPossession(ProofOfPossessionJson {
+                public_key: encode_hex_nc(&pop.public_key),
+                proof_of_possession: encode_hex_nc(&pop.proof_of_possession),
+            }),
+            None => SignerJson::Empty {},
+        }
+    }
+
+    pub fn parse(&self) -> Result<Option<key::bls::ProofOfPossession>> {
+        match self {
+            SignerJson::ProofOfPossession(pop) => Ok(Some(key::bls::ProofOfPossession {
+                public_key: decode_hex_nc(&pop.public_key)?,
+                proof_of_possession: decode_hex_nc(&pop.proof_of_possession)?,
+                ..key::bls::ProofOfPossession::default()
+            })),
+            SignerJson::Empty {} => Ok(None),
+        }
+    }
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.AddValidatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddValidatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub validator: ValidatorJson,
+    pub stake: Vec<OutputJson>,
+    #[serde(rename = "rewardsOwner")]
+    pub rewards_owner: OutputOwnersJson,
+    pub shares: u32,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.AddSubnetValidatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddSubnetValidatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub validator: SubnetValidatorJson,
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: SigIndicesJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.AddDelegatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddDelegatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub validator: ValidatorJson,
+    pub stake: Vec<OutputJson>,
+    #[serde(rename = "rewardsOwner")]
+    pub rewards_owner: OutputOwnersJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.AddPermissionlessValidatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddPermissionlessValidatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub validator: ValidatorJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    pub signer: SignerJson,
+    pub stake: Vec<OutputJson>,
+    #[serde(rename = "validationRewardsOwner")]
+    pub validator_rewards_owner: OutputOwnersJson,
+    #[serde(rename = "delegationRewardsOwner")]
+    pub delegator_rewards_owner: OutputOwnersJson,
+    pub shares: u32,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.AddPermissionlessDelegatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddPermissionlessDelegatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub validator: ValidatorJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    pub stake: Vec<OutputJson>,
+    #[serde(rename = "rewardsOwner")]
+    pub rewards_owner: OutputOwnersJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.CreateChainTx"
+/// Go encodes "[]byte" in base64, thus requires the "codec_base64" feature.
+#[cfg(feature = "codec_base64")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateChainTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    #[serde(rename = "chainName")]
+    pub chain_name: String,
+    #[serde(rename = "vmID")]
+    pub vm_id: ids::Id,
+    #[serde(rename = "fxIDs")]
+    pub fx_ids: Vec<ids::Id>,
+    #[serde(rename = "genesisData", with = "crate::codec::serde::base64_bytes")]
+    pub genesis_data: Vec<u8>,
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: SigIndicesJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.CreateSubnetTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateSubnetTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    pub owner: OutputOwnersJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.RemoveSubnetValidatorTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveSubnetValidatorTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "nodeID")]
+    pub node_id: node::Id,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: SigIndicesJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.TransformSubnetTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransformSubnetTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    #[serde(rename = "initialSupply")]
+    pub initial_supply: u64,
+    #[serde(rename = "maximumSupply")]
+    pub maximum_supply: u64,
+    #[serde(rename = "minConsumptionRate")]
+    pub min_consumption_rate: u64,
+    #[serde(rename = "maxConsumptionRate")]
+    pub max_consumption_rate: u64,
+    #[serde(rename = "minValidatorStake")]
+    pub min_validator_stake: u64,
+    #[serde(rename = "maxValidatorStake")]
+    pub max_validator_stake: u64,
+    #[serde(rename = "minStakeDuration")]
+    pub min_stake_duration: u32,
+    #[serde(rename = "maxStakeDuration")]
+    pub max_stake_duration: u32,
+    #[serde(rename = "minDelegationFee")]
+    pub min_delegation_fee: u32,
+    #[serde(rename = "minDelegatorStake")]
+    pub min_delegator_stake: u64,
+    #[serde(rename = "maxValidatorWeightFactor")]
+    pub max_validator_weight_factor: u8,
+    #[serde(rename = "uptimeRequirement")]
+    pub uptime_requirement: u32,
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: SigIndicesJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.TransferSubnetOwnershipTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransferSubnetOwnershipTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "subnetID")]
+    pub subnet_id: ids::Id,
+    #[serde(rename = "subnetAuthorization")]
+    pub subnet_auth: SigIndicesJson,
+    #[serde(rename = "newOwner")]
+    pub owner: OutputOwnersJson,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.ImportTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "sourceChain")]
+    pub source_chain: ids::Id,
+    #[serde(rename = "importedInputs")]
+    pub imported_inputs: Vec<InputJson>,
+}
+
+/// ref. "avalanchego/vms/platformvm/txs.ExportTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportTxJson {
+    #[serde(flatten)]
+    pub base_tx: BaseTxJson,
+    #[serde(rename = "destinationChain")]
+    pub destination_chain: ids::Id,
+    #[serde(rename = "exportedOutputs")]
+    pub exported_outputs: Vec<OutputJson>,
+}
+
+/// Encodes the signed P-chain tx, where each credential is "secp256k1fx.Credential".
+fn encode_signed<U: Serialize>(
+    unsigned_tx: U,
+    base_tx: &txs::Tx,
+    creds: &[key::secp256k1::txs::Credential],
+) -> Result<String> {
+    SignedTxJson {
+        unsigned_tx,
+        credentials: creds.iter().map(CredentialJson::new).collect(),
+        id: tx_id(base_tx),
+    }
+    .encode()
+}
+
+fn parse_credentials(creds: &[CredentialJson]) -> Result<Vec<key::secp256k1::txs::Credential>> {
+    creds.iter().map(CredentialJson::parse).collect()
+}
+
+/// Recomputes the metadata of the parsed tx.
+/// ref. "avalanchego/vms/platformvm/txs.Tx.Initialize"
+fn initialize<T: codec::linear::Pack>(
+    type_id: u32,
+    unsigned_tx: &T,
+    creds: &[key::secp256k1::txs::Credential],
+) -> Result<txs::Metadata> {
+    txs::Metadata::compute(type_id, unsigned_tx, |packer: &packer::Packer| {
+        platformvm::txs::pack_credentials(packer, creds)
+    })
+}
+
+impl Json for platformvm::txs::add_validator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = AddValidatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            validator: ValidatorJson::new(&self.validator),
+            stake: OutputJson::new_list(ctx, &self.stake_transferable_outputs)?,
+            rewards_owner: OutputOwnersJson::new(ctx, &self.rewards_owner)?,
+            shares: self.shares,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<AddValidatorTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            validator: unsigned_tx.validator.parse(),
+            stake_transferable_outputs: OutputJson::parse_list(&unsigned_tx.stake)?,
+            rewards_owner: unsigned_tx.rewards_owner.parse()?,
+            shares: unsigned_tx.shares,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::add_subnet_validator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = AddSubnetValidatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            validator: SubnetValidatorJson {
+                validator: ValidatorJson::new(&self.validator.validator),
+                subnet_id: self.validator.subnet_id,
+            },
+            subnet_auth: SigIndicesJson {
+                sig_indices: self.subnet_auth.sig_indices.clone(),
+            },
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<AddSubnetValidatorTxJson, CredentialJson> =
+            SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            validator: platformvm::txs::add_subnet_validator::Validator {
+                validator: unsigned_tx.validator.validator.parse(),
+                subnet_id: unsigned_tx.validator.subnet_id,
+            },
+            subnet_auth: key::secp256k1::txs::Input::new(unsigned_tx.subnet_auth.sig_indices),
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::add_permissionless_validator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = AddPermissionlessValidatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            validator: ValidatorJson::new(&self.validator),
+            subnet_id: self.subnet_id,
+            signer: SignerJson::new(&self.signer),
+            stake: OutputJson::new_list(ctx, &self.stake_transferable_outputs)?,
+            validator_rewards_owner: OutputOwnersJson::new(ctx, &self.validator_rewards_owner)?,
+            delegator_rewards_owner: OutputOwnersJson::new(ctx, &self.delegator_rewards_owner)?,
+            shares: self.delegation_shares,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<AddPermissionlessValidatorTxJson, CredentialJson> =
+            SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            validator: unsigned_tx.validator.parse(),
+            subnet_id: unsigned_tx.subnet_id,
+            signer: unsigned_tx.signer.parse()?,
+            stake_transferable_outputs: OutputJson::parse_list(&unsigned_tx.stake)?,
+            validator_rewards_owner: unsigned_tx.validator_rewards_owner.parse()?,
+            delegator_rewards_owner: unsigned_tx.delegator_rewards_owner.parse()?,
+            delegation_shares: unsigned_tx.shares,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::add_delegator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = AddDelegatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            validator: ValidatorJson::new(&self.validator),
+            stake: OutputJson::new_list(ctx, &self.stake_transferable_outputs)?,
+            rewards_owner: OutputOwnersJson::new(ctx, &self.rewards_owner)?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<AddDelegatorTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            validator: unsigned_tx.validator.parse(),
+            stake_transferable_outputs: OutputJson::parse_list(&unsigned_tx.stake)?,
+            rewards_owner: unsigned_tx.rewards_owner.parse()?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::add_permissionless_delegator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = AddPermissionlessDelegatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            validator: ValidatorJson::new(&self.validator),
+            subnet_id: self.subnet_id,
+            stake: OutputJson::new_list(ctx, &self.stake_transferable_outputs)?,
+            rewards_owner: OutputOwnersJson::new(ctx, &self.rewards_owner)?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<AddPermissionlessDelegatorTxJson, CredentialJson> =
+            SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            validator: unsigned_tx.validator.parse(),
+            subnet_id: unsigned_tx.subnet_id,
+            stake_transferable_outputs: OutputJson::parse_list(&unsigned_tx.stake)?,
+            rewards_owner: unsigned_tx.rewards_owner.parse()?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+#[cfg(feature = "codec_base64")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codec_base64")))]
+impl Json for platformvm::txs::create_chain::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = CreateChainTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            subnet_id: self.subnet_id,
+            chain_name: self.chain_name.clone(),
+            vm_id: self.vm_id,
+            fx_ids: self.fx_ids.clone().unwrap_or_default(),
+            genesis_data: self.genesis_data.clone(),
+            subnet_auth: SigIndicesJson {
+                sig_indices: self.subnet_auth.sig_indices.clone(),
+            },
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<CreateChainTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            subnet_id: unsigned_tx.subnet_id,
+            chain_name: unsigned_tx.chain_name,
+            vm_id: unsigned_tx.vm_id,
+            fx_ids: if unsigned_tx.fx_ids.is_empty() {
+                None
+            } else {
+                Some(unsigned_tx.fx_ids)
+            },
+            genesis_data: unsigned_tx.genesis_data,
+            subnet_auth: key::secp256k1::txs::Input::new(unsigned_tx.subnet_auth.sig_indices),
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::create_subnet::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = CreateSubnetTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            owner: OutputOwnersJson::new(ctx, &self.owner)?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<CreateSubnetTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            owner: unsigned_tx.owner.parse()?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::remove_subnet_validator::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = RemoveSubnetValidatorTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            node_id: self.node_id,
+            subnet_id: self.subnet_id,
+            subnet_auth: SigIndicesJson {
+                sig_indices: self.subnet_auth.sig_indices.clone(),
+            },
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<RemoveSubnetValidatorTxJson, CredentialJson> =
+            SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            node_id: unsigned_tx.node_id,
+            subnet_id: unsigned_tx.subnet_id,
+            subnet_auth: key::secp256k1::txs::Input::new(unsigned_tx.subnet_auth.sig_indices),
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::transform_subnet::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = TransformSubnetTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            subnet_id: self.subnet_id,
+            asset_id: self.asset_id,
+            initial_supply: self.initial_supply,
+            maximum_supply: self.maximum_supply,
+            min_consumption_rate: self.min_consumption_rate,
+            max_consumption_rate: self.max_consumption_rate,
+            min_validator_stake: self.min_validator_stake,
+            max_validator_stake: self.max_validator_stake,
+            min_stake_duration: self.min_stake_duration,
+            max_stake_duration: self.max_stake_duration,
+            min_delegation_fee: self.min_delegation_fee,
+            min_delegator_stake: self.min_delegator_stake,
+            max_validator_weight_factor: self.max_validator_weight_factor,
+            uptime_requirement: self.uptime_requirement,
+            subnet_auth: SigIndicesJson {
+                sig_indices: self.subnet_auth.sig_indices.clone(),
+            },
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<TransformSubnetTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            subnet_id: unsigned_tx.subnet_id,
+            asset_id: unsigned_tx.asset_id,
+            initial_supply: unsigned_tx.initial_supply,
+            maximum_supply: unsigned_tx.maximum_supply,
+            min_consumption_rate: unsigned_tx.min_consumption_rate,
+            max_consumption_rate: unsigned_tx.max_consumption_rate,
+            min_validator_stake: unsigned_tx.min_validator_stake,
+            max_validator_stake: unsigned_tx.max_validator_stake,
+            min_stake_duration: unsigned_tx.min_stake_duration,
+            max_stake_duration: unsigned_tx.max_stake_duration,
+            min_delegation_fee: unsigned_tx.min_delegation_fee,
+            min_delegator_stake: unsigned_tx.min_delegator_stake,
+            max_validator_weight_factor: unsigned_tx.max_validator_weight_factor,
+            uptime_requirement: unsigned_tx.uptime_requirement,
+            subnet_auth: key::secp256k1::txs::Input::new(unsigned_tx.subnet_auth.sig_indices),
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::transfer_subnet_ownership::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = TransferSubnetOwnershipTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            subnet_id: self.subnet_id,
+            subnet_auth: SigIndicesJson {
+                sig_indices: self.subnet_auth.sig_indices.clone(),
+            },
+            owner: OutputOwnersJson::new(ctx, &self.owner)?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<TransferSubnetOwnershipTxJson, CredentialJson> =
+            SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            subnet_id: unsigned_tx.subnet_id,
+            subnet_auth: key::secp256k1::txs::Input::new(unsigned_tx.subnet_auth.sig_indices),
+            owner: unsigned_tx.owner.parse()?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::base::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        encode_signed(
+            BaseTxJson::new(ctx, &self.base_tx)?,
+            &self.base_tx,
+            &self.creds,
+        )
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<BaseTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let mut tx = Self {
+            base_tx: signed.unsigned_tx.parse()?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::import::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = ImportTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            source_chain: self.source_chain_id,
+            imported_inputs: InputJson::new_list(&self.source_chain_transferable_inputs)?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<ImportTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            source_chain_id: unsigned_tx.source_chain,
+            source_chain_transferable_inputs: InputJson::parse_list(&unsigned_tx.imported_inputs),
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+impl Json for platformvm::txs::export::Tx {
+    fn to_json(&self, ctx: &Context) -> Result<String> {
+        let unsigned_tx = ExportTxJson {
+            base_tx: BaseTxJson::new(ctx, &self.base_tx)?,
+            destination_chain: self.destination_chain_id,
+            exported_outputs: OutputJson::new_list(
+                ctx,
+                &self.destination_chain_transferable_outputs,
+            )?,
+        };
+        encode_signed(unsigned_tx, &self.base_tx, &self.creds)
+    }
+
+    fn from_json(d: &str) -> Result<Self> {
+        let signed: SignedTxJson<ExportTxJson, CredentialJson> = SignedTxJson::decode(d)?;
+        let unsigned_tx = signed.unsigned_tx;
+        let mut tx = Self {
+            base_tx: unsigned_tx.base_tx.parse()?,
+            destination_chain_id: unsigned_tx.destination_chain,
+            destination_chain_transferable_outputs: OutputJson::parse_list(
+                &unsigned_tx.exported_outputs,
+            )?,
+            creds: parse_credentials(&signed.credentials)?,
+        };
+        tx.base_tx.metadata = Some(initialize(Self::type_id(), &tx, &tx.creds)?);
+        Ok(tx)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::json::test_create_subnet_tx_json --exact --show-output
+#[test]
+fn test_create_subnet_tx_json() {
+    // ref. "platform.getTx" with "encoding=json" on a local network
+    // (same response as in "jsonrpc::platformvm::test_get_tx")
+    let d = "{\"unsignedTx\":{\"networkID\":1000000,\"blockchainID\":\"11111111111111111111111111111111LpoYY\",\
+\"outputs\":[{\"assetID\":\"u8aaQ7MxyW32iHuP2xMXgYPrWYAsSbh8RJV9C6p1UeuGvqR3\",\"fxID\":\"spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ\",\
+\"output\":{\"addresses\":[\"P-custom12szthht8tnl455u4mz3ns3nvvkel8ezvw2n8cx\"],\"amount\":245952587549460688,\"locktime\":0,\"threshold\":1}}],\
+\"inputs\":[{\"txID\":\"nN5QsURgEpM8D3e9q8FonS4EE13mnaBDtnQmgSwwUfBZ6FSW1\",\"outputIndex\":0,\"assetID\":\"u8aaQ7MxyW32iHuP2xMXgYPrWYAsSbh8RJV9C6p1UeuGvqR3\",\
+\"fxID\":\"spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ\",\"input\":{\"amount\":245952587649460688,\"signatureIndices\":[0]}}],\
+\"memo\":\"0x\",\"owner\":{\"addresses\":[\"P-custom12szthht8tnl455u4mz3ns3nvvkel8ezvw2n8cx\"],\"locktime\":0,\"threshold\":1}},\
+\"credentials\":[{\"signatures\":[\"0xcb356822dc8990672b5777ec50b57da91baf572240e7d4e9e38f26ec9dbdfd8e376fdc5f30769b842668cd8d81bd71db926dfbe326585137d363566ee500369f01\"]}],\
+\"id\":\"2u7jNdqHoS2ggg4WCmDaHUCTPwvuC1fs34fr8fRmKNztuUuXnc\"}";
+
+    let tx = platformvm::txs::create_subnet::Tx::from_json(d).unwrap();
+    assert_eq!(tx.base_tx.network_id, 1000000);
+    assert_eq!(tx.owner.threshold, 1);
+    assert_eq!(tx.creds.len(), 1);
+
+    // the ID is recomputed from the binary encoding
+    let metadata = tx.base_tx.metadata.clone().unwrap();
+    assert_eq!(
+        tx.tx_id().to_string(),
+        "2u7jNdqHoS2ggg4WCmDaHUCTPwvuC1fs34fr8fRmKNztuUuXnc"
+    );
+    let parsed =
+        platformvm::txs::create_subnet::Tx::unpack(&metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed.tx_id(), tx.tx_id());
+
+    let ctx = Context::new("P", 1000000);
+    assert_eq!(tx.to_json(&ctx).unwrap(), d);
+    assert_eq!(parsed.to_json(&ctx).unwrap(), d);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::json::test_add_permissionless_validator_tx_json --exact --show-output
+#[test]
+fn test_add_permissionless_validator_tx_json() {
+    use crate::ids::short;
+
+    let owner = key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[1; 20])]);
+    let mut tx = platformvm::txs::add_permissionless_validator::Tx {
+        base_tx: txs::Tx {
+            network_id: 5,
+            ..txs::Tx::default()
+        },
+        validator: platformvm::txs::Validator {
+            node_id: node::Id::from_slice(&[2; 20]),
+            start: 1,
+            end: 2,
+            weight: 3,
+        },
+        stake_transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id: ids::Id::from_slice(&[3; 32]),
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(3, owner.clone())),
+            ..txs::transferable::Output::default()
+        }]),
+        validator_rewards_owner: owner.clone(),
+        delegator_rewards_owner: owner,
+        delegation_shares: 20000,
+        ..platformvm::txs::add_permissionless_validator::Tx::default()
+    };
+
+    let ctx = Context::new("P", 5);
+    let encoded = tx.to_json(&ctx).unwrap();
+    assert!(encoded.contains(
+        "\"subnetID\":\"11111111111111111111111111111111LpoYY\",\"signer\":{},\"stake\":"
+    ));
+    assert!(encoded.contains("\"addresses\":[\"P-fuji1"));
+    let parsed = platformvm::txs::add_permissionless_validator::Tx::from_json(&encoded).unwrap();
+    assert!(parsed.signer.is_none());
+    assert_eq!(parsed.validator, tx.validator);
+
+    tx.signer = Some(key::bls::ProofOfPossession {
+        public_key: vec![4; key::bls::public_key::LEN],
+        proof_of_possession: vec![5; key::bls::signature::LEN],
+        ..key::bls::ProofOfPossession::default()
+    });
+    let encoded = tx.to_json(&ctx).unwrap();
+    assert!(encoded.contains(&format!(
+        "\"signer\":{{\"publicKey\":\"0x{}\",\"proofOfPossession\":\"0x{}\"}}",
+        hex::encode([4; key::bls::public_key::LEN]),
+        hex::encode([5; key::bls::signature::LEN])
+    )));
+    let parsed = platformvm::txs::add_permissionless_validator::Tx::from_json(&encoded).unwrap();
+    assert_eq!(
+        parsed.signer.unwrap().public_key,
+        tx.signer.unwrap().public_key
+    );
+}
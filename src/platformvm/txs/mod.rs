@@ -1,19 +1,34 @@
+pub mod add_delegator;
+pub mod add_permissionless_delegator;
 pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
+pub mod advance_time;
+pub mod base;
 pub mod create_chain;
 pub mod create_subnet;
 pub mod export;
 pub mod import;
+pub mod json;
+pub mod remove_subnet_validator;
+pub mod reward_validator;
 pub mod status;
+pub mod transfer_subnet_ownership;
+pub mod transform_subnet;
 
 use std::cmp::Ordering;
 
 use crate::{
-    codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    codec::{
+        self,
+        linear::{Pack, Unpack},
+        serde::hex_0x_bytes::Hex0xBytes,
+    },
+    errors::{Error, Result},
+    formatting,
     ids::{self, node},
-    key,
-    txs::transferable,
+    impl_linear_codec, key, packer,
+    txs::{self, transferable},
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
@@ -193,8 +208,30 @@ impl StakeableLockIn {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// ref. "avalanchego/vms/platformvm/stakeable.LockIn.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.locktime == 0 {
+            return Err(Error::Other {
+                message: "invalid locktime".to_string(), // ref. "errInvalidLocktime"
+                retryable: false,
+            });
+        }
+        self.transfer_input.verify_syntax()
+    }
 }
 
+// "StakeableLockIn.TransferableIn" is an interface
+// thus prefixed with the type ID of "secp256k1fx.TransferInput"
+impl_linear_codec!(
+    StakeableLockIn,
+    interface {
+        locktime,
+        #[interface]
+        transfer_input,
+    }
+);
+
 impl Ord for StakeableLockIn {
     fn cmp(&self, other: &StakeableLockIn) -> Ordering {
         self.locktime
@@ -317,8 +354,30 @@ impl StakeableLockOut {
     pub fn type_id() -> u32 {
         *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
     }
+
+    /// ref. "avalanchego/vms/platformvm/stakeable.LockOut.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.locktime == 0 {
+            return Err(Error::Other {
+                message: "invalid locktime".to_string(), // ref. "errInvalidLocktime"
+                retryable: false,
+            });
+        }
+        self.transfer_output.verify_syntax()
+    }
 }
 
+// "StakeableLockOut.TransferableOut" is an interface
+// thus prefixed with the type ID of "secp256k1fx.TransferOutput"
+impl_linear_codec!(
+    StakeableLockOut,
+    interface {
+        locktime,
+        #[interface]
+        transfer_output,
+    }
+);
+
 impl Ord for StakeableLockOut {
     fn cmp(&self, other: &StakeableLockOut) -> Ordering {
         self.locktime
@@ -540,4 +599,527 @@ impl Validator {
             weight: 0,
         }
     }
+
+    /// ref. "avalanchego/vms/platformvm/txs.Validator.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.weight == 0 {
+            return Err(Error::Other {
+                message: "weight of this validator is too low".to_string(), // ref. "ErrWeightTooSmall"
+                retryable: false,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Verifies the stake outputs of a staker tx are valid, sorted and
+/// of a single asset, and returns the total staked amount to be
+/// matched against the validator weight.
+/// ref. "avalanchego/vms/platformvm/txs.AddPermissionlessValidatorTx.SyntacticVerify"
+pub fn verify_stake_outputs(outputs: &[transferable::Output]) -> Result<u64> {
+    if outputs.is_empty() {
+        return Err(Error::Other {
+            message: "no stake".to_string(), // ref. "errNoStake"
+            retryable: false,
+        });
+    }
+
+    let mut total_stake: u64 = 0;
+    for output in outputs.iter() {
+        output.verify_syntax()?;
+        if output.asset_id != outputs[0].asset_id {
+            return Err(Error::Other {
+                message: "multiple staked assets".to_string(), // ref. "errMultipleStakedAssets"
+                retryable: false,
+            });
+        }
+
+        let amount = match (&output.transfer_output, &output.stakeable_lock_out) {
+            (Some(out), _) => out.amount,
+            (None, Some(lock_out)) => lock_out.transfer_output.amount,
+            (None, None) => 0,
+        };
+        total_stake = total_stake
+            .checked_add(amount)
+            .ok_or_else(|| Error::Other {
+                message: "stake amount overflow".to_string(), // ref. "math.ErrOverflow"
+                retryable: false,
+            })?;
+    }
+
+    if !outputs.windows(2).all(|w| w[0] <= w[1]) {
+        return Err(Error::Other {
+            message: "outputs not sorted".to_string(), // ref. "errOutputsNotSorted"
+            retryable: false,
+        });
+    }
+    Ok(total_stake)
+}
+
+// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Validator>
+impl_linear_codec!(Validator {
+    node_id,
+    start,
+    end,
+    weight,
+});
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::test_verify_stake_outputs --exact --show-output
+#[test]
+fn test_verify_stake_outputs() {
+    use crate::ids::short;
+
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[1; 20])]);
+    let new_output = |asset: u8, amount: u64| transferable::Output {
+        asset_id: ids::Id::from_slice(&[asset; 32]),
+        transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+            amount,
+            owners.clone(),
+        )),
+        ..transferable::Output::default()
+    };
+    let locked = transferable::Output {
+        asset_id: ids::Id::from_slice(&[1; 32]),
+        stakeable_lock_out: Some(StakeableLockOut {
+            locktime: 1,
+            transfer_output: key::secp256k1::txs::transfer::Output::new(300, owners.clone()),
+        }),
+        ..transferable::Output::default()
+    };
+
+    let mut outputs = vec![new_output(1, 100), new_output(1, 200), locked];
+    outputs.sort();
+    assert_eq!(verify_stake_outputs(&outputs).unwrap(), 600);
+
+    assert!(verify_stake_outputs(&[]).is_err());
+    assert!(verify_stake_outputs(&[new_output(1, 0)]).is_err());
+    assert!(verify_stake_outputs(&[new_output(1, 100), new_output(2, 100)]).is_err());
+    assert!(verify_stake_outputs(&[new_output(1, 200), new_output(1, 100)]).is_err());
+    assert!(verify_stake_outputs(&[new_output(1, u64::MAX), new_output(1, u64::MAX)]).is_err());
+
+    assert!(Validator::default().verify_syntax().is_err());
+}
+
+/// Unpacks the "Tx.Creds" field, each of which is "secp256k1fx.Credential"
+/// encoded as the "verify.Verifiable" interface, thus prefixed with the type ID.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+pub fn unpack_credentials(packer: &packer::Packer) -> Result<Vec<key::secp256k1::txs::Credential>> {
+    let creds_len = packer.unpack_u32()?;
+    let mut creds: Vec<key::secp256k1::txs::Credential> = Vec::new();
+    for _ in 0..creds_len {
+        creds.push(codec::linear::unpack_interface(packer)?);
+    }
+    Ok(creds)
+}
+
+/// Decodes the signed tx of the type through "codec::P_CODEC", and returns
+/// the unsigned tx with its credentials and the metadata of the given bytes.
+/// Fails if the codec version is unknown, the type ID is not the one of
+/// the type, or bytes are left after decoding.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+pub fn unpack_signed_tx<U: Unpack + codec::linear::Interface>(
+    d: &[u8],
+) -> Result<(U, Vec<key::secp256k1::txs::Credential>, txs::Metadata)> {
+    let (_, (unsigned_tx, creds, unsigned_tx_len)) =
+        codec::P_CODEC.unmarshal_with(d, |registry, packer| {
+            registry.verify_type_id(packer.unpack_u32()?, &U::interface_type_name())?;
+            let unsigned_tx = U::unpack_from(packer)?;
+
+            // all bytes before credentials are the unsigned tx bytes
+            let unsigned_tx_len = packer.get_offset();
+            let creds = unpack_credentials(packer)?;
+            Ok((unsigned_tx, creds, unsigned_tx_len))
+        })?;
+
+    let metadata = txs::Metadata::new(&d[..unsigned_tx_len], d);
+    Ok((unsigned_tx, creds, metadata))
+}
+
+/// Decodes the hex-encoded tx bytes with the checksum
+/// as returned by the "platform.getTx" API with "hex" encoding.
+pub fn decode_hex(d: &str) -> Result<Vec<u8>> {
+    // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+    let d = d.trim_start_matches("0x");
+    formatting::decode_hex_with_checksum(d.as_bytes()).map_err(|e| Error::Other {
+        message: format!("failed formatting::decode_hex_with_checksum '{}'", e),
+        retryable: false,
+    })
+}
+
+/// Packs the "Tx.Creds" field, each of which is "secp256k1fx.Credential"
+/// encoded as the "verify.Verifiable" interface, thus prefixed with the type ID.
+pub fn pack_credentials(
+    packer: &packer::Packer,
+    creds: &[key::secp256k1::txs::Credential],
+) -> Result<()> {
+    packer.pack_u32(creds.len() as u32)?;
+    for cred in creds.iter() {
+        codec::linear::pack_interface(cred, packer)?;
+    }
+    Ok(())
+}
+
+/// Signs the unsigned tx with the signers, and returns the "Tx.Creds"
+/// with the metadata computed from the signed tx bytes.
+/// ref. "avalanchego/vms/platformvm/txs.Tx.Sign"
+pub async fn sign_tx<U: Pack, T: key::secp256k1::SignOnly>(
+    type_id: u32,
+    unsigned_tx: &U,
+    signers: Vec<Vec<T>>,
+) -> Result<(Vec<key::secp256k1::txs::Credential>, txs::Metadata)> {
+    let creds = txs::sign_credentials(type_id, unsigned_tx, &signers).await?;
+    let metadata = txs::Metadata::compute(type_id, unsigned_tx, |packer| {
+        pack_credentials(packer, &creds)
+    })?;
+    Ok((creds, metadata))
+}
+
+/// Represents any of the signed P-chain transactions,
+/// decoded based on the unsigned tx type ID in "codec::P_TYPES".
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub enum Transaction {
+    AddValidator(add_validator::Tx),
+    AddSubnetValidator(add_subnet_validator::Tx),
+    AddDelegator(add_delegator::Tx),
+    AddPermissionlessDelegator(add_permissionless_delegator::Tx),
+    AddPermissionlessValidator(add_permissionless_validator::Tx),
+    CreateChain(create_chain::Tx),
+    CreateSubnet(create_subnet::Tx),
+    RemoveSubnetValidator(remove_subnet_validator::Tx),
+    TransformSubnet(transform_subnet::Tx),
+    TransferSubnetOwnership(transfer_subnet_ownership::Tx),
+    Base(base::Tx),
+    AdvanceTime(advance_time::Tx),
+    RewardValidator(reward_validator::Tx),
+    Import(import::Tx),
+    Export(export::Tx),
+}
+
+impl Transaction {
+    /// Parses the signed tx bytes by peeking the unsigned tx type,
+    /// resolved with the registry of the codec version in "codec::P_CODEC".
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let type_name = codec::P_CODEC.type_name_of(d)?;
+        if type_name == add_validator::Tx::type_name() {
+            Ok(Transaction::AddValidator(add_validator::Tx::unpack(d)?))
+        } else if type_name == add_subnet_validator::Tx::type_name() {
+            Ok(Transaction::AddSubnetValidator(
+                add_subnet_validator::Tx::unpack(d)?,
+            ))
+        } else if type_name == add_delegator::Tx::type_name() {
+            Ok(Transaction::AddDelegator(add_delegator::Tx::unpack(d)?))
+        } else if type_name == add_permissionless_delegator::Tx::type_name() {
+            Ok(Transaction::AddPermissionlessDelegator(
+                add_permissionless_delegator::Tx::unpack(d)?,
+            ))
+        } else if type_name == add_permissionless_validator::Tx::type_name() {
+            Ok(Transaction::AddPermissionlessValidator(
+                add_permissionless_validator::Tx::unpack(d)?,
+            ))
+        } else if type_name == create_chain::Tx::type_name() {
+            Ok(Transaction::CreateChain(create_chain::Tx::unpack(d)?))
+        } else if type_name == create_subnet::Tx::type_name() {
+            Ok(Transaction::CreateSubnet(create_subnet::Tx::unpack(d)?))
+        } else if type_name == remove_subnet_validator::Tx::type_name() {
+            Ok(Transaction::RemoveSubnetValidator(
+                remove_subnet_validator::Tx::unpack(d)?,
+            ))
+        } else if type_name == transform_subnet::Tx::type_name() {
+            Ok(Transaction::TransformSubnet(transform_subnet::Tx::unpack(
+                d,
+            )?))
+        } else if type_name == transfer_subnet_ownership::Tx::type_name() {
+            Ok(Transaction::TransferSubnetOwnership(
+                transfer_subnet_ownership::Tx::unpack(d)?,
+            ))
+        } else if type_name == base::Tx::type_name() {
+            Ok(Transaction::Base(base::Tx::unpack(d)?))
+        } else if type_name == advance_time::Tx::type_name() {
+            Ok(Transaction::AdvanceTime(advance_time::Tx::unpack(d)?))
+        } else if type_name == reward_validator::Tx::type_name() {
+            Ok(Transaction::RewardValidator(reward_validator::Tx::unpack(
+                d,
+            )?))
+        } else if type_name == import::Tx::type_name() {
+            Ok(Transaction::Import(import::Tx::unpack(d)?))
+        } else if type_name == export::Tx::type_name() {
+            Ok(Transaction::Export(export::Tx::unpack(d)?))
+        } else {
+            Err(Error::Other {
+                message: format!("unknown or unsupported P-chain tx type {}", type_name),
+                retryable: false,
+            })
+        }
+    }
+
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Transaction::AddValidator(_) => add_validator::Tx::type_id(),
+            Transaction::AddSubnetValidator(_) => add_subnet_validator::Tx::type_id(),
+            Transaction::AddDelegator(_) => add_delegator::Tx::type_id(),
+            Transaction::AddPermissionlessDelegator(_) => {
+                add_permissionless_delegator::Tx::type_id()
+            }
+            Transaction::AddPermissionlessValidator(_) => {
+                add_permissionless_validator::Tx::type_id()
+            }
+            Transaction::CreateChain(_) => create_chain::Tx::type_id(),
+            Transaction::CreateSubnet(_) => create_subnet::Tx::type_id(),
+            Transaction::RemoveSubnetValidator(_) => remove_subnet_validator::Tx::type_id(),
+            Transaction::TransformSubnet(_) => transform_subnet::Tx::type_id(),
+            Transaction::TransferSubnetOwnership(_) => transfer_subnet_ownership::Tx::type_id(),
+            Transaction::Base(_) => base::Tx::type_id(),
+            Transaction::AdvanceTime(_) => advance_time::Tx::type_id(),
+            Transaction::RewardValidator(_) => reward_validator::Tx::type_id(),
+            Transaction::Import(_) => import::Tx::type_id(),
+            Transaction::Export(_) => export::Tx::type_id(),
+        }
+    }
+
+    pub fn type_name(&self) -> String {
+        match self {
+            Transaction::AddValidator(_) => add_validator::Tx::type_name(),
+            Transaction::AddSubnetValidator(_) => add_subnet_validator::Tx::type_name(),
+            Transaction::AddDelegator(_) => add_delegator::Tx::type_name(),
+            Transaction::AddPermissionlessDelegator(_) => {
+                add_permissionless_delegator::Tx::type_name()
+            }
+            Transaction::AddPermissionlessValidator(_) => {
+                add_permissionless_validator::Tx::type_name()
+            }
+            Transaction::CreateChain(_) => create_chain::Tx::type_name(),
+            Transaction::CreateSubnet(_) => create_subnet::Tx::type_name(),
+            Transaction::RemoveSubnetValidator(_) => remove_subnet_validator::Tx::type_name(),
+            Transaction::TransformSubnet(_) => transform_subnet::Tx::type_name(),
+            Transaction::TransferSubnetOwnership(_) => transfer_subnet_ownership::Tx::type_name(),
+            Transaction::Base(_) => base::Tx::type_name(),
+            Transaction::AdvanceTime(_) => advance_time::Tx::type_name(),
+            Transaction::RewardValidator(_) => reward_validator::Tx::type_name(),
+            Transaction::Import(_) => import::Tx::type_name(),
+            Transaction::Export(_) => export::Tx::type_name(),
+        }
+    }
+
+    pub fn tx_id(&self) -> ids::Id {
+        match self {
+            Transaction::AddValidator(tx) => tx.tx_id(),
+            Transaction::AddSubnetValidator(tx) => tx.tx_id(),
+            Transaction::AddDelegator(tx) => tx.tx_id(),
+            Transaction::AddPermissionlessDelegator(tx) => tx.tx_id(),
+            Transaction::AddPermissionlessValidator(tx) => tx.tx_id(),
+            Transaction::CreateChain(tx) => tx.tx_id(),
+            Transaction::CreateSubnet(tx) => tx.tx_id(),
+            Transaction::RemoveSubnetValidator(tx) => tx.tx_id(),
+            Transaction::TransformSubnet(tx) => tx.tx_id(),
+            Transaction::TransferSubnetOwnership(tx) => tx.tx_id(),
+            Transaction::Base(tx) => tx.tx_id(),
+            Transaction::AdvanceTime(tx) => tx.tx_id(),
+            Transaction::RewardValidator(tx) => tx.tx_id(),
+            Transaction::Import(tx) => tx.tx_id(),
+            Transaction::Export(tx) => tx.tx_id(),
+        }
+    }
+
+    /// Returns "None" for the txs without "avax.BaseTx"
+    /// (i.e., "AdvanceTimeTx" and "RewardValidatorTx").
+    pub fn base_tx(&self) -> Option<&txs::Tx> {
+        match self {
+            Transaction::AddValidator(tx) => Some(&tx.base_tx),
+            Transaction::AddSubnetValidator(tx) => Some(&tx.base_tx),
+            Transaction::AddDelegator(tx) => Some(&tx.base_tx),
+            Transaction::AddPermissionlessDelegator(tx) => Some(&tx.base_tx),
+            Transaction::AddPermissionlessValidator(tx) => Some(&tx.base_tx),
+            Transaction::CreateChain(tx) => Some(&tx.base_tx),
+            Transaction::CreateSubnet(tx) => Some(&tx.base_tx),
+            Transaction::RemoveSubnetValidator(tx) => Some(&tx.base_tx),
+            Transaction::TransformSubnet(tx) => Some(&tx.base_tx),
+            Transaction::TransferSubnetOwnership(tx) => Some(&tx.base_tx),
+            Transaction::Base(tx) => Some(&tx.base_tx),
+            Transaction::AdvanceTime(_) | Transaction::RewardValidator(_) => None,
+            Transaction::Import(tx) => Some(&tx.base_tx),
+            Transaction::Export(tx) => Some(&tx.base_tx),
+        }
+    }
+
+    pub fn creds(&self) -> &[key::secp256k1::txs::Credential] {
+        match self {
+            Transaction::AddValidator(tx) => &tx.creds,
+            Transaction::AddSubnetValidator(tx) => &tx.creds,
+            Transaction::AddDelegator(tx) => &tx.creds,
+            Transaction::AddPermissionlessDelegator(tx) => &tx.creds,
+            Transaction::AddPermissionlessValidator(tx) => &tx.creds,
+            Transaction::CreateChain(tx) => &tx.creds,
+            Transaction::CreateSubnet(tx) => &tx.creds,
+            Transaction::RemoveSubnetValidator(tx) => &tx.creds,
+            Transaction::TransformSubnet(tx) => &tx.creds,
+            Transaction::TransferSubnetOwnership(tx) => &tx.creds,
+            Transaction::Base(tx) => &tx.creds,
+            Transaction::AdvanceTime(tx) => &tx.creds,
+            Transaction::RewardValidator(tx) => &tx.creds,
+            Transaction::Import(tx) => &tx.creds,
+            Transaction::Export(tx) => &tx.creds,
+        }
+    }
+
+    /// Packs the unsigned tx fields without the type ID.
+    fn pack_unsigned(&self, packer: &packer::Packer) -> Result<()> {
+        match self {
+            Transaction::AddValidator(tx) => tx.pack_to(packer),
+            Transaction::AddSubnetValidator(tx) => tx.pack_to(packer),
+            Transaction::AddDelegator(tx) => tx.pack_to(packer),
+            Transaction::AddPermissionlessDelegator(tx) => tx.pack_to(packer),
+            Transaction::AddPermissionlessValidator(tx) => tx.pack_to(packer),
+            Transaction::CreateChain(tx) => tx.pack_to(packer),
+            Transaction::CreateSubnet(tx) => tx.pack_to(packer),
+            Transaction::RemoveSubnetValidator(tx) => tx.pack_to(packer),
+            Transaction::TransformSubnet(tx) => tx.pack_to(packer),
+            Transaction::TransferSubnetOwnership(tx) => tx.pack_to(packer),
+            Transaction::Base(tx) => tx.pack_to(packer),
+            Transaction::AdvanceTime(tx) => tx.pack_to(packer),
+            Transaction::RewardValidator(tx) => tx.pack_to(packer),
+            Transaction::Import(tx) => tx.pack_to(packer),
+            Transaction::Export(tx) => tx.pack_to(packer),
+        }
+    }
+
+    /// Recomputes the tx ID by re-packing the tx with the codec version,
+    /// since the tx embedded in a block is not prefixed with the codec version.
+    /// ref. "avalanchego/vms/platformvm/txs.Tx.Initialize"
+    fn initialize(&mut self) -> Result<()> {
+        let metadata = {
+            let unsigned_tx = TransactionUnsigned(self);
+            txs::Metadata::compute(self.type_id(), &unsigned_tx, |packer| {
+                pack_credentials(packer, self.creds())
+            })?
+        };
+        match self {
+            Transaction::AddValidator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::AddSubnetValidator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::AddDelegator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::AddPermissionlessDelegator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::AddPermissionlessValidator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::CreateChain(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::CreateSubnet(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::RemoveSubnetValidator(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::TransformSubnet(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::TransferSubnetOwnership(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::Base(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::AdvanceTime(tx) => tx.metadata = Some(metadata),
+            Transaction::RewardValidator(tx) => tx.metadata = Some(metadata),
+            Transaction::Import(tx) => tx.base_tx.metadata = Some(metadata),
+            Transaction::Export(tx) => tx.base_tx.metadata = Some(metadata),
+        }
+        Ok(())
+    }
+}
+
+/// Packs the unsigned tx of "Transaction" for the metadata computation.
+struct TransactionUnsigned<'a>(&'a Transaction);
+
+impl codec::linear::Pack for TransactionUnsigned<'_> {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        self.0.pack_unsigned(packer)
+    }
+}
+
+/// "*txs.Tx" as a struct field (e.g., in blocks), where the unsigned tx
+/// is an interface prefixed with the type ID, followed by the credentials.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+impl codec::linear::Pack for Transaction {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        let type_id = match packer.registry() {
+            Some(registry) => registry.resolve_type_id(&self.type_name())?,
+            None => self.type_id(),
+        };
+        packer.pack_u32(type_id)?;
+        self.pack_unsigned(packer)?;
+        pack_credentials(packer, self.creds())
+    }
+}
+
+impl codec::linear::Unpack for Transaction {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let type_id = packer.unpack_u32()?;
+
+        let mut tx = if codec::linear::is_type::<add_validator::Tx>(packer.registry(), type_id) {
+            Transaction::AddValidator(add_validator::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<add_subnet_validator::Tx>(packer.registry(), type_id) {
+            Transaction::AddSubnetValidator(add_subnet_validator::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<add_delegator::Tx>(packer.registry(), type_id) {
+            Transaction::AddDelegator(add_delegator::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<add_permissionless_delegator::Tx>(
+            packer.registry(),
+            type_id,
+        ) {
+            Transaction::AddPermissionlessDelegator(add_permissionless_delegator::Tx::unpack_from(
+                packer,
+            )?)
+        } else if codec::linear::is_type::<add_permissionless_validator::Tx>(
+            packer.registry(),
+            type_id,
+        ) {
+            Transaction::AddPermissionlessValidator(add_permissionless_validator::Tx::unpack_from(
+                packer,
+            )?)
+        } else if codec::linear::is_type::<create_chain::Tx>(packer.registry(), type_id) {
+            Transaction::CreateChain(create_chain::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<create_subnet::Tx>(packer.registry(), type_id) {
+            Transaction::CreateSubnet(create_subnet::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<remove_subnet_validator::Tx>(packer.registry(), type_id)
+        {
+            Transaction::RemoveSubnetValidator(remove_subnet_validator::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<transform_subnet::Tx>(packer.registry(), type_id) {
+            Transaction::TransformSubnet(transform_subnet::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<transfer_subnet_ownership::Tx>(
+            packer.registry(),
+            type_id,
+        ) {
+            Transaction::TransferSubnetOwnership(transfer_subnet_ownership::Tx::unpack_from(
+                packer,
+            )?)
+        } else if codec::linear::is_type::<base::Tx>(packer.registry(), type_id) {
+            Transaction::Base(base::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<advance_time::Tx>(packer.registry(), type_id) {
+            Transaction::AdvanceTime(advance_time::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<reward_validator::Tx>(packer.registry(), type_id) {
+            Transaction::RewardValidator(reward_validator::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<import::Tx>(packer.registry(), type_id) {
+            Transaction::Import(import::Tx::unpack_from(packer)?)
+        } else if codec::linear::is_type::<export::Tx>(packer.registry(), type_id) {
+            Transaction::Export(export::Tx::unpack_from(packer)?)
+        } else {
+            return Err(Error::Other {
+                message: format!("unknown or unsupported P-chain tx type ID {}", type_id),
+                retryable: false,
+            });
+        };
+
+        let creds = unpack_credentials(packer)?;
+        match &mut tx {
+            Transaction::AddValidator(tx) => tx.creds = creds,
+            Transaction::AddSubnetValidator(tx) => tx.creds = creds,
+            Transaction::AddDelegator(tx) => tx.creds = creds,
+            Transaction::AddPermissionlessDelegator(tx) => tx.creds = creds,
+            Transaction::AddPermissionlessValidator(tx) => tx.creds = creds,
+            Transaction::CreateChain(tx) => tx.creds = creds,
+            Transaction::CreateSubnet(tx) => tx.creds = creds,
+            Transaction::RemoveSubnetValidator(tx) => tx.creds = creds,
+            Transaction::TransformSubnet(tx) => tx.creds = creds,
+            Transaction::TransferSubnetOwnership(tx) => tx.creds = creds,
+            Transaction::Base(tx) => tx.creds = creds,
+            Transaction::AdvanceTime(tx) => tx.creds = creds,
+            Transaction::RewardValidator(tx) => tx.creds = creds,
+            Transaction::Import(tx) => tx.creds = creds,
+            Transaction::Export(tx) => tx.creds = creds,
+        }
+        tx.initialize()?;
+        Ok(tx)
+    }
 }
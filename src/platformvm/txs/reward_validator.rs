@@ -0,0 +1,115 @@
+use crate::{codec, errors::Result, ids, impl_linear_codec, key, platformvm, txs};
+use serde::{Deserialize, Serialize};
+
+/// Proposes to remove the staker whose staking period has ended, and to
+/// reward it (if committed), only issued by the block builder
+/// (no base tx and no credentials).
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#RewardValidatorTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// ID of the tx that created the staker being removed/rewarded.
+    pub staker_tx_id: ids::Id,
+
+    /// Not serialized, and only non-empty once initialized.
+    /// Since this tx has no "avax.BaseTx", the metadata is kept here.
+    pub metadata: Option<txs::Metadata>,
+
+    /// Always empty, but packed as a part of "platformvm.Tx".
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            staker_tx_id: ids::Id::empty(),
+            metadata: None,
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(staker_tx_id: ids::Id) -> Self {
+        Self {
+            staker_tx_id,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is initialized.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.metadata.is_some() {
+            let m = self.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.RewardValidatorTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Computes the tx ID and bytes, with no signature.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Initialize>
+    pub fn initialize(&mut self) -> Result<()> {
+        let metadata = txs::Metadata::compute(Self::type_id(), self, |packer| {
+            platformvm::txs::pack_credentials(packer, &self.creds)
+        })?;
+        self.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately
+impl_linear_codec!(Tx, interface { staker_tx_id });
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::reward_validator::test_reward_validator_tx --exact --show-output
+#[test]
+fn test_reward_validator_tx() {
+    let mut tx = Tx::new(ids::Id::from_slice(&[0x7f; 32]));
+    tx.initialize().unwrap();
+
+    let expected: Vec<u8> = [
+        vec![
+            0x00, 0x00, // codec version
+            0x00, 0x00, 0x00, 0x14, // type ID
+        ],
+        vec![0x7f; 32], // staker tx ID
+    ]
+    .concat();
+    let metadata = tx.metadata.clone().unwrap();
+    assert_eq!(metadata.tx_bytes_with_no_signature, expected);
+
+    let parsed_tx = Tx::unpack(&metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+    assert_eq!(parsed_tx.staker_tx_id, ids::Id::from_slice(&[0x7f; 32]));
+}
@@ -0,0 +1,191 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Transfers the subnet ownership (i.e., control keys and threshold) to the new owner,
+/// authorized by the current subnet owner.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#TransferSubnetOwnershipTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    /// ID of the subnet this tx is modifying.
+    pub subnet_id: ids::Id,
+    /// Proves that the issuer has the right to modify the subnet.
+    pub subnet_auth: key::secp256k1::txs::Input,
+    /// Who is now authorized to manage this subnet.
+    pub owner: key::secp256k1::txs::OutputOwners,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            subnet_id: ids::Id::empty(),
+            subnet_auth: key::secp256k1::txs::Input::default(),
+            owner: key::secp256k1::txs::OutputOwners::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.TransferSubnetOwnershipTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. "avalanchego/vms/platformvm/txs.TransferSubnetOwnershipTx.SyntacticVerify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if self.subnet_id.is_empty() {
+            return Err(Error::Other {
+                message: "cannot transfer ownership of a permissionless subnet".to_string(), // ref. "ErrTransferPermissionlessSubnet"
+                retryable: false,
+            });
+        }
+        self.base_tx.verify_syntax()?;
+        self.subnet_auth.verify_syntax()?;
+        self.owner.verify_syntax()
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        subnet_id,
+        #[interface]
+        subnet_auth,
+        #[interface]
+        owner,
+    }
+);
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::transfer_subnet_ownership::test_transfer_subnet_ownership_tx_serialization_with_one_signer --exact --show-output
+#[test]
+fn test_transfer_subnet_ownership_tx_serialization_with_one_signer() {
+    use crate::ids::short;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 10,
+            ..txs::Tx::default()
+        },
+        subnet_id: ids::Id::from_slice(&[0x22; 32]),
+        subnet_auth: key::secp256k1::txs::Input::new(vec![0]),
+        owner: key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[0x44; 20])]),
+        ..Tx::default()
+    };
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    tx.verify_syntax().expect("failed verify_syntax");
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let expected_unsigned_bytes: Vec<u8> = [
+        vec![
+            0x00, 0x00, // codec version
+            0x00, 0x00, 0x00, 0x21, // type ID
+            0x00, 0x00, 0x00, 0x0a, // network ID
+        ],
+        vec![0x00; 32], // blockchain ID
+        vec![
+            0x00, 0x00, 0x00, 0x00, // outputs
+            0x00, 0x00, 0x00, 0x00, // inputs
+            0x00, 0x00, 0x00, 0x00, // memo
+        ],
+        vec![0x22; 32], // subnet ID
+        vec![
+            0x00, 0x00, 0x00, 0x0a, // "secp256k1fx.Input" type ID
+            0x00, 0x00, 0x00, 0x01, // number of sig indices
+            0x00, 0x00, 0x00, 0x00, //
+            0x00, 0x00, 0x00, 0x0b, // "secp256k1fx.OutputOwners" type ID
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // locktime
+            0x00, 0x00, 0x00, 0x01, // threshold
+            0x00, 0x00, 0x00, 0x01, // number of addresses
+        ],
+        vec![0x44; 20],
+    ]
+    .concat();
+    assert_eq!(
+        tx_metadata.tx_bytes_with_no_signature,
+        expected_unsigned_bytes
+    );
+
+    let parsed_tx = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+}
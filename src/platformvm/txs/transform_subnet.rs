@@ -0,0 +1,352 @@
+use crate::{
+    codec,
+    errors::{Error, Result},
+    ids, impl_linear_codec, key, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+pub use crate::platformvm::reward::PERCENT_DENOMINATOR;
+
+/// Transforms a permissioned subnet into an elastic (permissionless) subnet,
+/// staked with its own subnet asset, authorized by the subnet owner.
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#TransformSubnetTx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx>
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx>
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+    /// ID of the subnet to transform.
+    pub subnet_id: ids::Id,
+    /// Asset to use when staking on the subnet.
+    pub asset_id: ids::Id,
+    /// Initial amount of the subnet asset in existence.
+    pub initial_supply: u64,
+    /// Maximum amount of the subnet asset to ever exist.
+    pub maximum_supply: u64,
+    /// Minimum percentage of the remaining supply to be minted as staking
+    /// rewards, for the minimum stake duration (in "PERCENT_DENOMINATOR").
+    pub min_consumption_rate: u64,
+    /// Maximum percentage of the remaining supply to be minted as staking
+    /// rewards, for the maximum stake duration (in "PERCENT_DENOMINATOR").
+    pub max_consumption_rate: u64,
+    /// Minimum amount of the subnet asset required to validate the subnet.
+    pub min_validator_stake: u64,
+    /// Maximum amount of the subnet asset a validator can stake,
+    /// including delegations.
+    pub max_validator_stake: u64,
+    /// Minimum staking duration in seconds.
+    pub min_stake_duration: u32,
+    /// Maximum staking duration in seconds.
+    pub max_stake_duration: u32,
+    /// Minimum delegation fee in "PERCENT_DENOMINATOR".
+    pub min_delegation_fee: u32,
+    /// Minimum amount of the subnet asset required to delegate.
+    pub min_delegator_stake: u64,
+    /// Maximum ratio of the total delegation to the validator stake
+    /// (e.g., 1 means delegators can not stake on this validator).
+    pub max_validator_weight_factor: u8,
+    /// Minimum uptime to be rewarded for staking, in "PERCENT_DENOMINATOR".
+    pub uptime_requirement: u32,
+    /// Proves that the issuer has the right to transform the subnet.
+    pub subnet_auth: key::secp256k1::txs::Input,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            subnet_id: ids::Id::empty(),
+            asset_id: ids::Id::empty(),
+            initial_supply: 0,
+            maximum_supply: 0,
+            min_consumption_rate: 0,
+            max_consumption_rate: 0,
+            min_validator_stake: 0,
+            max_validator_stake: 0,
+            min_stake_duration: 0,
+            max_stake_duration: 0,
+            min_delegation_fee: 0,
+            min_delegator_stake: 0,
+            max_validator_weight_factor: 0,
+            uptime_requirement: 0,
+            subnet_auth: key::secp256k1::txs::Input::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.TransformSubnetTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// Verifies the transformation parameters, given the AVAX asset ID
+    /// which can not be used as the subnet staking asset.
+    /// ref. "avalanchego/vms/platformvm/txs.TransformSubnetTx.SyntacticVerify"
+    pub fn verify_syntax(&self, avax_asset_id: &ids::Id) -> Result<()> {
+        let err = if self.subnet_id.is_empty() {
+            Some("cannot transform primary network") // ref. "errCantTransformPrimaryNetwork"
+        } else if self.asset_id.is_empty() {
+            Some("empty asset ID is not valid") // ref. "errEmptyAssetID"
+        } else if self.asset_id == *avax_asset_id {
+            Some("asset ID can't be AVAX") // ref. "errAssetIDCantBeAVAX"
+        } else if self.initial_supply == 0 {
+            Some("initial supply must be non-0") // ref. "errInitialSupplyZero"
+        } else if self.initial_supply > self.maximum_supply {
+            Some("initial supply can't be greater than maximum supply") // ref. "errInitialSupplyGreaterThanMaxSupply"
+        } else if self.min_consumption_rate > self.max_consumption_rate {
+            Some("min consumption rate must be less than or equal to max consumption rate")
+        // ref. "errMinConsumptionRateTooLarge"
+        } else if self.max_consumption_rate > PERCENT_DENOMINATOR {
+            Some("max consumption rate must be less than or equal to 1,000,000")
+        // ref. "errMaxConsumptionRateTooLarge"
+        } else if self.min_validator_stake == 0 {
+            Some("min validator stake must be non-0") // ref. "errMinValidatorStakeZero"
+        } else if self.min_validator_stake > self.initial_supply {
+            Some("min validator stake must be less than or equal to initial supply")
+        // ref. "errMinValidatorStakeAboveSupply"
+        } else if self.min_validator_stake > self.max_validator_stake {
+            Some("min validator stake must be less than or equal to max validator stake")
+        // ref. "errMinValidatorStakeAboveMax"
+        } else if self.max_validator_stake > self.maximum_supply {
+            Some("max validator stake must be less than or equal to max supply")
+        // ref. "errMaxValidatorStakeTooLarge"
+        } else if self.min_stake_duration == 0 {
+            Some("min stake duration must be non-0") // ref. "errMinStakeDurationZero"
+        } else if self.min_stake_duration > self.max_stake_duration {
+            Some("min stake duration must be less than or equal to max stake duration")
+        // ref. "errMinStakeDurationTooLarge"
+        } else if self.min_delegation_fee as u64 > PERCENT_DENOMINATOR {
+            Some("min delegation fee must be less than or equal to 1,000,000") // ref. "errMinDelegationFeeTooLarge"
+        } else if self.min_delegator_stake == 0 {
+            Some("min delegator stake must be non-0") // ref. "errMinDelegatorStakeZero"
+        } else if self.max_validator_weight_factor == 0 {
+            Some("max validator weight factor must be non-0") // ref. "errMaxValidatorWeightFactorZero"
+        } else if self.uptime_requirement as u64 > PERCENT_DENOMINATOR {
+            Some("uptime requirement must be less than or equal to 1,000,000") // ref. "errUptimeRequirementTooLarge"
+        } else {
+            None
+        };
+        if let Some(message) = err {
+            return Err(Error::Other {
+                message: message.to_string(),
+                retryable: false,
+            });
+        }
+
+        self.base_tx.verify_syntax()?;
+        self.subnet_auth.verify_syntax()
+    }
+
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign>
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/crypto#PrivateKeyED25519.SignHash>
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, signers: Vec<Vec<T>>) -> Result<()> {
+        let (creds, metadata) = platformvm::txs::sign_tx(Self::type_id(), self, signers).await?;
+        self.creds = creds;
+        self.base_tx.metadata = Some(metadata);
+        Ok(())
+    }
+
+    /// Parses the signed tx bytes (e.g., "platform.getTx" API response)
+    /// and initializes the metadata with the recomputed tx ID.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Parse>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let (mut tx, creds, metadata) = platformvm::txs::unpack_signed_tx::<Self>(d)?;
+        tx.creds = creds;
+        tx.base_tx.metadata = Some(metadata);
+        Ok(tx)
+    }
+
+    /// Parses the hex-encoded signed tx from the "platform.getTx" API.
+    pub fn from_hex(d: &str) -> Result<Self> {
+        let decoded = platformvm::txs::decode_hex(d)?;
+        Self::unpack(&decoded)
+    }
+}
+
+// "Tx.Creds" are packed separately with the signing process
+impl_linear_codec!(
+    Tx,
+    interface {
+        base_tx,
+        subnet_id,
+        asset_id,
+        initial_supply,
+        maximum_supply,
+        min_consumption_rate,
+        max_consumption_rate,
+        min_validator_stake,
+        max_validator_stake,
+        min_stake_duration,
+        max_stake_duration,
+        min_delegation_fee,
+        min_delegator_stake,
+        max_validator_weight_factor,
+        uptime_requirement,
+        #[interface]
+        subnet_auth,
+    }
+);
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::transform_subnet::test_transform_subnet_tx --exact --show-output
+#[test]
+fn test_transform_subnet_tx() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let avax_asset_id = ids::Id::from_slice(&[0x01; 32]);
+    let mut tx = Tx {
+        base_tx: txs::Tx {
+            network_id: 10,
+            ..txs::Tx::default()
+        },
+        subnet_id: ids::Id::from_slice(&[0x22; 32]),
+        asset_id: ids::Id::from_slice(&[0x33; 32]),
+        initial_supply: 1_000,
+        maximum_supply: 2_000,
+        min_consumption_rate: 100_000,
+        max_consumption_rate: 120_000,
+        min_validator_stake: 10,
+        max_validator_stake: 500,
+        min_stake_duration: 24 * 60 * 60,
+        max_stake_duration: 365 * 24 * 60 * 60,
+        min_delegation_fee: 20_000,
+        min_delegator_stake: 5,
+        max_validator_weight_factor: 5,
+        uptime_requirement: 800_000,
+        subnet_auth: key::secp256k1::txs::Input::new(vec![0]),
+        ..Tx::default()
+    };
+    assert!(tx.verify_syntax(&avax_asset_id).is_ok());
+
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-2kqWNDaqUKQyE4ZsV5GLCGeizE6sHAJVyjnfjXoXrtcZpK9M67",
+    )
+    .expect("failed to load private key");
+    let signers: Vec<Vec<key::secp256k1::private_key::Key>> = vec![vec![test_key]];
+    ab!(tx.sign(signers)).expect("failed to sign");
+
+    let tx_metadata = tx.base_tx.metadata.clone().unwrap();
+    let b = &tx_metadata.tx_bytes_with_no_signature;
+    // codec version, type ID
+    assert_eq!(&b[..6], &[0x00, 0x00, 0x00, 0x00, 0x00, 0x18]);
+    // parameters follow the base tx (network ID, blockchain ID, outputs, inputs, memo)
+    // and the subnet/asset IDs
+    let offset = 6 + 4 + 32 + 4 + 4 + 4 + 32 + 32;
+    let params: Vec<u8> = [
+        1_000_u64.to_be_bytes().to_vec(),
+        2_000_u64.to_be_bytes().to_vec(),
+        100_000_u64.to_be_bytes().to_vec(),
+        120_000_u64.to_be_bytes().to_vec(),
+        10_u64.to_be_bytes().to_vec(),
+        500_u64.to_be_bytes().to_vec(),
+        (24 * 60 * 60_u32).to_be_bytes().to_vec(),
+        (365 * 24 * 60 * 60_u32).to_be_bytes().to_vec(),
+        20_000_u32.to_be_bytes().to_vec(),
+        5_u64.to_be_bytes().to_vec(),
+        vec![5],
+        800_000_u32.to_be_bytes().to_vec(),
+        vec![
+            0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        ],
+    ]
+    .concat();
+    assert_eq!(&b[offset..], params.as_slice());
+
+    let parsed_tx = Tx::unpack(&tx_metadata.tx_bytes_with_signatures).expect("failed Tx::unpack");
+    assert_eq!(parsed_tx, tx);
+    assert_eq!(parsed_tx.tx_id(), tx.tx_id());
+
+    // invalid parameters
+    let invalids = vec![
+        Tx {
+            subnet_id: ids::Id::empty(),
+            ..tx.clone()
+        },
+        Tx {
+            asset_id: avax_asset_id,
+            ..tx.clone()
+        },
+        Tx {
+            initial_supply: 2_001,
+            ..tx.clone()
+        },
+        Tx {
+            min_consumption_rate: 120_001,
+            ..tx.clone()
+        },
+        Tx {
+            max_consumption_rate: PERCENT_DENOMINATOR + 1,
+            ..tx.clone()
+        },
+        Tx {
+            min_validator_stake: 1_001,
+            max_validator_stake: 1_500,
+            ..tx.clone()
+        },
+        Tx {
+            max_validator_stake: 2_001,
+            ..tx.clone()
+        },
+        Tx {
+            min_stake_duration: 0,
+            ..tx.clone()
+        },
+        Tx {
+            min_delegation_fee: PERCENT_DENOMINATOR as u32 + 1,
+            ..tx.clone()
+        },
+        Tx {
+            max_validator_weight_factor: 0,
+            ..tx.clone()
+        },
+        Tx {
+            uptime_requirement: PERCENT_DENOMINATOR as u32 + 1,
+            ..tx.clone()
+        },
+        Tx {
+            subnet_auth: key::secp256k1::txs::Input::new(vec![1, 0]),
+            ..tx.clone()
+        },
+    ];
+    for invalid in invalids.iter() {
+        assert!(invalid.verify_syntax(&avax_asset_id).is_err());
+    }
+}
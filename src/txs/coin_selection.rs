@@ -0,0 +1,253 @@
+//! Coin selection strategies to pick the UTXOs to spend.
+use std::{cmp, fmt};
+
+use crate::txs::utxo;
+
+/// Selects the UTXOs to cover the target amount.
+pub trait CoinSelector: fmt::Debug + Send + Sync {
+    /// Returns the indices of the selected amounts whose sum is greater than
+    /// or equal to the target, or "None" if the amounts cannot cover the target.
+    /// The amounts are in the UTXO Id order, so the selection is deterministic.
+    fn select(&self, amounts: &[u64], target: u64) -> Option<Vec<usize>>;
+}
+
+/// Selects the UTXOs with the selector.
+pub fn select<'a>(
+    selector: &dyn CoinSelector,
+    candidates: Vec<&'a utxo::Utxo>,
+    target: u64,
+) -> Option<Vec<&'a utxo::Utxo>> {
+    let amounts: Vec<u64> = candidates.iter().map(|utxo| utxo.amount()).collect();
+    let indices = selector.select(&amounts, target)?;
+    Some(indices.into_iter().map(|i| candidates[i]).collect())
+}
+
+/// Consumes the amounts in the given order until the target is met.
+fn select_greedy(amounts: &[u64], indices: Vec<usize>, target: u64) -> Option<Vec<usize>> {
+    let mut selected = Vec::new();
+    let mut total = 0_u64;
+    for i in indices {
+        if total >= target {
+            break;
+        }
+        total = total.saturating_add(amounts[i]);
+        selected.push(i);
+    }
+    if total < target {
+        return None;
+    }
+    Some(selected)
+}
+
+impl CoinSelector for utxo::Order {
+    fn select(&self, amounts: &[u64], target: u64) -> Option<Vec<usize>> {
+        // stable sort to keep the UTXO Id order for the same amounts
+        let mut indices: Vec<usize> = (0..amounts.len()).collect();
+        match self {
+            utxo::Order::UtxoId => {}
+            utxo::Order::LargestFirst => indices.sort_by_key(|i| cmp::Reverse(amounts[*i])),
+            utxo::Order::SmallestFirst => indices.sort_by_key(|i| amounts[*i]),
+        }
+        select_greedy(amounts, indices, target)
+    }
+}
+
+/// Searches for the UTXOs whose sum exactly matches the target (within the tolerance),
+/// thus requiring no change output. Falls back to the largest-first selection
+/// if no such match is found within the maximum tries.
+/// ref. <https://github.com/bitcoin/bitcoin/blob/master/src/wallet/coinselection.cpp> "SelectCoinsBnB"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BranchAndBound {
+    /// Maximum excess over the target to accept as the match.
+    pub tolerance: u64,
+    /// Maximum number of the search steps.
+    pub max_tries: usize,
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl BranchAndBound {
+    pub fn default() -> Self {
+        Self {
+            tolerance: 0,
+            max_tries: 100_000,
+        }
+    }
+
+    /// Returns the selected positions of the "sorted" values once the match is found.
+    /// Walks the include/exclude tree depth-first with an explicit stack
+    /// rather than recursion, so that the search depth is not bounded
+    /// by the call stack for the wallets with many UTXOs.
+    fn search(&self, values: &[u64], remaining: &[u64], target: u64) -> Option<Vec<usize>> {
+        // included positions with the total before each inclusion
+        let mut included: Vec<(usize, u64)> = Vec::new();
+        let (mut depth, mut total) = (0_usize, 0_u64);
+        for _ in 0..self.max_tries {
+            let backtrack = if total > target.saturating_add(self.tolerance) {
+                true
+            } else if total >= target {
+                return Some(included.into_iter().map(|(pos, _)| pos).collect());
+            } else {
+                depth == values.len() || total.saturating_add(remaining[depth]) < target
+            };
+
+            if backtrack {
+                // exclude the last included, and skip the same amounts
+                // since excluding them leads to the same results
+                // (nothing left to exclude means the whole tree is searched)
+                let (last, total_before) = included.pop()?;
+                total = total_before;
+                depth = last + 1;
+                while depth < values.len() && values[depth] == values[last] {
+                    depth += 1;
+                }
+            } else {
+                // include the current
+                included.push((depth, total));
+                total = total.saturating_add(values[depth]);
+                depth += 1;
+            }
+        }
+        None
+    }
+}
+
+impl CoinSelector for BranchAndBound {
+    fn select(&self, amounts: &[u64], target: u64) -> Option<Vec<usize>> {
+        let mut indices: Vec<usize> = (0..amounts.len()).collect();
+        indices.sort_by_key(|i| cmp::Reverse(amounts[*i]));
+        let values: Vec<u64> = indices.iter().map(|i| amounts[*i]).collect();
+
+        // sum of the values from each position to the end, to prune the branches
+        // that cannot reach the target
+        let mut remaining = vec![0_u64; values.len()];
+        let mut sum = 0_u64;
+        for (i, v) in values.iter().enumerate().rev() {
+            sum = sum.saturating_add(*v);
+            remaining[i] = sum;
+        }
+
+        if let Some(selected) = self.search(&values, &remaining, target) {
+            return Some(selected.into_iter().map(|pos| indices[pos]).collect());
+        }
+        utxo::Order::LargestFirst.select(amounts, target)
+    }
+}
+
+/// Consumes as many of the smallest UTXOs as possible (up to the maximum inputs),
+/// so that the small UTXOs are merged into the change output.
+/// Returns "None" if the target cannot be covered within the maximum inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Consolidate {
+    /// Maximum number of the inputs to limit the tx size.
+    pub max_inputs: usize,
+}
+
+impl Default for Consolidate {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Consolidate {
+    pub fn default() -> Self {
+        Self { max_inputs: 128 }
+    }
+}
+
+impl CoinSelector for Consolidate {
+    fn select(&self, amounts: &[u64], target: u64) -> Option<Vec<usize>> {
+        let mut smallest_first: Vec<usize> = (0..amounts.len()).collect();
+        smallest_first.sort_by_key(|i| amounts[*i]);
+
+        let mut selected: Vec<usize> = smallest_first
+            .iter()
+            .take(self.max_inputs)
+            .copied()
+            .collect();
+        let total = selected
+            .iter()
+            .fold(0_u64, |total, i| total.saturating_add(amounts[*i]));
+        if total >= target {
+            return Some(selected);
+        }
+
+        // the smallest UTXOs cannot cover the target,
+        // so cover the target first and fill the rest with the smallest
+        selected = utxo::Order::LargestFirst.select(amounts, target)?;
+        if selected.len() > self.max_inputs {
+            return None;
+        }
+        for i in smallest_first {
+            if selected.len() >= self.max_inputs {
+                break;
+            }
+            if !selected.contains(&i) {
+                selected.push(i);
+            }
+        }
+        Some(selected)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::coin_selection::test_coin_selection --exact --show-output
+#[test]
+fn test_coin_selection() {
+    let amounts = [50, 10, 30, 10, 70, 5];
+    let sum = |selected: &[usize]| selected.iter().map(|i| amounts[*i]).sum::<u64>();
+
+    assert_eq!(
+        utxo::Order::UtxoId.select(&amounts, 55).unwrap(),
+        vec![0, 1]
+    );
+    assert_eq!(
+        utxo::Order::LargestFirst.select(&amounts, 55).unwrap(),
+        vec![4]
+    );
+    assert_eq!(
+        utxo::Order::SmallestFirst.select(&amounts, 55).unwrap(),
+        vec![5, 1, 3, 2]
+    );
+    assert!(utxo::Order::UtxoId.select(&amounts, 176).is_none());
+    assert!(utxo::Order::UtxoId.select(&amounts, 0).unwrap().is_empty());
+
+    // exact matches
+    let bnb = BranchAndBound::default();
+    let selected = bnb.select(&amounts, 55).unwrap();
+    assert_eq!(sum(&selected), 55);
+    assert_eq!(selected, vec![0, 5]);
+    let selected = bnb.select(&amounts, 95).unwrap();
+    assert_eq!(sum(&selected), 95);
+    assert_eq!(sum(&bnb.select(&amounts, 175).unwrap()), 175);
+
+    // no exact match within the tolerance, thus falls back to the largest-first
+    assert_eq!(bnb.select(&amounts, 4).unwrap(), vec![4]);
+    let bnb = BranchAndBound {
+        tolerance: 1,
+        ..BranchAndBound::default()
+    };
+    assert_eq!(bnb.select(&amounts, 4).unwrap(), vec![5]);
+    assert!(bnb.select(&amounts, 176).is_none());
+
+    // searches as deep as the number of UTXOs without overflowing the stack
+    let many = vec![1_u64; 50_000];
+    let selected = BranchAndBound::default().select(&many, 50_000).unwrap();
+    assert_eq!(selected.len(), 50_000);
+
+    // small UTXOs are merged
+    let consolidate = Consolidate { max_inputs: 4 };
+    assert_eq!(consolidate.select(&amounts, 20).unwrap(), vec![5, 1, 3, 2]);
+    let selected = consolidate.select(&amounts, 100).unwrap();
+    assert_eq!(selected, vec![4, 0, 5, 1]);
+    assert!(sum(&selected) >= 100);
+    assert!(consolidate.select(&amounts, 176).is_none());
+
+    // the target is covered only with more than the maximum inputs
+    let consolidate = Consolidate { max_inputs: 2 };
+    assert_eq!(consolidate.select(&amounts, 120).unwrap(), vec![4, 0]);
+    assert!(consolidate.select(&amounts, 150).is_none());
+}
@@ -0,0 +1,546 @@
+//! JSON rendering of transactions in the same format as avalanchego
+//! returns with "encoding=json" (e.g., "platform.getTx", "avm.getTx").
+use crate::{
+    constants,
+    errors::{Error, Result},
+    formatting,
+    ids::{self, short},
+    key, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+
+/// Formatting context for the JSON encoding, which determines
+/// the bech32 addresses (e.g., "P-avax1...", "X-fuji1...").
+/// ref. "avalanchego/vms/secp256k1fx.OutputOwners.InitCtx"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    /// Chain alias prefixed to each address (e.g., "P", "X").
+    pub chain_alias: String,
+    /// Human-readable part of the bech32 address (e.g., "avax", "fuji").
+    pub hrp: String,
+}
+
+impl Context {
+    /// Creates a context with the HRP of the network ID.
+    /// ref. "avalanchego/utils/constants.GetHRP"
+    pub fn new(chain_alias: &str, network_id: u32) -> Self {
+        let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
+            Some(v) => v,
+            None => constants::FALLBACK_HRP,
+        };
+        Self::new_with_hrp(chain_alias, hrp)
+    }
+
+    pub fn new_with_hrp(chain_alias: &str, hrp: &str) -> Self {
+        Self {
+            chain_alias: chain_alias.to_string(),
+            hrp: hrp.to_string(),
+        }
+    }
+
+    /// ref. "avalanchego/utils/formatting/address.Format"
+    pub fn format_address(&self, addr: &short::Id) -> Result<String> {
+        formatting::address(&self.chain_alias, &self.hrp, addr.as_ref()).map_err(|e| Error::Other {
+            message: format!("failed formatting::address '{}'", e),
+            retryable: false,
+        })
+    }
+}
+
+/// Implemented by the transactions that can be rendered in, and parsed from,
+/// the avalanchego JSON encoding.
+pub trait Json: Sized {
+    /// Renders the tx with its credentials and ID.
+    fn to_json(&self, ctx: &Context) -> Result<String>;
+
+    /// Parses the JSON-encoded tx, and initializes the metadata
+    /// by re-encoding the tx in the binary format.
+    fn from_json(d: &str) -> Result<Self>;
+}
+
+/// Signed tx in the JSON encoding.
+/// ref. "avalanchego/vms/platformvm/txs.Tx"
+/// ref. "avalanchego/vms/avm/txs.Tx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedTxJson<U, C> {
+    #[serde(rename = "unsignedTx")]
+    pub unsigned_tx: U,
+    pub credentials: Vec<C>,
+    #[serde(default)]
+    pub id: ids::Id,
+}
+
+impl<U: Serialize, C: Serialize> SignedTxJson<U, C> {
+    pub fn encode(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| Error::Other {
+            message: format!("failed serde_json::to_string '{}'", e),
+            retryable: false,
+        })
+    }
+}
+
+impl<'de, U: Deserialize<'de>, C: Deserialize<'de>> SignedTxJson<U, C> {
+    pub fn decode(d: &'de str) -> Result<Self> {
+        serde_json::from_str(d).map_err(|e| Error::Other {
+            message: format!("failed serde_json::from_str '{}'", e),
+            retryable: false,
+        })
+    }
+}
+
+/// Returns the tx ID, or the empty ID if the tx is not signed yet.
+pub fn tx_id(base_tx: &txs::Tx) -> ids::Id {
+    match &base_tx.metadata {
+        Some(metadata) => metadata.id,
+        None => ids::Id::empty(),
+    }
+}
+
+/// Encodes bytes with the "0x" prefix and without the checksum.
+/// ref. "avalanchego/utils/formatting.HexNC"
+pub fn encode_hex_nc(d: &[u8]) -> String {
+    format!("0x{}", hex::encode(d))
+}
+
+pub fn decode_hex_nc(d: &str) -> Result<Vec<u8>> {
+    hex::decode(d.trim_start_matches("0x")).map_err(|e| Error::Other {
+        message: format!("failed hex::decode '{}'", e),
+        retryable: false,
+    })
+}
+
+/// Parses the bech32 address (e.g., "P-avax1...") into the short ID.
+pub fn parse_address(addr: &str) -> Result<short::Id> {
+    let addr = match addr.split_once('-') {
+        Some((_, addr)) => addr,
+        None => addr,
+    };
+    let (_, d) = key::secp256k1::address::avax_address_to_short_bytes("", addr).map_err(|e| {
+        Error::Other {
+            message: format!("failed avax_address_to_short_bytes '{}'", e),
+            retryable: false,
+        }
+    })?;
+    if d.len() != short::LEN {
+        return Err(Error::Other {
+            message: format!("address has {} bytes (expected {})", d.len(), short::LEN),
+            retryable: false,
+        });
+    }
+    Ok(short::Id::from_slice(&d))
+}
+
+/// "secp256k1fx.OutputOwners" in JSON, with the fields sorted by name
+/// as avalanchego encodes the "Fields" map.
+/// ref. "avalanchego/vms/secp256k1fx.OutputOwners.MarshalJSON"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputOwnersJson {
+    pub addresses: Vec<String>,
+    pub locktime: u64,
+    pub threshold: u32,
+}
+
+impl OutputOwnersJson {
+    pub fn new(ctx: &Context, owners: &key::secp256k1::txs::OutputOwners) -> Result<Self> {
+        let mut addresses = Vec::new();
+        for addr in owners.addresses.iter() {
+            addresses.push(ctx.format_address(addr)?);
+        }
+        Ok(Self {
+            addresses,
+            locktime: owners.locktime,
+            threshold: owners.threshold,
+        })
+    }
+
+    pub fn parse(&self) -> Result<key::secp256k1::txs::OutputOwners> {
+        let mut addresses = Vec::new();
+        for addr in self.addresses.iter() {
+            addresses.push(parse_address(addr)?);
+        }
+        Ok(key::secp256k1::txs::OutputOwners {
+            locktime: self.locktime,
+            threshold: self.threshold,
+            addresses,
+        })
+    }
+}
+
+/// ref. "avalanchego/vms/secp256k1fx.TransferOutput.MarshalJSON"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferOutputJson {
+    pub addresses: Vec<String>,
+    pub amount: u64,
+    pub locktime: u64,
+    pub threshold: u32,
+}
+
+impl TransferOutputJson {
+    pub fn new(ctx: &Context, out: &key::secp256k1::txs::transfer::Output) -> Result<Self> {
+        let owners = OutputOwnersJson::new(ctx, &out.output_owners)?;
+        Ok(Self {
+            addresses: owners.addresses,
+            amount: out.amount,
+            locktime: owners.locktime,
+            threshold: owners.threshold,
+        })
+    }
+
+    pub fn parse(&self) -> Result<key::secp256k1::txs::transfer::Output> {
+        let owners = OutputOwnersJson {
+            addresses: self.addresses.clone(),
+            locktime: self.locktime,
+            threshold: self.threshold,
+        };
+        Ok(key::secp256k1::txs::transfer::Output {
+            amount: self.amount,
+            output_owners: owners.parse()?,
+        })
+    }
+}
+
+/// ref. "avalanchego/vms/platformvm/stakeable.LockOut"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StakeableLockOutJson {
+    pub locktime: u64,
+    pub output: TransferOutputJson,
+}
+
+/// "avax.TransferableOut" interface in JSON.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OutJson {
+    Transfer(TransferOutputJson),
+    StakeableLock(StakeableLockOutJson),
+}
+
+/// ref. "avalanchego/vms/components/avax.TransferableOutput"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutputJson {
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    #[serde(rename = "fxID")]
+    pub fx_id: ids::Id,
+    pub output: OutJson,
+}
+
+impl OutputJson {
+    pub fn new(ctx: &Context, output: &txs::transferable::Output) -> Result<Self> {
+        let out = match (&output.transfer_output, &output.stakeable_lock_out) {
+            (Some(out), None) => OutJson::Transfer(TransferOutputJson::new(ctx, out)?),
+            (None, Some(lock_out)) => OutJson::StakeableLock(StakeableLockOutJson {
+                locktime: lock_out.locktime,
+                output: TransferOutputJson::new(ctx, &lock_out.transfer_output)?,
+            }),
+            _ => {
+                return Err(Error::Other {
+                    message: "only one of transfer_output and stakeable_lock_out must be Some"
+                        .to_string(),
+                    retryable: false,
+                })
+            }
+        };
+        Ok(Self {
+            asset_id: output.asset_id,
+            fx_id: output.fx_id.unwrap_or_else(key::secp256k1::txs::fx_id),
+            output: out,
+        })
+    }
+
+    pub fn parse(&self) -> Result<txs::transferable::Output> {
+        let mut output = txs::transferable::Output {
+            asset_id: self.asset_id,
+            fx_id: Some(self.fx_id),
+            ..txs::transferable::Output::default()
+        };
+        match &self.output {
+            OutJson::Transfer(out) => output.transfer_output = Some(out.parse()?),
+            OutJson::StakeableLock(lock_out) => {
+                output.stakeable_lock_out = Some(platformvm::txs::StakeableLockOut {
+                    locktime: lock_out.locktime,
+                    transfer_output: lock_out.output.parse()?,
+                })
+            }
+        }
+        Ok(output)
+    }
+
+    pub fn new_list(
+        ctx: &Context,
+        outputs: &Option<Vec<txs::transferable::Output>>,
+    ) -> Result<Vec<Self>> {
+        let mut rs = Vec::new();
+        for output in outputs.iter().flatten() {
+            rs.push(Self::new(ctx, output)?);
+        }
+        Ok(rs)
+    }
+
+    pub fn parse_list(outputs: &[Self]) -> Result<Option<Vec<txs::transferable::Output>>> {
+        if outputs.is_empty() {
+            return Ok(None);
+        }
+        let mut rs = Vec::new();
+        for output in outputs.iter() {
+            rs.push(output.parse()?);
+        }
+        Ok(Some(rs))
+    }
+}
+
+/// "secp256k1fx.Input" in JSON (e.g., subnet authorization).
+/// ref. "avalanchego/vms/secp256k1fx.Input"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SigIndicesJson {
+    #[serde(rename = "signatureIndices")]
+    pub sig_indices: Vec<u32>,
+}
+
+/// ref. "avalanchego/vms/secp256k1fx.TransferInput"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransferInputJson {
+    pub amount: u64,
+    #[serde(rename = "signatureIndices")]
+    pub sig_indices: Vec<u32>,
+}
+
+/// ref. "avalanchego/vms/platformvm/stakeable.LockIn"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StakeableLockInJson {
+    pub locktime: u64,
+    pub input: TransferInputJson,
+}
+
+/// "avax.TransferableIn" interface in JSON.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InJson {
+    Transfer(TransferInputJson),
+    StakeableLock(StakeableLockInJson),
+}
+
+/// ref. "avalanchego/vms/components/avax.TransferableInput"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InputJson {
+    #[serde(rename = "txID")]
+    pub tx_id: ids::Id,
+    #[serde(rename = "outputIndex")]
+    pub output_index: u32,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    #[serde(rename = "fxID")]
+    pub fx_id: ids::Id,
+    pub input: InJson,
+}
+
+impl InputJson {
+    pub fn new(input: &txs::transferable::Input) -> Result<Self> {
+        let transfer_input_json =
+            |input: &key::secp256k1::txs::transfer::Input| TransferInputJson {
+                amount: input.amount,
+                sig_indices: input.sig_indices.clone(),
+            };
+        let in_json = match (&input.transfer_input, &input.stakeable_lock_in) {
+            (Some(input), None) => InJson::Transfer(transfer_input_json(input)),
+            (None, Some(lock_in)) => InJson::StakeableLock(StakeableLockInJson {
+                locktime: lock_in.locktime,
+                input: transfer_input_json(&lock_in.transfer_input),
+            }),
+            _ => {
+                return Err(Error::Other {
+                    message: "only one of transfer_input and stakeable_lock_in must be Some"
+                        .to_string(),
+                    retryable: false,
+                })
+            }
+        };
+        Ok(Self {
+            tx_id: input.utxo_id.tx_id,
+            output_index: input.utxo_id.output_index,
+            asset_id: input.asset_id,
+            fx_id: if input.fx_id.is_empty() {
+                key::secp256k1::txs::fx_id()
+            } else {
+                input.fx_id
+            },
+            input: in_json,
+        })
+    }
+
+    pub fn parse(&self) -> txs::transferable::Input {
+        let transfer_input = |input: &TransferInputJson| {
+            key::secp256k1::txs::transfer::Input::new(input.amount, input.sig_indices.clone())
+        };
+        let mut input = txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: self.tx_id,
+                output_index: self.output_index,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: self.asset_id,
+            fx_id: self.fx_id,
+            ..txs::transferable::Input::default()
+        };
+        match &self.input {
+            InJson::Transfer(v) => input.transfer_input = Some(transfer_input(v)),
+            InJson::StakeableLock(v) => {
+                input.stakeable_lock_in = Some(platformvm::txs::StakeableLockIn {
+                    locktime: v.locktime,
+                    transfer_input: transfer_input(&v.input),
+                })
+            }
+        }
+        input
+    }
+
+    pub fn new_list(inputs: &Option<Vec<txs::transferable::Input>>) -> Result<Vec<Self>> {
+        let mut rs = Vec::new();
+        for input in inputs.iter().flatten() {
+            rs.push(Self::new(input)?);
+        }
+        Ok(rs)
+    }
+
+    pub fn parse_list(inputs: &[Self]) -> Option<Vec<txs::transferable::Input>> {
+        if inputs.is_empty() {
+            return None;
+        }
+        Some(inputs.iter().map(Self::parse).collect())
+    }
+}
+
+/// ref. "avalanchego/vms/components/avax.BaseTx"
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaseTxJson {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    pub outputs: Vec<OutputJson>,
+    pub inputs: Vec<InputJson>,
+    pub memo: String,
+}
+
+impl BaseTxJson {
+    pub fn new(ctx: &Context, base_tx: &txs::Tx) -> Result<Self> {
+        Ok(Self {
+            network_id: base_tx.network_id,
+            blockchain_id: base_tx.blockchain_id,
+            outputs: OutputJson::new_list(ctx, &base_tx.transferable_outputs)?,
+            inputs: InputJson::new_list(&base_tx.transferable_inputs)?,
+            memo: encode_hex_nc(base_tx.memo.as_deref().unwrap_or_default()),
+        })
+    }
+
+    pub fn parse(&self) -> Result<txs::Tx> {
+        let memo = decode_hex_nc(&self.memo)?;
+        Ok(txs::Tx {
+            network_id: self.network_id,
+            blockchain_id: self.blockchain_id,
+            transferable_outputs: OutputJson::parse_list(&self.outputs)?,
+            transferable_inputs: InputJson::parse_list(&self.inputs),
+            memo: if memo.is_empty() { None } else { Some(memo) },
+            ..txs::Tx::default()
+        })
+    }
+}
+
+/// ref. "avalanchego/vms/secp256k1fx.Credential.MarshalJSON"
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CredentialJson {
+    pub signatures: Vec<String>,
+}
+
+impl CredentialJson {
+    pub fn new(cred: &key::secp256k1::txs::Credential) -> Self {
+        Self {
+            signatures: cred.signatures.iter().map(|s| encode_hex_nc(s)).collect(),
+        }
+    }
+
+    pub fn parse(&self) -> Result<key::secp256k1::txs::Credential> {
+        let mut signatures = Vec::new();
+        for sig in self.signatures.iter() {
+            signatures.push(decode_hex_nc(sig)?);
+        }
+        Ok(key::secp256k1::txs::Credential::new(signatures))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::json::test_base_tx_json --exact --show-output
+#[test]
+fn test_base_tx_json() {
+    use crate::{codec::linear::Pack, packer::Packer};
+
+    let ctx = Context::new("P", 1);
+    assert_eq!(ctx.hrp, "avax");
+    assert_eq!(Context::new("X", 10).hrp, "testing");
+    assert_eq!(Context::new("X", 9999).hrp, constants::FALLBACK_HRP);
+    assert_eq!(
+        key::secp256k1::txs::fx_id().to_string(),
+        "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ"
+    );
+
+    let addr = short::Id::from_slice(&[1; 20]);
+    let formatted = ctx.format_address(&addr).unwrap();
+    assert!(formatted.starts_with("P-avax1"));
+    assert_eq!(parse_address(&formatted).unwrap(), addr);
+
+    let base_tx = txs::Tx {
+        network_id: 1,
+        blockchain_id: ids::Id::empty(),
+        transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id: ids::Id::from_slice(&[2; 32]),
+            stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                locktime: 10,
+                transfer_output: key::secp256k1::txs::transfer::Output::new(
+                    5,
+                    key::secp256k1::txs::OutputOwners::new(0, 1, &[addr]),
+                ),
+            }),
+            ..txs::transferable::Output::default()
+        }]),
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::from_slice(&[3; 32]),
+                output_index: 1,
+                ..txs::utxo::Id::default()
+            },
+            asset_id: ids::Id::from_slice(&[2; 32]),
+            transfer_input: Some(key::secp256k1::txs::transfer::Input::new(6, vec![0])),
+            ..txs::transferable::Input::default()
+        }]),
+        memo: Some(vec![0xab]),
+        ..txs::Tx::default()
+    };
+
+    let encoded = serde_json::to_string(&BaseTxJson::new(&ctx, &base_tx).unwrap()).unwrap();
+    let expected = format!(
+        "{{\"networkID\":1,\"blockchainID\":\"11111111111111111111111111111111LpoYY\",\
+         \"outputs\":[{{\"assetID\":\"{}\",\"fxID\":\"spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ\",\
+         \"output\":{{\"locktime\":10,\"output\":{{\"addresses\":[\"{}\"],\"amount\":5,\"locktime\":0,\"threshold\":1}}}}}}],\
+         \"inputs\":[{{\"txID\":\"{}\",\"outputIndex\":1,\"assetID\":\"{}\",\"fxID\":\"spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ\",\
+         \"input\":{{\"amount\":6,\"signatureIndices\":[0]}}}}],\"memo\":\"0xab\"}}",
+        ids::Id::from_slice(&[2; 32]),
+        formatted,
+        ids::Id::from_slice(&[3; 32]),
+        ids::Id::from_slice(&[2; 32]),
+    );
+    assert_eq!(encoded, expected);
+
+    let decoded: BaseTxJson = serde_json::from_str(&encoded).unwrap();
+    let parsed = decoded.parse().unwrap();
+    let packer = Packer::new(1024, 0);
+    parsed.pack_to(&packer).unwrap();
+    let parsed_bytes = packer.take_bytes();
+    let packer = Packer::new(1024, 0);
+    base_tx.pack_to(&packer).unwrap();
+    assert_eq!(parsed_bytes, packer.take_bytes());
+}
@@ -1,3 +1,6 @@
+pub mod coin_selection;
+pub mod json;
+pub mod partial;
 pub mod raw;
 pub mod transferable;
 pub mod utxo;
@@ -5,11 +8,15 @@ pub mod utxo;
 use super::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
     errors::{Error, Result},
-    hash, ids, key, packer, platformvm,
+    hash, ids, impl_linear_codec, key, packer,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+/// Max number of bytes in the memo field.
+/// ref. "avalanchego/vms/components/avax.MaxMemoSize"
+pub const MAX_MEMO_SIZE: usize = 256;
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#BaseTx>
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -78,259 +85,75 @@ impl Tx {
     /// ref. "avalanchego/codec/linearcodec.linearCodec.MarshalInto"
     /// ref. "avalanchego/codec/reflectcodec.genericCodec.MarshalInto"
     pub fn pack(&self, codec_version: u16, type_id: u32) -> Result<packer::Packer> {
-        // ref. "avalanchego/codec.manager.Marshal", "vms/avm.newCustomCodecs"
-        // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
-        let packer = packer::Packer::new((1 << 31) - 1, 128);
+        codec::linear::pack_with_header(codec_version, type_id, self)
+    }
 
-        // codec version
-        // ref. "avalanchego/codec.manager.Marshal"
-        packer.pack_u16(codec_version)?;
-        packer.pack_u32(type_id)?;
-
-        // marshal the actual struct "avm.BaseTx"
-        // "BaseTx.Metadata" is not serialize:"true" thus skipping serialization!!!
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#BaseTx
-        // ref. "avalanchego/codec/reflectcodec.structFielder"
-        packer.pack_u32(self.network_id)?;
-        packer.pack_bytes(self.blockchain_id.as_ref())?;
-
-        // "transferable_outputs" field; pack the number of slice elements
-        if self.transferable_outputs.is_some() {
-            let transferable_outputs = self.transferable_outputs.as_ref().unwrap();
-            packer.pack_u32(transferable_outputs.len() as u32)?;
-
-            for transferable_output in transferable_outputs.iter() {
-                // "TransferableOutput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableOutput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_output.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
-                if transferable_output.transfer_output.is_none()
-                    && transferable_output.stakeable_lock_out.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_out = {
-                    if transferable_output.transfer_output.is_some() {
-                        key::secp256k1::txs::transfer::Output::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockOut::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
-                packer.pack_u32(type_id_transferable_out)?;
-
-                match type_id_transferable_out {
-                    7 => {
-                        // "key::secp256k1::txs::transfer::Output"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        let transfer_output = transferable_output.transfer_output.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(transfer_output.amount)?;
-
-                        // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferOutput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        packer.pack_u64(transfer_output.output_owners.locktime)?;
-                        packer.pack_u32(transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
-                        for addr in transfer_output.output_owners.addresses.iter() {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    22 => {
-                        // "platformvm::txs::StakeableLockOut"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        let stakeable_lock_out =
-                            transferable_output.stakeable_lock_out.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockOut.locktime" field
-                        packer.pack_u64(stakeable_lock_out.locktime)?;
-
-                        // secp256k1fx.TransferOutput type ID
-                        packer.pack_u32(7)?;
-
-                        // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockOut"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
-                        //
-                        // marshal "secp256k1fx.TransferOutput.Amt" field
-                        packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
-                        packer
-                            .pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
-                        packer
-                            .pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
-                        packer.pack_u32(
-                            stakeable_lock_out
-                                .transfer_output
-                                .output_owners
-                                .addresses
-                                .len() as u32,
-                        )?;
-                        for addr in stakeable_lock_out
-                            .transfer_output
-                            .output_owners
-                            .addresses
-                            .iter()
-                        {
-                            packer.pack_bytes(addr.as_ref())?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableOutput",
-                                type_id_transferable_out
-                            ),
-                            retryable: false,
-                        })
-                    }
-                }
+    /// Verifies the tx is well-formed and in the canonical form:
+    /// every input and output is valid, outputs are sorted,
+    /// and inputs are sorted and unique.
+    /// ref. "avalanchego/vms/components/avax.BaseTx.Verify"
+    /// ref. "avalanchego/vms/platformvm/txs.BaseTx.SyntacticVerify"
+    /// ref. "avalanchego/vms/avm/txs/executor.SyntacticVerifier.BaseTx"
+    pub fn verify_syntax(&self) -> Result<()> {
+        if let Some(memo) = &self.memo {
+            if memo.len() > MAX_MEMO_SIZE {
+                return Err(Error::Other {
+                    message: format!(
+                        "memo exceeds maximum length: {} > {}",
+                        memo.len(),
+                        MAX_MEMO_SIZE
+                    ), // ref. "ErrMemoTooLarge"
+                    retryable: false,
+                });
             }
-        } else {
-            packer.pack_u32(0_u32)?;
         }
 
-        // "transferable_inputs" field; pack the number of slice elements
-        if self.transferable_inputs.is_some() {
-            let transferable_inputs = self.transferable_inputs.as_ref().unwrap();
-            packer.pack_u32(transferable_inputs.len() as u32)?;
-
-            for transferable_input in transferable_inputs.iter() {
-                // "TransferableInput.UTXOID" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXOID
-                packer.pack_bytes(transferable_input.utxo_id.tx_id.as_ref())?;
-                packer.pack_u32(transferable_input.utxo_id.output_index)?;
-
-                // "TransferableInput.Asset" is struct and serialize:"true"
-                // but embedded inline in the struct "TransferableInput"
-                // so no need to encode type ID
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
-                packer.pack_bytes(transferable_input.asset_id.as_ref())?;
-
-                // fx_id is serialize:"false" thus skipping serialization
-
-                // decide the type
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
-                if transferable_input.transfer_input.is_none()
-                    && transferable_input.stakeable_lock_in.is_none()
-                {
-                    return Err(Error::Other {
-                        message: "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in".to_string(),
-                        retryable: false,
-                    });
-                }
-                let type_id_transferable_in = {
-                    if transferable_input.transfer_input.is_some() {
-                        key::secp256k1::txs::transfer::Input::type_id()
-                    } else {
-                        platformvm::txs::StakeableLockIn::type_id()
-                    }
-                };
-                // marshal type ID for "key::secp256k1::txs::transfer::Input" or "platformvm::txs::StakeableLockIn"
-                packer.pack_u32(type_id_transferable_in)?;
-
-                match type_id_transferable_in {
-                    5 => {
-                        // "key::secp256k1::txs::transfer::Input"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        let transfer_input = transferable_input.transfer_input.clone().unwrap();
-
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(transfer_input.amount)?;
-
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer.pack_u32(transfer_input.sig_indices.len() as u32)?;
-                        for idx in transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    21 => {
-                        // "platformvm::txs::StakeableLockIn"
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        let stakeable_lock_in =
-                            transferable_input.stakeable_lock_in.clone().unwrap();
-
-                        // marshal "platformvm::txs::StakeableLockIn.locktime" field
-                        packer.pack_u64(stakeable_lock_in.locktime)?;
-
-                        // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
-                        // but embedded inline in the struct "StakeableLockIn"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        //
-                        // marshal "secp256k1fx.TransferInput.Amt" field
-                        packer.pack_u64(stakeable_lock_in.transfer_input.amount)?;
-                        //
-                        // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
-                        // but embedded inline in the struct "TransferInput"
-                        // so no need to encode type ID
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
-                        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
-                        packer
-                            .pack_u32(stakeable_lock_in.transfer_input.sig_indices.len() as u32)?;
-                        for idx in stakeable_lock_in.transfer_input.sig_indices.iter() {
-                            packer.pack_u32(*idx)?;
-                        }
-                    }
-                    _ => {
-                        return Err(Error::Other {
-                            message: format!(
-                                "unexpected type ID {} for TransferableInput",
-                                type_id_transferable_in
-                            ),
-                            retryable: false,
-                        })
-                    }
-                }
+        if let Some(outputs) = &self.transferable_outputs {
+            for output in outputs.iter() {
+                output.verify_syntax()?;
+            }
+            if !outputs.windows(2).all(|w| w[0] <= w[1]) {
+                return Err(Error::Other {
+                    message: "outputs not sorted".to_string(), // ref. "ErrOutputsNotSorted"
+                    retryable: false,
+                });
             }
-        } else {
-            packer.pack_u32(0_u32)?;
         }
 
-        // marshal "BaseTx.memo"
-        if self.memo.is_some() {
-            let memo = self.memo.as_ref().unwrap();
-            packer.pack_u32(memo.len() as u32)?;
-            packer.pack_bytes(memo)?;
-        } else {
-            packer.pack_u32(0_u32)?;
+        if let Some(inputs) = &self.transferable_inputs {
+            for input in inputs.iter() {
+                input.verify_syntax()?;
+            }
+            if !cmp_manager::is_sorted_and_unique(inputs) {
+                return Err(Error::Other {
+                    message: "inputs not sorted and unique".to_string(), // ref. "ErrInputsNotSortedUnique"
+                    retryable: false,
+                });
+            }
         }
 
-        Ok(packer)
+        Ok(())
     }
 }
 
+// "BaseTx.Metadata" is not serialize:"true" thus skipping serialization
+// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#BaseTx
+impl_linear_codec!(
+    Tx,
+    interface {
+        network_id,
+        blockchain_id,
+        transferable_outputs,
+        transferable_inputs,
+        memo,
+    }
+);
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::test_base_tx_serialization --exact --show-output
 /// ref. "avalanchego/vms/avm.TestBaseTxSerialization"
 #[test]
 fn test_base_tx_serialization() {
-    use crate::{ids::short, key};
+    use crate::{codec::linear::Unpack, ids::short, key};
 
     // ref. "avalanchego/vms/avm/vm_test.go"
     let test_key = key::secp256k1::private_key::Key::from_cb58(
@@ -474,6 +297,18 @@ fn test_base_tx_serialization() {
         &expected_unsigned_tx_bytes,
         &unsigned_tx_bytes
     ));
+
+    let packer =
+        packer::Packer::load_bytes_for_unpack(unsigned_tx_bytes.len() + 1024, &unsigned_tx_bytes);
+    assert_eq!(packer.unpack_u16().unwrap(), 0);
+    assert_eq!(packer.unpack_u32().unwrap(), Tx::type_id());
+    let parsed_tx = Tx::unpack_from(&packer).expect("failed to unpack unsigned_tx");
+    assert_eq!(packer.get_offset(), unsigned_tx_bytes.len());
+    assert_eq!(parsed_tx, unsigned_tx);
+    assert_eq!(
+        parsed_tx.transferable_inputs.clone().unwrap()[0].transfer_input,
+        unsigned_tx.transferable_inputs.clone().unwrap()[0].transfer_input
+    );
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Metadata>
@@ -509,6 +344,28 @@ impl Metadata {
         }
     }
 
+    /// Computes the metadata by packing the unsigned tx with the codec version
+    /// and its type ID, followed by the credentials.
+    /// ref. "avalanchego/vms/platformvm/txs.Tx.Initialize"
+    /// ref. "avalanchego/vms/avm/txs.Tx.Initialize"
+    pub fn compute<T: codec::linear::Pack>(
+        type_id: u32,
+        unsigned_tx: &T,
+        pack_creds: impl FnOnce(&packer::Packer) -> Result<()>,
+    ) -> Result<Self> {
+        let packer = codec::linear::pack_with_header(codec::VERSION, type_id, unsigned_tx)?;
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        pack_creds(&packer)?;
+        let tx_bytes_with_signatures = packer.take_bytes();
+
+        Ok(Self::new(
+            &tx_bytes_with_no_signature,
+            &tx_bytes_with_signatures,
+        ))
+    }
+
     pub fn verify(&self) -> Result<()> {
         if self.id.is_empty() {
             return Err(Error::Other {
@@ -519,3 +376,117 @@ impl Metadata {
         Ok(())
     }
 }
+
+/// Signs the hash of the unsigned tx bytes (prefixed with the codec version
+/// and its type ID) with each group of the signers (in case of multi-sig),
+/// and returns one "secp256k1fx.Credential" per group in the same order.
+/// ref. "avalanchego/vms/platformvm/txs.Tx.Sign"
+/// ref. "avalanchego/vms/avm/txs.Tx.SignSECP256K1Fx"
+pub async fn sign_credentials<U: codec::linear::Pack, T: key::secp256k1::SignOnly>(
+    type_id: u32,
+    unsigned_tx: &U,
+    signers: &[Vec<T>],
+) -> Result<Vec<key::secp256k1::txs::Credential>> {
+    let packer = codec::linear::pack_with_header(codec::VERSION, type_id, unsigned_tx)?;
+
+    // IMPORTANT: only hash the unsigned tx but not the credentials
+    let tx_bytes_hash = hash::sha256(packer.take_bytes());
+
+    let mut creds = Vec::with_capacity(signers.len());
+    for keys in signers.iter() {
+        let mut signatures = Vec::with_capacity(keys.len());
+        for k in keys.iter() {
+            let sig = k.sign_digest(&tx_bytes_hash).await?;
+            signatures.push(Vec::from(sig));
+        }
+        creds.push(key::secp256k1::txs::Credential { signatures });
+    }
+    Ok(creds)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::test_verify_syntax --exact --show-output
+#[test]
+fn test_verify_syntax() {
+    use crate::{ids::short, key, platformvm};
+
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[1])]);
+    let output = |amount: u64| transferable::Output {
+        asset_id: ids::Id::from_slice(&[1]),
+        transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+            amount,
+            owners.clone(),
+        )),
+        ..transferable::Output::default()
+    };
+    let input = |tx_id: u8, sig_indices: Vec<u32>| transferable::Input {
+        utxo_id: utxo::Id {
+            tx_id: ids::Id::from_slice(&[tx_id]),
+            ..utxo::Id::default()
+        },
+        asset_id: ids::Id::from_slice(&[1]),
+        transfer_input: Some(key::secp256k1::txs::transfer::Input::new(1, sig_indices)),
+        ..transferable::Input::default()
+    };
+
+    let tx = Tx {
+        transferable_outputs: Some(vec![output(1), output(2)]),
+        transferable_inputs: Some(vec![input(1, vec![0]), input(2, vec![0, 1])]),
+        memo: Some(vec![0; MAX_MEMO_SIZE]),
+        ..Tx::default()
+    };
+    assert!(tx.verify_syntax().is_ok());
+    assert!(Tx::default().verify_syntax().is_ok());
+
+    let invalid_txs = [
+        // memo too large
+        Tx {
+            memo: Some(vec![0; MAX_MEMO_SIZE + 1]),
+            ..tx.clone()
+        },
+        // unsorted outputs
+        Tx {
+            transferable_outputs: Some(vec![output(2), output(1)]),
+            ..tx.clone()
+        },
+        // output without value
+        Tx {
+            transferable_outputs: Some(vec![output(0)]),
+            ..tx.clone()
+        },
+        // output without the fx output
+        Tx {
+            transferable_outputs: Some(vec![transferable::Output::default()]),
+            ..tx.clone()
+        },
+        // unsorted inputs
+        Tx {
+            transferable_inputs: Some(vec![input(2, vec![0]), input(1, vec![0])]),
+            ..tx.clone()
+        },
+        // duplicate inputs
+        Tx {
+            transferable_inputs: Some(vec![input(1, vec![0]), input(1, vec![0])]),
+            ..tx.clone()
+        },
+        // unsorted sig indices
+        Tx {
+            transferable_inputs: Some(vec![input(1, vec![1, 0])]),
+            ..tx.clone()
+        },
+        // stakeable lock without locktime
+        Tx {
+            transferable_inputs: Some(vec![transferable::Input {
+                transfer_input: None,
+                stakeable_lock_in: Some(platformvm::txs::StakeableLockIn {
+                    locktime: 0,
+                    transfer_input: key::secp256k1::txs::transfer::Input::new(1, vec![0]),
+                }),
+                ..input(1, vec![0])
+            }]),
+            ..tx.clone()
+        },
+    ];
+    for invalid in invalid_txs.iter() {
+        assert!(invalid.verify_syntax().is_err());
+    }
+}
@@ -0,0 +1,612 @@
+//! Partially-signed transaction for offline multisig (similar to Bitcoin PSBT).
+//!
+//! The unsigned tx is created once (e.g., by the party that selects the UTXOs),
+//! saved to a file, then independently signed by each key holder, and merged.
+//! Once every signature slot is filled, the signed tx bytes can be issued
+//! (e.g., "platform.issueTx" or "avm.issueTx").
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::Path,
+};
+
+use crate::{
+    avm,
+    codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    errors::{Error, Result},
+    hash,
+    ids::{self, short},
+    key, packer, platformvm, txs,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Represents a signature to be provided by the address.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct SigSlot {
+    pub address: short::Id,
+    #[serde_as(as = "Option<Hex0xBytes>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Vec<u8>>,
+}
+
+impl SigSlot {
+    pub fn new(address: short::Id) -> Self {
+        Self {
+            address,
+            signature: None,
+        }
+    }
+}
+
+/// Represents the signature slots of a credential, one slot per signature index
+/// of the input (or the subnet authorization) in the same order.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct CredentialSlots {
+    /// Feature extension of the credential, which decides its type ID
+    /// (e.g., "nftfx" or "propertyfx" operations on the X-chain).
+    /// Empty for "secp256k1fx" credentials.
+    #[serde(default)]
+    pub fx_id: ids::Id,
+    /// Owners of the UTXO (or the subnet) that the credential authorizes,
+    /// for the cosigners to check the addresses and the threshold they sign for.
+    /// "None" if unknown (e.g., the UTXO imported from another chain).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owners: Option<key::secp256k1::txs::OutputOwners>,
+    pub slots: Vec<SigSlot>,
+}
+
+impl CredentialSlots {
+    pub fn new(addresses: Vec<short::Id>) -> Self {
+        Self {
+            fx_id: ids::Id::empty(),
+            owners: None,
+            slots: addresses.into_iter().map(SigSlot::new).collect(),
+        }
+    }
+
+    /// Returns the addresses whose signatures are still missing for this credential.
+    pub fn missing(&self) -> Vec<short::Id> {
+        self.slots
+            .iter()
+            .filter(|slot| slot.signature.is_none())
+            .map(|slot| slot.address.clone())
+            .collect()
+    }
+
+    /// Describes the unfilled slots with the owners of the credential, if any.
+    fn describe_missing(&self, index: usize) -> Option<String> {
+        let missing = self.missing();
+        if missing.is_empty() {
+            return None;
+        }
+        let owners = match &self.owners {
+            Some(owners) => format!(
+                "{}-of-{} owners {:?} (locktime {})",
+                owners.threshold,
+                owners.addresses.len(),
+                owners.addresses,
+                owners.locktime
+            ),
+            None => "unknown owners".to_string(),
+        };
+        Some(format!(
+            "credential {} for {} signed {} of {} signatures, missing {:?}",
+            index,
+            owners,
+            self.slots.len() - missing.len(),
+            self.slots.len(),
+            missing
+        ))
+    }
+}
+
+/// Represents the unsigned tx with the signature slots for each credential.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PartiallySignedTx {
+    /// Unsigned tx bytes with the codec version and the tx type ID.
+    #[serde_as(as = "Hex0xBytes")]
+    pub tx_bytes_with_no_signature: Vec<u8>,
+    pub credentials: Vec<CredentialSlots>,
+}
+
+impl PartiallySignedTx {
+    /// Creates a new partially-signed tx where "signers" lists
+    /// the addresses to sign each credential.
+    pub fn new(tx_bytes_with_no_signature: &[u8], signers: Vec<Vec<short::Id>>) -> Self {
+        Self {
+            tx_bytes_with_no_signature: Vec::from(tx_bytes_with_no_signature),
+            credentials: signers.into_iter().map(CredentialSlots::new).collect(),
+        }
+    }
+
+    /// Packs the unsigned tx with the codec version and its type ID
+    /// (e.g., "platformvm::txs::add_validator::Tx::type_id()").
+    pub fn from_unsigned<T: codec::linear::Pack>(
+        type_id: u32,
+        unsigned_tx: &T,
+        signers: Vec<Vec<short::Id>>,
+    ) -> Result<Self> {
+        let packer = codec::linear::pack_with_header(codec::VERSION, type_id, unsigned_tx)?;
+        Ok(Self::new(&packer.take_bytes(), signers))
+    }
+
+    /// Same as "from_unsigned" but with the slots of the signer addresses
+    /// (e.g., the signers returned by the wallet "spend" for each input).
+    pub fn from_signers<U: codec::linear::Pack, T: key::secp256k1::ReadOnly>(
+        type_id: u32,
+        unsigned_tx: &U,
+        signers: &[Vec<T>],
+    ) -> Result<Self> {
+        let mut addrs: Vec<Vec<short::Id>> = Vec::with_capacity(signers.len());
+        for keys in signers.iter() {
+            addrs.push(
+                keys.iter()
+                    .map(|k| k.short_address())
+                    .collect::<Result<Vec<short::Id>>>()?,
+            );
+        }
+        Self::from_unsigned(type_id, unsigned_tx, addrs)
+    }
+
+    /// Sets the fx ID of each credential in the same order
+    /// (e.g., "avm::txs::operation::Tx::credential_fx_ids").
+    #[must_use]
+    pub fn fx_ids(mut self, fx_ids: &[ids::Id]) -> Self {
+        for (cred, fx_id) in self.credentials.iter_mut().zip(fx_ids.iter()) {
+            cred.fx_id = *fx_id;
+        }
+        self
+    }
+
+    /// Sets the owners of each credential in the same order
+    /// (e.g., the owners of the UTXO each input spends).
+    #[must_use]
+    pub fn owners(mut self, owners: &[Option<key::secp256k1::txs::OutputOwners>]) -> Self {
+        for (cred, owners) in self.credentials.iter_mut().zip(owners.iter()) {
+            cred.owners = owners.clone();
+        }
+        self
+    }
+
+    /// Same as "owners" but looks up the owners of the UTXO that each credential
+    /// spends, in the same order of "utxo_ids". The owners of the UTXOs
+    /// not in the set are left unknown.
+    #[must_use]
+    pub fn owners_from_utxos(self, utxos: &txs::utxo::Set, utxo_ids: &[&txs::utxo::Id]) -> Self {
+        let owners: Vec<Option<key::secp256k1::txs::OutputOwners>> = utxo_ids
+            .iter()
+            .map(|utxo_id| utxos.get(utxo_id).and_then(|utxo| utxo.owners().cloned()))
+            .collect();
+        self.owners(&owners)
+    }
+
+    /// Decodes the unsigned P-chain tx (with no credential) for the signers
+    /// to inspect what they sign. The tx ID of the decoded tx is not final.
+    pub fn unsigned_platformvm_tx(&self) -> Result<platformvm::txs::Transaction> {
+        platformvm::txs::Transaction::unpack(&self.with_no_credential())
+    }
+
+    /// Decodes the unsigned X-chain tx (with no credential) for the signers
+    /// to inspect what they sign. The tx ID of the decoded tx is not final.
+    pub fn unsigned_avm_tx(&self) -> Result<avm::txs::Transaction> {
+        avm::txs::Transaction::unpack(&self.with_no_credential())
+    }
+
+    /// Returns the unsigned tx bytes followed by the zero credential length.
+    fn with_no_credential(&self) -> Vec<u8> {
+        let mut d = self.tx_bytes_with_no_signature.clone();
+        d.extend_from_slice(&0_u32.to_be_bytes());
+        d
+    }
+
+    fn slots(&self) -> impl Iterator<Item = &SigSlot> {
+        self.credentials.iter().flat_map(|cred| cred.slots.iter())
+    }
+
+    /// Returns the digest that every signer signs.
+    pub fn hash(&self) -> Vec<u8> {
+        hash::sha256(&self.tx_bytes_with_no_signature)
+    }
+
+    /// Signs every empty slot that belongs to the signer address,
+    /// and returns the number of signatures added.
+    pub async fn sign<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly>(
+        &mut self,
+        signer: &T,
+    ) -> Result<usize> {
+        let address = signer.short_address()?;
+        let digest = self.hash();
+
+        let mut signed = 0_usize;
+        for slot in self
+            .credentials
+            .iter_mut()
+            .flat_map(|cred| cred.slots.iter_mut())
+        {
+            if slot.address != address || slot.signature.is_some() {
+                continue;
+            }
+            let sig = signer.sign_digest(&digest).await?;
+            slot.signature = Some(Vec::from(sig));
+            signed += 1;
+        }
+        Ok(signed)
+    }
+
+    /// Adds the signatures from the other copy of the same unsigned tx
+    /// (e.g., signed by another key holder). Each signature is verified
+    /// against its slot address.
+    pub fn merge(&mut self, other: &PartiallySignedTx) -> Result<()> {
+        if self.tx_bytes_with_no_signature != other.tx_bytes_with_no_signature {
+            return Err(Error::Other {
+                message: "cannot merge partially-signed txs with different unsigned txs"
+                    .to_string(),
+                retryable: false,
+            });
+        }
+        let same_slots = self.credentials.len() == other.credentials.len()
+            && self
+                .credentials
+                .iter()
+                .zip(other.credentials.iter())
+                .all(|(a, b)| {
+                    a.fx_id == b.fx_id
+                        && a.owners == b.owners
+                        && a.slots.len() == b.slots.len()
+                        && a.slots
+                            .iter()
+                            .zip(b.slots.iter())
+                            .all(|(x, y)| x.address == y.address)
+                });
+        if !same_slots {
+            return Err(Error::Other {
+                message: "cannot merge partially-signed txs with different signers".to_string(),
+                retryable: false,
+            });
+        }
+
+        let digest = self.hash();
+        for (slot, other_slot) in self
+            .credentials
+            .iter_mut()
+            .flat_map(|cred| cred.slots.iter_mut())
+            .zip(other.slots())
+        {
+            let sig = match &other_slot.signature {
+                Some(sig) => sig,
+                None => continue,
+            };
+            if slot.signature.is_some() {
+                // both signed, signatures are deterministic (RFC6979)
+                // but verify anyway in case the other copy was tampered
+                if slot.signature.as_ref() != Some(sig) {
+                    verify_signature(&digest, sig, &slot.address)?;
+                }
+                continue;
+            }
+            verify_signature(&digest, sig, &slot.address)?;
+            slot.signature = Some(sig.clone());
+        }
+        Ok(())
+    }
+
+    /// Returns the addresses whose signatures are still missing.
+    pub fn missing(&self) -> Vec<short::Id> {
+        let mut addrs: Vec<short::Id> = Vec::new();
+        for slot in self.slots() {
+            if slot.signature.is_none() && !addrs.contains(&slot.address) {
+                addrs.push(slot.address.clone());
+            }
+        }
+        addrs
+    }
+
+    /// Describes each credential with the unfilled slots, with the owners
+    /// it authorizes and the signatures provided so far.
+    pub fn missing_by_credential(&self) -> Vec<String> {
+        self.credentials
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cred)| cred.describe_missing(i))
+            .collect()
+    }
+
+    /// Returns "true" if every signature slot is filled.
+    pub fn is_complete(&self) -> bool {
+        self.slots().all(|slot| slot.signature.is_some())
+    }
+
+    /// Assembles the signed tx with one credential per slot list, packed with
+    /// the type ID of its fx, and returns the metadata with the tx ID and the
+    /// signed tx bytes.
+    /// ref. "avalanchego/vms/platformvm/txs.Tx.Sign"
+    /// ref. "avalanchego/vms/avm/txs.Tx.SignNFTFx"
+    pub fn finalize(&self) -> Result<txs::Metadata> {
+        if !self.is_complete() {
+            return Err(Error::Other {
+                message: format!(
+                    "missing signatures ({})",
+                    self.missing_by_credential().join("; ")
+                ),
+                retryable: false,
+            });
+        }
+
+        // "secp256k1fx" credentials with the empty fx ID are the same on both
+        // chains, the others are resolved to their own type IDs by "fx::Credential"
+        let creds: Vec<avm::txs::fx::Credential> = self
+            .credentials
+            .iter()
+            .map(|cred| avm::txs::fx::Credential {
+                fx_id: cred.fx_id,
+                cred: key::secp256k1::txs::Credential::new(
+                    cred.slots
+                        .iter()
+                        .map(|slot| slot.signature.clone().unwrap())
+                        .collect(),
+                ),
+            })
+            .collect();
+
+        let packer = packer::Packer::load_bytes_for_pack(
+            self.tx_bytes_with_no_signature.len() + 1024 * 1024,
+            &self.tx_bytes_with_no_signature,
+        );
+        packer.pack_u32(creds.len() as u32)?;
+        for cred in creds.iter() {
+            codec::linear::Pack::pack_to(cred, &packer)?;
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+
+        Ok(txs::Metadata::new(
+            &self.tx_bytes_with_no_signature,
+            &tx_bytes_with_signatures,
+        ))
+    }
+
+    pub fn load(file_path: &str) -> Result<Self> {
+        log::info!("loading partially-signed tx from {}", file_path);
+
+        if !Path::new(file_path).exists() {
+            return Err(Error::Other {
+                message: format!("file {} does not exists", file_path),
+                retryable: false,
+            });
+        }
+
+        let f = File::open(file_path).map_err(|e| Error::Other {
+            message: format!("failed to open {} ({})", file_path, e),
+            retryable: false,
+        })?;
+        serde_json::from_reader(f).map_err(|e| Error::Other {
+            message: format!("failed serde_json::from_reader {}", e),
+            retryable: false,
+        })
+    }
+
+    pub fn sync(&self, file_path: &str) -> std::io::Result<()> {
+        log::info!("syncing partially-signed tx to '{}'", file_path);
+        let path = Path::new(file_path);
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let d = serde_json::to_vec_pretty(self).map_err(|e| {
+            std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("failed to serialize JSON {}", e),
+            )
+        })?;
+
+        let mut f = File::create(file_path)?;
+        f.write_all(&d)?;
+
+        Ok(())
+    }
+}
+
+/// Verifies the signature recovers to the expected address.
+fn verify_signature(digest: &[u8], sig: &[u8], address: &short::Id) -> Result<()> {
+    let pubkey = key::secp256k1::public_key::Key::from_signature(digest, sig)?;
+    if pubkey.to_short_id()? != *address {
+        return Err(Error::Other {
+            message: format!("signature does not match the signer {}", address),
+            retryable: false,
+        });
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::partial::test_partially_signed_tx --exact --show-output
+#[test]
+fn test_partially_signed_tx() {
+    use crate::platformvm;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let key1 = key::secp256k1::private_key::Key::generate().unwrap();
+    let key2 = key::secp256k1::private_key::Key::generate().unwrap();
+    let key3 = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr1 = key1.to_public_key().to_short_id().unwrap();
+    let addr2 = key2.to_public_key().to_short_id().unwrap();
+
+    let mut tx = platformvm::txs::create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: 10,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id: ids::Id::from_slice(&[1; 32]),
+                    ..txs::utxo::Id::default()
+                },
+                asset_id: ids::Id::from_slice(&[2; 32]),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input::new(1000, vec![0, 1])),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        owner: key::secp256k1::txs::OutputOwners::new(0, 1, &[short::Id::from_slice(&[1; 20])]),
+        ..platformvm::txs::create_subnet::Tx::default()
+    };
+
+    // 2-of-2 multisig input
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 2, &[addr1.clone(), addr2.clone()]);
+    let psbt = PartiallySignedTx::from_unsigned(
+        platformvm::txs::create_subnet::Tx::type_id(),
+        &tx,
+        vec![vec![addr1.clone(), addr2.clone()]],
+    )
+    .unwrap()
+    .owners(&[Some(owners.clone())]);
+    assert!(!psbt.is_complete());
+    assert_eq!(psbt.missing(), vec![addr1.clone(), addr2.clone()]);
+    assert!(psbt.finalize().is_err());
+
+    // signers can inspect the unsigned tx and the owners they authorize
+    match psbt.unsigned_platformvm_tx().unwrap() {
+        platformvm::txs::Transaction::CreateSubnet(decoded) => {
+            assert_eq!(decoded.base_tx.network_id, tx.base_tx.network_id);
+            assert_eq!(decoded.owner, tx.owner);
+            assert_eq!(
+                decoded.base_tx.transferable_inputs,
+                tx.base_tx.transferable_inputs
+            );
+        }
+        other => panic!("unexpected tx {:?}", other),
+    }
+    assert_eq!(psbt.credentials[0].owners, Some(owners.clone()));
+
+    // each party signs its own copy
+    let mut psbt1 = psbt.clone();
+    assert_eq!(ab!(psbt1.sign(&key1)).unwrap(), 1);
+    assert_eq!(ab!(psbt1.sign(&key3)).unwrap(), 0);
+    assert_eq!(psbt1.missing(), vec![addr2.clone()]);
+    assert_eq!(
+        psbt1.missing_by_credential(),
+        vec![format!(
+            "credential 0 for 2-of-2 owners {:?} (locktime 0) signed 1 of 2 signatures, missing {:?}",
+            owners.addresses,
+            vec![addr2.clone()]
+        )]
+    );
+
+    let tmp_path = random_manager::tmp_path(10, Some(".json")).unwrap();
+    let mut psbt2 = psbt.clone();
+    ab!(psbt2.sign(&key2)).unwrap();
+    psbt2.sync(&tmp_path).unwrap();
+    let psbt2 = PartiallySignedTx::load(&tmp_path).unwrap();
+    fs::remove_file(&tmp_path).unwrap();
+
+    psbt1.merge(&psbt2).unwrap();
+    assert!(psbt1.is_complete());
+
+    // same as signing with all keys at once
+    let metadata = psbt1.finalize().unwrap();
+    ab!(tx.sign(vec![vec![key1.clone(), key2.clone()]])).unwrap();
+    assert_eq!(tx.base_tx.metadata.clone().unwrap(), metadata);
+    let parsed =
+        platformvm::txs::create_subnet::Tx::unpack(&metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed.tx_id(), metadata.id);
+
+    // signature from the wrong key
+    let mut tampered = psbt.clone();
+    tampered.credentials[0].slots[1].signature = psbt1.credentials[0].slots[0].signature.clone();
+    let mut merged = psbt.clone();
+    assert!(merged.merge(&tampered).is_err());
+
+    // different owners
+    let other = psbt.clone().owners(&[None]);
+    assert!(merged.merge(&other).is_err());
+
+    // owners looked up from the UTXOs that each credential spends
+    let utxo_id = tx.base_tx.transferable_inputs.as_ref().unwrap()[0]
+        .utxo_id
+        .clone();
+    let utxos = txs::utxo::Set::new(vec![txs::utxo::Utxo {
+        utxo_id: utxo_id.clone(),
+        transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+            1000,
+            owners.clone(),
+        )),
+        ..txs::utxo::Utxo::default()
+    }]);
+    let looked_up = psbt
+        .clone()
+        .owners(&[None])
+        .owners_from_utxos(&utxos, &[&utxo_id]);
+    assert_eq!(looked_up, psbt);
+
+    // different unsigned tx
+    let other = PartiallySignedTx::new(&[0, 0], vec![vec![addr1]]);
+    assert!(merged.merge(&other).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::partial::test_partially_signed_tx_fx_ids --exact --show-output
+#[test]
+fn test_partially_signed_tx_fx_ids() {
+    use crate::avm;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let key1 = key::secp256k1::private_key::Key::generate().unwrap();
+    let key2 = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr1 = key1.to_public_key().to_short_id().unwrap();
+    let addr2 = key2.to_public_key().to_short_id().unwrap();
+
+    let tx = avm::txs::Tx::new(txs::Tx {
+        network_id: 10,
+        ..txs::Tx::default()
+    });
+
+    // "secp256k1fx" credential for an input, then "nftfx" and "propertyfx" operations
+    let mut psbt = PartiallySignedTx::from_signers(
+        avm::txs::Tx::type_id(),
+        &tx,
+        &[vec![key1.clone()], vec![key2.clone()], vec![key1.clone()]],
+    )
+    .unwrap()
+    .fx_ids(&[
+        ids::Id::empty(),
+        avm::nftfx::fx_id(),
+        avm::propertyfx::fx_id(),
+    ]);
+    assert_eq!(psbt.credentials[0].slots, vec![SigSlot::new(addr1.clone())]);
+    assert_eq!(psbt.credentials[1].slots, vec![SigSlot::new(addr2)]);
+    assert_eq!(psbt.credentials[2].slots, vec![SigSlot::new(addr1)]);
+
+    // slots must be merged only with the same fx IDs
+    let other = psbt.clone().fx_ids(&[ids::Id::empty(), ids::Id::empty()]);
+    assert!(psbt.clone().merge(&other).is_err());
+
+    match psbt.unsigned_avm_tx().unwrap() {
+        avm::txs::Transaction::Base(decoded) => assert_eq!(decoded.base_tx.network_id, 10),
+        other => panic!("unexpected tx {:?}", other),
+    }
+
+    assert_eq!(ab!(psbt.sign(&key1)).unwrap(), 2);
+    assert_eq!(ab!(psbt.sign(&key2)).unwrap(), 1);
+    let metadata = psbt.finalize().unwrap();
+
+    // each credential is packed with the type ID of its fx
+    let parsed = avm::txs::Tx::unpack(&metadata.tx_bytes_with_signatures).unwrap();
+    assert_eq!(parsed.tx_id(), metadata.id);
+    let fx_ids: Vec<ids::Id> = parsed.fx_creds.iter().map(|cred| cred.fx_id).collect();
+    assert_eq!(
+        fx_ids,
+        vec![
+            ids::Id::empty(),
+            avm::nftfx::fx_id(),
+            avm::propertyfx::fx_id()
+        ]
+    );
+}
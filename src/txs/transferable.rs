@@ -1,6 +1,10 @@
 use std::cmp::Ordering;
 
-use crate::{ids, key, platformvm, txs};
+use crate::{
+    codec::linear::{self, Pack, Unpack},
+    errors::{Error, Result},
+    ids, key, packer, platformvm, txs,
+};
 use serde::{Deserialize, Serialize};
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
@@ -44,6 +48,22 @@ impl Output {
             stakeable_lock_out: None,
         }
     }
+
+    /// ref. "avalanchego/vms/components/avax.TransferableOutput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        match (&self.transfer_output, &self.stakeable_lock_out) {
+            (Some(out), None) => out.verify_syntax(),
+            (None, Some(lock_out)) => lock_out.verify_syntax(),
+            (None, None) => Err(Error::Other {
+                message: "nil transferable feature extension output is not valid".to_string(), // ref. "errNilTransferableFxOutput"
+                retryable: false,
+            }),
+            (Some(_), Some(_)) => Err(Error::Other {
+                message: "unexpected Somes in transfer_output and stakeable_lock_out".to_string(),
+                retryable: false,
+            }),
+        }
+    }
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableOutputs>
@@ -123,6 +143,65 @@ impl PartialEq for Output {
     }
 }
 
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput>
+impl Pack for Output {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        // "TransferableOutput.Asset" is embedded inline
+        // fx_id is serialize:"false" thus skipping serialization
+        self.asset_id.pack_to(packer)?;
+
+        // "TransferableOutput.Out" is an interface
+        // thus prefixed with the type ID
+        if let Some(transfer_output) = &self.transfer_output {
+            linear::pack_interface(transfer_output, packer)
+        } else if let Some(stakeable_lock_out) = &self.stakeable_lock_out {
+            linear::pack_interface(stakeable_lock_out, packer)
+        } else {
+            Err(Error::Other {
+                message:
+                    "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out"
+                        .to_string(),
+                retryable: false,
+            })
+        }
+    }
+}
+
+impl Unpack for Output {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let asset_id = ids::Id::unpack_from(packer)?;
+
+        let type_id_transferable_out = packer.unpack_u32()?;
+        if linear::is_type::<key::secp256k1::txs::transfer::Output>(
+            packer.registry(),
+            type_id_transferable_out,
+        ) {
+            Ok(Self {
+                asset_id,
+                transfer_output: Some(Unpack::unpack_from(packer)?),
+                ..Self::default()
+            })
+        } else if linear::is_type::<platformvm::txs::StakeableLockOut>(
+            packer.registry(),
+            type_id_transferable_out,
+        ) {
+            Ok(Self {
+                asset_id,
+                stakeable_lock_out: Some(Unpack::unpack_from(packer)?),
+                ..Self::default()
+            })
+        } else {
+            Err(Error::Other {
+                message: format!(
+                    "unexpected type ID {} for TransferableOutput",
+                    type_id_transferable_out
+                ),
+                retryable: false,
+            })
+        }
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableOutputs>
 /// ref. "avalanchego/vms/components/avax.TestTransferableOutputSorting"
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::transferable::test_sort_transferable_outputs --exact --show-output
@@ -441,6 +520,22 @@ impl Input {
             stakeable_lock_in: None,
         }
     }
+
+    /// ref. "avalanchego/vms/components/avax.TransferableInput.Verify"
+    pub fn verify_syntax(&self) -> Result<()> {
+        match (&self.transfer_input, &self.stakeable_lock_in) {
+            (Some(input), None) => input.verify_syntax(),
+            (None, Some(lock_in)) => lock_in.verify_syntax(),
+            (None, None) => Err(Error::Other {
+                message: "nil transferable feature extension input is not valid".to_string(), // ref. "errNilTransferableFxInput"
+                retryable: false,
+            }),
+            (Some(_), Some(_)) => Err(Error::Other {
+                message: "unexpected Somes in transfer_input and stakeable_lock_in".to_string(),
+                retryable: false,
+            }),
+        }
+    }
 }
 
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputs>
@@ -469,6 +564,69 @@ impl PartialEq for Input {
     }
 }
 
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput>
+impl Pack for Input {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        // "TransferableInput.UTXOID" and "TransferableInput.Asset" are embedded inline
+        // fx_id is serialize:"false" thus skipping serialization
+        self.utxo_id.pack_to(packer)?;
+        self.asset_id.pack_to(packer)?;
+
+        // "TransferableInput.In" is an interface
+        // thus prefixed with the type ID
+        if let Some(transfer_input) = &self.transfer_input {
+            linear::pack_interface(transfer_input, packer)
+        } else if let Some(stakeable_lock_in) = &self.stakeable_lock_in {
+            linear::pack_interface(stakeable_lock_in, packer)
+        } else {
+            Err(Error::Other {
+                message:
+                    "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in"
+                        .to_string(),
+                retryable: false,
+            })
+        }
+    }
+}
+
+impl Unpack for Input {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        let utxo_id = txs::utxo::Id::unpack_from(packer)?;
+        let asset_id = ids::Id::unpack_from(packer)?;
+
+        let type_id_transferable_in = packer.unpack_u32()?;
+        if linear::is_type::<key::secp256k1::txs::transfer::Input>(
+            packer.registry(),
+            type_id_transferable_in,
+        ) {
+            Ok(Self {
+                utxo_id,
+                asset_id,
+                transfer_input: Some(Unpack::unpack_from(packer)?),
+                ..Self::default()
+            })
+        } else if linear::is_type::<platformvm::txs::StakeableLockIn>(
+            packer.registry(),
+            type_id_transferable_in,
+        ) {
+            Ok(Self {
+                utxo_id,
+                asset_id,
+                stakeable_lock_in: Some(Unpack::unpack_from(packer)?),
+                ..Self::default()
+            })
+        } else {
+            Err(Error::Other {
+                message: format!(
+                    "unexpected type ID {} for TransferableInput",
+                    type_id_transferable_in
+                ),
+                retryable: false,
+            })
+        }
+    }
+}
+
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputs>
 /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputsWithSigners>
 /// ref. "avalanchego/vms/components/avax.TestTransferableInputSorting"
@@ -1,11 +1,20 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
 
 use crate::{
-    codec,
+    avm,
+    codec::{
+        self,
+        linear::{self, Pack, Unpack},
+    },
     errors::{Error, Result},
     formatting,
     ids::{self, short},
-    key, packer, platformvm,
+    impl_linear_codec, key,
+    packer::{self, reader::PackerReader},
+    platformvm, txs,
 };
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +61,12 @@ impl Id {
     }
 }
 
+// "symbol" and "id" are serialize:"false"
+impl_linear_codec!(Id {
+    tx_id,
+    output_index,
+});
+
 impl Ord for Id {
     fn cmp(&self, other: &Id) -> Ordering {
         self.tx_id
@@ -149,9 +164,29 @@ pub struct Utxo {
     /// "*platformvm.StakeableLockOut" which embeds "*secp256k1fx.TransferOutput"
     /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut>
     ///
+    /// "*secp256k1fx.MintOutput" for the X-chain asset minting right
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOutput>
+    ///
+    /// "*nftfx.MintOutput" for the X-chain NFT group minting right
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOutput>
+    ///
+    /// "*nftfx.TransferOutput" for the X-chain NFT
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOutput>
+    ///
+    /// "*propertyfx.MintOutput" for the X-chain property minting right
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOutput>
+    ///
+    /// "*propertyfx.OwnedOutput" for the X-chain property
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#OwnedOutput>
+    ///
     /// MUST: only one of the following can be "Some".
     pub transfer_output: Option<key::secp256k1::txs::transfer::Output>,
     pub stakeable_lock_out: Option<platformvm::txs::StakeableLockOut>,
+    pub mint_output: Option<key::secp256k1::txs::mint::Output>,
+    pub nft_mint_output: Option<avm::nftfx::MintOutput>,
+    pub nft_transfer_output: Option<avm::nftfx::TransferOutput>,
+    pub property_mint_output: Option<avm::propertyfx::MintOutput>,
+    pub property_owned_output: Option<avm::propertyfx::OwnedOutput>,
 }
 
 impl Default for Utxo {
@@ -167,6 +202,11 @@ impl Utxo {
             asset_id: ids::Id::empty(),
             transfer_output: None,
             stakeable_lock_out: None,
+            mint_output: None,
+            nft_mint_output: None,
+            nft_transfer_output: None,
+            property_mint_output: None,
+            property_owned_output: None,
         }
     }
 
@@ -201,58 +241,150 @@ impl Utxo {
         // codec version
         // ref. "avalanchego/codec.manager.Marshal"
         packer.pack_u16(codec_version)?;
+        self.pack_to(&packer)?;
 
-        packer.pack_bytes(self.utxo_id.tx_id.as_ref())?;
-        packer.pack_u32(self.utxo_id.output_index)?;
+        Ok(packer)
+    }
 
-        packer.pack_bytes(self.asset_id.as_ref())?;
+    /// Parses raw bytes to "Utxo".
+    /// It assumes the data are already decoded from "hex".
+    /// The fields are read in place with the borrowed reader,
+    /// without copying each field into an intermediate "Vec".
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXO>
+    pub fn unpack(d: &[u8]) -> Result<Self> {
+        let mut reader = PackerReader::new(d);
 
+        let _codec_version = reader.unpack_u16()?;
+        reader.unpack_utxo()
+    }
+
+    /// Returns the "*secp256k1fx.TransferOutput", which is the inner output
+    /// for "*platformvm.StakeableLockOut".
+    pub fn output(&self) -> Option<&key::secp256k1::txs::transfer::Output> {
         if let Some(out) = &self.transfer_output {
-            packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
-            packer.pack_u64(out.amount)?;
+            return Some(out);
+        }
+        self.stakeable_lock_out
+            .as_ref()
+            .map(|out| &out.transfer_output)
+    }
 
-            packer.pack_u64(out.output_owners.locktime)?;
-            packer.pack_u32(out.output_owners.threshold)?;
+    pub fn amount(&self) -> u64 {
+        self.output().map_or(0, |out| out.amount)
+    }
 
-            packer.pack_u32(out.output_owners.addresses.len() as u32)?;
-            for addr in out.output_owners.addresses.iter() {
-                packer.pack_bytes(addr.as_ref())?;
-            }
-        } else if let Some(lock_out) = &self.stakeable_lock_out {
-            packer.pack_u32(platformvm::txs::StakeableLockOut::type_id())?;
-            packer.pack_u64(lock_out.locktime)?;
+    /// Returns the owners of the output, including the minting rights, the NFTs and the properties.
+    pub fn owners(&self) -> Option<&key::secp256k1::txs::OutputOwners> {
+        if let Some(out) = &self.mint_output {
+            return Some(&out.output_owners);
+        }
+        if let Some(out) = &self.nft_mint_output {
+            return Some(&out.output_owners);
+        }
+        if let Some(out) = &self.nft_transfer_output {
+            return Some(&out.output_owners);
+        }
+        if let Some(out) = &self.property_mint_output {
+            return Some(&out.output_owners);
+        }
+        if let Some(out) = &self.property_owned_output {
+            return Some(&out.output_owners);
+        }
+        self.output().map(|out| &out.output_owners)
+    }
 
-            packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
-            packer.pack_u64(lock_out.transfer_output.amount)?;
+    /// Returns the lock state at the given unix timestamp.
+    /// ref. "avalanchego/vms/platformvm.Service.GetBalance"
+    pub fn lock_state(&self, now_unix: u64) -> LockState {
+        let out = match self.output() {
+            Some(out) => out,
+            None => return LockState::LockedNotStakeable,
+        };
+        if out.output_owners.locktime > now_unix {
+            return LockState::LockedNotStakeable;
+        }
+        match &self.stakeable_lock_out {
+            Some(lock_out) if lock_out.locktime > now_unix => LockState::LockedStakeable,
+            _ => LockState::Unlocked,
+        }
+    }
+}
 
-            packer.pack_u64(lock_out.transfer_output.output_owners.locktime)?;
-            packer.pack_u32(lock_out.transfer_output.output_owners.threshold)?;
+impl PackerReader<'_> {
+    fn unpack_transfer_output(&mut self) -> Result<key::secp256k1::txs::transfer::Output> {
+        let amount = self.unpack_u64()?;
+        let locktime = self.unpack_u64()?;
+        let threshold = self.unpack_u32()?;
 
-            packer.pack_u32(lock_out.transfer_output.output_owners.addresses.len() as u32)?;
-            for addr in lock_out.transfer_output.output_owners.addresses.iter() {
-                packer.pack_bytes(addr.as_ref())?;
-            }
-        }
+        let addresses_len = self.unpack_u32()? as usize;
+        let d = self.unpack_bytes(addresses_len * ids::short::LEN)?;
+        let addresses = d
+            .chunks_exact(ids::short::LEN)
+            .map(ids::short::Id::from_slice)
+            .collect();
 
-        Ok(packer)
+        Ok(key::secp256k1::txs::transfer::Output {
+            amount,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime,
+                threshold,
+                addresses,
+            },
+        })
     }
 
-    /// Parses raw bytes to "Utxo".
-    /// It assumes the data are already decoded from "hex".
-    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXO>
-    pub fn unpack(d: &[u8]) -> Result<Self> {
-        let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
+    fn unpack_mint_output(&mut self) -> Result<key::secp256k1::txs::mint::Output> {
+        let locktime = self.unpack_u64()?;
+        let threshold = self.unpack_u32()?;
 
-        let _codec_version = packer.unpack_u16()?;
+        let addresses_len = self.unpack_u32()? as usize;
+        let d = self.unpack_bytes(addresses_len * ids::short::LEN)?;
+        let addresses = d
+            .chunks_exact(ids::short::LEN)
+            .map(ids::short::Id::from_slice)
+            .collect();
 
-        // must unpack in the order of struct
-        let tx_id_bytes = packer.unpack_bytes(ids::LEN)?;
-        let tx_id = ids::Id::from_slice(&tx_id_bytes);
+        Ok(key::secp256k1::txs::mint::Output {
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime,
+                threshold,
+                addresses,
+            },
+        })
+    }
 
-        let output_index = packer.unpack_u32()?;
+    fn unpack_nft_mint_output(&mut self) -> Result<avm::nftfx::MintOutput> {
+        let group_id = self.unpack_u32()?;
+        let mint_output = self.unpack_mint_output()?;
+        Ok(avm::nftfx::MintOutput::new(
+            group_id,
+            mint_output.output_owners,
+        ))
+    }
 
-        let asset_id_bytes = packer.unpack_bytes(ids::LEN)?;
-        let asset_id = ids::Id::from_slice(&asset_id_bytes);
+    fn unpack_nft_transfer_output(&mut self) -> Result<avm::nftfx::TransferOutput> {
+        let group_id = self.unpack_u32()?;
+        let payload_len = self.unpack_u32()? as usize;
+        let payload = self.unpack_bytes(payload_len)?.to_vec();
+        let mint_output = self.unpack_mint_output()?;
+        Ok(avm::nftfx::TransferOutput::new(
+            group_id,
+            payload,
+            mint_output.output_owners,
+        ))
+    }
+
+    /// Unpacks the "Utxo" without the codec version.
+    /// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXO>
+    pub fn unpack_utxo(&mut self) -> Result<Utxo> {
+        let tx_id = ids::Id::from_slice(self.unpack_bytes(ids::LEN)?);
+        let output_index = self.unpack_u32()?;
+        let asset_id = ids::Id::from_slice(self.unpack_bytes(ids::LEN)?);
+        let utxo_id = Id {
+            tx_id,
+            output_index,
+            ..Id::default()
+        };
 
         // "Out verify.State" is an interface
         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXO
@@ -262,89 +394,162 @@ impl Utxo {
         //
         // "*platformvm.StakeableLockOut" which embeds "*secp256k1fx.TransferOutput"-- type ID 22
         // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-        let type_id_verify_state = packer.unpack_u32()?;
-        match type_id_verify_state {
-            7 => {}
-            22 => {}
-            _ => {
+        //
+        // "*secp256k1fx.MintOutput" -- type ID 6 in the X-chain codec
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#MintOutput
+        //
+        // "*nftfx.MintOutput" and "*nftfx.TransferOutput" -- type ID 10 and 11 in the X-chain codec
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#MintOutput
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#TransferOutput
+        //
+        // "*propertyfx.MintOutput" and "*propertyfx.OwnedOutput" -- type ID 15 and 16 in the X-chain codec
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#MintOutput
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#OwnedOutput
+        let type_id_verify_state = self.unpack_u32()?;
+        if linear::is_type::<key::secp256k1::txs::transfer::Output>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                transfer_output: Some(self.unpack_transfer_output()?),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<platformvm::txs::StakeableLockOut>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            let locktime = self.unpack_u64()?;
+
+            // "StakeableLockOut.TransferableOut" is an interface
+            let type_id_transfer_output = self.unpack_u32()?;
+            if !linear::is_type::<key::secp256k1::txs::transfer::Output>(
+                self.registry(),
+                type_id_transfer_output,
+            ) {
                 return Err(Error::Other {
-                    message: format!("unknown type ID for verify.State {}", type_id_verify_state),
+                    message: format!(
+                        "unexpected type ID {} (expected type {})",
+                        type_id_transfer_output,
+                        key::secp256k1::txs::transfer::Output::type_name()
+                    ),
                     retryable: false,
-                })
+                });
             }
-        }
-
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-        let stakeable_lock_out = {
-            if type_id_verify_state == 22 {
-                let stakeable_lock_out_locktime = packer.unpack_u64()?;
 
-                // "*secp256k1fx.TransferOutput" -- type ID 7
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                let _type_id_secp256k1fx_transfer_output = packer.unpack_u32()?;
-
-                let mut so = platformvm::txs::StakeableLockOut::default();
-                so.locktime = stakeable_lock_out_locktime;
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+                    locktime,
+                    transfer_output: self.unpack_transfer_output()?,
+                }),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<key::secp256k1::txs::mint::Output>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                mint_output: Some(self.unpack_mint_output()?),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<avm::nftfx::MintOutput>(self.registry(), type_id_verify_state) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                nft_mint_output: Some(self.unpack_nft_mint_output()?),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<avm::nftfx::TransferOutput>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                nft_transfer_output: Some(self.unpack_nft_transfer_output()?),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<avm::propertyfx::MintOutput>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                property_mint_output: Some(avm::propertyfx::MintOutput::new(
+                    self.unpack_mint_output()?.output_owners,
+                )),
+                ..Utxo::default()
+            })
+        } else if linear::is_type::<avm::propertyfx::OwnedOutput>(
+            self.registry(),
+            type_id_verify_state,
+        ) {
+            Ok(Utxo {
+                utxo_id,
+                asset_id,
+                property_owned_output: Some(avm::propertyfx::OwnedOutput::new(
+                    self.unpack_mint_output()?.output_owners,
+                )),
+                ..Utxo::default()
+            })
+        } else {
+            Err(Error::Other {
+                message: format!("unknown type ID for verify.State {}", type_id_verify_state),
+                retryable: false,
+            })
+        }
+    }
+}
 
-                Some(so)
-            } else {
-                None
-            }
-        };
+/// ref. <https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXO>
+impl Pack for Utxo {
+    fn pack_to(&self, packer: &packer::Packer) -> Result<()> {
+        // "UTXO.UTXOID" and "UTXO.Asset" are embedded inline
+        self.utxo_id.pack_to(packer)?;
+        self.asset_id.pack_to(packer)?;
 
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-        let amount = packer.unpack_u64()?;
-        let locktime = packer.unpack_u64()?;
-        let threshold = packer.unpack_u32()?;
-        let addr_len = packer.unpack_u32()?;
-        let mut addresses: Vec<short::Id> = Vec::new();
-        for _ in 0..addr_len {
-            let b = packer.unpack_bytes(short::LEN)?;
-            addresses.push(short::Id::from_slice(&b));
+        // "Out verify.State" is an interface thus prefixed with the type ID
+        if let Some(out) = &self.transfer_output {
+            linear::pack_interface(out, packer)
+        } else if let Some(lock_out) = &self.stakeable_lock_out {
+            linear::pack_interface(lock_out, packer)
+        } else if let Some(mint_out) = &self.mint_output {
+            linear::pack_interface(mint_out, packer)
+        } else if let Some(nft_mint_out) = &self.nft_mint_output {
+            linear::pack_interface(nft_mint_out, packer)
+        } else if let Some(nft_transfer_out) = &self.nft_transfer_output {
+            linear::pack_interface(nft_transfer_out, packer)
+        } else if let Some(property_mint_out) = &self.property_mint_output {
+            linear::pack_interface(property_mint_out, packer)
+        } else if let Some(property_owned_out) = &self.property_owned_output {
+            linear::pack_interface(property_owned_out, packer)
+        } else {
+            Err(Error::Other {
+                message: "unexpected Nones in all Utxo outputs".to_string(),
+                retryable: false,
+            })
         }
-        let output_owners = key::secp256k1::txs::OutputOwners {
-            locktime,
-            threshold,
-            addresses,
-        };
-        let transfer_output = key::secp256k1::txs::transfer::Output {
-            amount,
-            output_owners,
-        };
+    }
+}
 
-        let utxo = {
-            if let Some(mut stakeable_lock_out) = stakeable_lock_out {
-                stakeable_lock_out.transfer_output = transfer_output;
-                Utxo {
-                    utxo_id: Id {
-                        tx_id,
-                        output_index,
-                        ..Id::default()
-                    },
-                    asset_id,
-                    stakeable_lock_out: Some(stakeable_lock_out),
-                    ..Utxo::default()
-                }
-            } else {
-                Utxo {
-                    utxo_id: Id {
-                        tx_id,
-                        output_index,
-                        ..Id::default()
-                    },
-                    asset_id,
-                    transfer_output: Some(transfer_output),
-                    ..Utxo::default()
-                }
-            }
-        };
-        Ok(utxo)
+/// Same decoder as "Utxo::unpack", with the borrowed reader over the packer bytes.
+impl Unpack for Utxo {
+    fn unpack_from(packer: &packer::Packer) -> Result<Self> {
+        packer.unpack_with_reader(|reader| reader.unpack_utxo())
     }
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_unpack_hex --exact --show-output
 #[test]
 fn test_utxo_unpack_hex() {
+    use crate::ids::short;
+
     let utxo_hex_1 = "0x000000000000000000000000000000000000000000000000000000000000000000000000000088eec2e099c6a528e689618e8721e04ae85ea574c7a15a7968644d14d54780140000000702c68af0bb1400000000000000000000000000010000000165844a05405f3662c1928142c6c2a783ef871de939b564db";
     let utxo = Utxo::from_hex(utxo_hex_1).unwrap();
     let utxo_hex_2 = utxo.to_hex().unwrap();
@@ -373,3 +578,517 @@ fn test_utxo_unpack_hex() {
 
     println!("{:?}", utxo);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_unpack_packer --exact --show-output
+#[test]
+fn test_utxo_unpack_packer() {
+    use crate::ids::short;
+
+    let utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[1; 32]),
+            output_index: 3,
+            ..Id::default()
+        },
+        asset_id: ids::Id::from_slice(&[2; 32]),
+        stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+            locktime: 1000,
+            transfer_output: key::secp256k1::txs::transfer::Output {
+                amount: 2000,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses: vec![
+                        short::Id::from_slice(&[3; 20]),
+                        short::Id::from_slice(&[4; 20]),
+                    ],
+                },
+            },
+        }),
+        ..Utxo::default()
+    };
+    let d = utxo.pack(codec::VERSION).unwrap().take_bytes();
+    assert_eq!(Utxo::unpack(&d).unwrap(), utxo);
+    assert!(Utxo::unpack(&d[..d.len() - 1]).is_err());
+
+    // "Unpack" shares the decoder, and advances the packer offset
+    // as much as read for the fields that follow
+    let packer = packer::Packer::new(1024, 0);
+    utxo.pack_to(&packer).unwrap();
+    packer.pack_u32(7).unwrap();
+    let b = packer.take_bytes();
+    let packer = packer::Packer::load_bytes_for_unpack(b.len(), &b);
+    assert_eq!(Utxo::unpack_from(&packer).unwrap(), utxo);
+    assert_eq!(packer.unpack_u32().unwrap(), 7);
+
+    // failed unpack does not advance the packer offset
+    let packer = packer::Packer::load_bytes_for_unpack(b.len() - 8, &b[..b.len() - 8]);
+    assert!(Utxo::unpack_from(&packer).is_err());
+    assert_eq!(packer.get_offset(), 0);
+}
+
+/// Represents the lock state of the UTXO at a given time.
+/// ref. "avalanchego/vms/platformvm.Service.GetBalance"
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum LockState {
+    /// Spendable for both transfers and staking.
+    Unlocked,
+    /// Locked by "*platformvm.StakeableLockOut",
+    /// thus can be staked but not transferred.
+    LockedStakeable,
+    /// Locked by the output owners locktime,
+    /// thus can be neither staked nor transferred.
+    LockedNotStakeable,
+}
+
+/// Represents the balances of an asset by the lock state.
+/// ref. "avalanchego/vms/platformvm.GetBalanceResponse"
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub unlocked: u64,
+    pub locked_stakeable: u64,
+    pub locked_not_stakeable: u64,
+}
+
+impl Balance {
+    pub fn total(&self) -> u64 {
+        self.unlocked
+            .saturating_add(self.locked_stakeable)
+            .saturating_add(self.locked_not_stakeable)
+    }
+
+    fn add(&mut self, lock_state: LockState, amount: u64) {
+        let v = match lock_state {
+            LockState::Unlocked => &mut self.unlocked,
+            LockState::LockedStakeable => &mut self.locked_stakeable,
+            LockState::LockedNotStakeable => &mut self.locked_not_stakeable,
+        };
+        *v = v.saturating_add(amount);
+    }
+}
+
+/// Represents the order in which the UTXOs are consumed.
+/// Ties are broken by the UTXO Id, so the selection is deterministic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Order {
+    /// Consumes in the UTXO Id order.
+    #[default]
+    UtxoId,
+    /// Consumes the largest UTXOs first, to minimize the number of inputs.
+    LargestFirst,
+    /// Consumes the smallest UTXOs first, to reduce the number of UTXOs.
+    SmallestFirst,
+}
+
+/// Represents the set of UTXOs indexed by the owner address and the asset,
+/// iterated in the UTXO Id order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Set {
+    utxos: BTreeMap<Id, Utxo>,
+    by_address: HashMap<short::Id, BTreeSet<Id>>,
+    by_asset: HashMap<ids::Id, BTreeSet<Id>>,
+}
+
+impl Set {
+    pub fn new(utxos: Vec<Utxo>) -> Self {
+        let mut set = Self::default();
+        for utxo in utxos {
+            set.insert(utxo);
+        }
+        set
+    }
+
+    /// Inserts the UTXO, and returns the previous one with the same UTXO Id.
+    pub fn insert(&mut self, utxo: Utxo) -> Option<Utxo> {
+        let prev = self.remove(&utxo.utxo_id);
+
+        if let Some(owners) = utxo.owners() {
+            for addr in owners.addresses.iter() {
+                self.by_address
+                    .entry(addr.clone())
+                    .or_default()
+                    .insert(utxo.utxo_id.clone());
+            }
+        }
+        self.by_asset
+            .entry(utxo.asset_id)
+            .or_default()
+            .insert(utxo.utxo_id.clone());
+        self.utxos.insert(utxo.utxo_id.clone(), utxo);
+
+        prev
+    }
+
+    /// Removes the UTXO (e.g., once consumed).
+    pub fn remove(&mut self, utxo_id: &Id) -> Option<Utxo> {
+        let utxo = self.utxos.remove(utxo_id)?;
+
+        if let Some(owners) = utxo.owners() {
+            for addr in owners.addresses.iter() {
+                if let Some(ids) = self.by_address.get_mut(addr) {
+                    ids.remove(utxo_id);
+                    if ids.is_empty() {
+                        self.by_address.remove(addr);
+                    }
+                }
+            }
+        }
+        if let Some(ids) = self.by_asset.get_mut(&utxo.asset_id) {
+            ids.remove(utxo_id);
+            if ids.is_empty() {
+                self.by_asset.remove(&utxo.asset_id);
+            }
+        }
+
+        Some(utxo)
+    }
+
+    pub fn get(&self, utxo_id: &Id) -> Option<&Utxo> {
+        self.utxos.get(utxo_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.utxos.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.utxos.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Utxo> {
+        self.utxos.values()
+    }
+
+    /// Returns the UTXOs whose output owners include the address.
+    pub fn by_owner(&self, address: &short::Id) -> Vec<&Utxo> {
+        self.lookup(self.by_address.get(address))
+    }
+
+    /// Returns the UTXOs of the asset in the lock state at the given unix timestamp.
+    pub fn by_asset(&self, asset_id: &ids::Id, lock_state: LockState, now_unix: u64) -> Vec<&Utxo> {
+        self.lookup(self.by_asset.get(asset_id))
+            .into_iter()
+            .filter(|utxo| utxo.lock_state(now_unix) == lock_state)
+            .collect()
+    }
+
+    /// Returns the balances of the asset at the given unix timestamp.
+    pub fn balance(&self, asset_id: &ids::Id, now_unix: u64) -> Balance {
+        let mut balance = Balance::default();
+        for utxo in self.lookup(self.by_asset.get(asset_id)) {
+            balance.add(utxo.lock_state(now_unix), utxo.amount());
+        }
+        balance
+    }
+
+    /// Returns the balances of the asset owned by the address
+    /// at the given unix timestamp.
+    pub fn balance_of(&self, address: &short::Id, asset_id: &ids::Id, now_unix: u64) -> Balance {
+        let mut balance = Balance::default();
+        for utxo in self.by_owner(address) {
+            if utxo.asset_id == *asset_id {
+                balance.add(utxo.lock_state(now_unix), utxo.amount());
+            }
+        }
+        balance
+    }
+
+    /// Selects the UTXOs of the asset in the lock state to cover the amount,
+    /// with the coin selection strategy (e.g., "Order::LargestFirst").
+    /// The selected UTXOs may exceed the amount, in which case the caller
+    /// returns the change.
+    pub fn select(
+        &self,
+        asset_id: &ids::Id,
+        lock_state: LockState,
+        amount: u64,
+        now_unix: u64,
+        selector: &dyn txs::coin_selection::CoinSelector,
+    ) -> Result<Vec<&Utxo>> {
+        let candidates = self.by_asset(asset_id, lock_state, now_unix);
+        let total = candidates
+            .iter()
+            .fold(0_u64, |total, utxo| total.saturating_add(utxo.amount()));
+        txs::coin_selection::select(selector, candidates, amount).ok_or_else(|| Error::Other {
+            message: format!(
+                "insufficient {:?} balance {} of asset {} (need {})",
+                lock_state, total, asset_id, amount
+            ),
+            retryable: false,
+        })
+    }
+
+    fn lookup(&self, utxo_ids: Option<&BTreeSet<Id>>) -> Vec<&Utxo> {
+        match utxo_ids {
+            Some(utxo_ids) => utxo_ids
+                .iter()
+                .filter_map(|utxo_id| self.utxos.get(utxo_id))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_set --exact --show-output
+#[test]
+fn test_utxo_set() {
+    let now = 1000_u64;
+    let asset_id = ids::Id::from_slice(&[1; 32]);
+    let other_asset_id = ids::Id::from_slice(&[2; 32]);
+    let addr1 = short::Id::from_slice(&[1; 20]);
+    let addr2 = short::Id::from_slice(&[2; 20]);
+
+    let new_utxo = |index: u32,
+                    asset_id: ids::Id,
+                    amount: u64,
+                    owner_locktime: u64,
+                    stakeable_locktime: Option<u64>,
+                    addr: &short::Id| {
+        let out = key::secp256k1::txs::transfer::Output {
+            amount,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime: owner_locktime,
+                threshold: 1,
+                addresses: vec![addr.clone()],
+            },
+        };
+        Utxo {
+            utxo_id: Id {
+                tx_id: ids::Id::from_slice(&[9; 32]),
+                output_index: index,
+                ..Id::default()
+            },
+            asset_id,
+            transfer_output: if stakeable_locktime.is_none() {
+                Some(out.clone())
+            } else {
+                None
+            },
+            stakeable_lock_out: stakeable_locktime.map(|locktime| {
+                platformvm::txs::StakeableLockOut {
+                    locktime,
+                    transfer_output: out,
+                }
+            }),
+            mint_output: None,
+            nft_mint_output: None,
+            nft_transfer_output: None,
+            property_mint_output: None,
+            property_owned_output: None,
+        }
+    };
+
+    let utxos = vec![
+        new_utxo(5, asset_id, 500, 0, None, &addr1),
+        new_utxo(4, asset_id, 100, 0, None, &addr1),
+        new_utxo(3, asset_id, 300, 0, None, &addr2),
+        // stakeable lock expired
+        new_utxo(2, asset_id, 200, 0, Some(now), &addr1),
+        new_utxo(1, asset_id, 1000, 0, Some(now + 1), &addr1),
+        new_utxo(0, asset_id, 2000, now + 1, None, &addr1),
+        new_utxo(6, asset_id, 3000, now + 1, Some(now + 1), &addr2),
+        new_utxo(7, other_asset_id, 7000, 0, None, &addr1),
+    ];
+    let mut set = Set::new(utxos.clone());
+    assert_eq!(set.len(), 8);
+    assert_eq!(utxos[0].lock_state(now), LockState::Unlocked);
+    assert_eq!(utxos[3].lock_state(now), LockState::Unlocked);
+    assert_eq!(utxos[4].lock_state(now), LockState::LockedStakeable);
+    assert_eq!(utxos[5].lock_state(now), LockState::LockedNotStakeable);
+    assert_eq!(utxos[6].lock_state(now), LockState::LockedNotStakeable);
+    assert_eq!(utxos[4].lock_state(now + 1), LockState::Unlocked);
+
+    assert_eq!(
+        set.balance(&asset_id, now),
+        Balance {
+            unlocked: 1100,
+            locked_stakeable: 1000,
+            locked_not_stakeable: 5000,
+        }
+    );
+    assert_eq!(set.balance(&asset_id, now).total(), 7100);
+    assert_eq!(
+        set.balance_of(&addr1, &asset_id, now),
+        Balance {
+            unlocked: 800,
+            locked_stakeable: 1000,
+            locked_not_stakeable: 2000,
+        }
+    );
+    assert_eq!(set.balance(&other_asset_id, now).unlocked, 7000);
+    assert_eq!(set.by_owner(&addr2).len(), 2);
+
+    // iterated in the UTXO Id order
+    let unlocked: Vec<u32> = set
+        .by_asset(&asset_id, LockState::Unlocked, now)
+        .iter()
+        .map(|utxo| utxo.utxo_id.output_index)
+        .collect();
+    assert_eq!(unlocked, vec![2, 3, 4, 5]);
+
+    let selected = |order: Order, amount: u64| -> Vec<u32> {
+        set.select(&asset_id, LockState::Unlocked, amount, now, &order)
+            .unwrap()
+            .iter()
+            .map(|utxo| utxo.utxo_id.output_index)
+            .collect()
+    };
+    assert_eq!(selected(Order::UtxoId, 450), vec![2, 3]);
+    assert_eq!(selected(Order::LargestFirst, 450), vec![5]);
+    assert_eq!(selected(Order::SmallestFirst, 450), vec![4, 2, 3]);
+    let exact: Vec<u32> = set
+        .select(
+            &asset_id,
+            LockState::Unlocked,
+            600,
+            now,
+            &txs::coin_selection::BranchAndBound::default(),
+        )
+        .unwrap()
+        .iter()
+        .map(|utxo| utxo.utxo_id.output_index)
+        .collect();
+    assert_eq!(exact, vec![5, 4]);
+    assert!(set
+        .select(&asset_id, LockState::Unlocked, 1101, now, &Order::UtxoId)
+        .is_err());
+    assert!(set
+        .select(
+            &asset_id,
+            LockState::LockedStakeable,
+            1000,
+            now,
+            &Order::UtxoId
+        )
+        .is_ok());
+
+    let removed = set.remove(&utxos[0].utxo_id).unwrap();
+    assert_eq!(removed, utxos[0]);
+    assert!(set.remove(&utxos[0].utxo_id).is_none());
+    assert_eq!(set.balance(&asset_id, now).unlocked, 600);
+    assert_eq!(set.balance_of(&addr1, &asset_id, now).unlocked, 300);
+    assert!(set.insert(removed.clone()).is_none());
+    assert_eq!(set.insert(removed).unwrap(), utxos[0]);
+    assert_eq!(set.len(), 8);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_mint_output --exact --show-output
+#[test]
+fn test_utxo_mint_output() {
+    let now = 1000_u64;
+    let asset_id = ids::Id::from_slice(&[1; 32]);
+    let addr = short::Id::from_slice(&[1; 20]);
+
+    let utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[9; 32]),
+            output_index: 1,
+            ..Id::default()
+        },
+        asset_id,
+        mint_output: Some(key::secp256k1::txs::mint::Output::new(
+            key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr)),
+        )),
+        ..Utxo::default()
+    };
+    let packed = utxo.pack(codec::VERSION).unwrap().take_bytes();
+    assert_eq!(Utxo::unpack(&packed).unwrap(), utxo);
+    assert_eq!(Utxo::from_hex(&utxo.to_hex().unwrap()).unwrap(), utxo);
+
+    // the minting right is owned but holds no balance
+    assert_eq!(utxo.amount(), 0);
+    assert!(utxo.output().is_none());
+    assert_eq!(utxo.owners().unwrap().addresses, vec![addr.clone()]);
+    let set = Set::new(vec![utxo]);
+    assert_eq!(set.by_owner(&addr).len(), 1);
+    assert_eq!(set.balance(&asset_id, now).total(), 0);
+    assert!(set.by_asset(&asset_id, LockState::Unlocked, now).is_empty());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_nft_outputs --exact --show-output
+#[test]
+fn test_utxo_nft_outputs() {
+    let now = 1000_u64;
+    let asset_id = ids::Id::from_slice(&[2; 32]);
+    let addr = short::Id::from_slice(&[2; 20]);
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+
+    let mint_utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[9; 32]),
+            ..Id::default()
+        },
+        asset_id,
+        nft_mint_output: Some(avm::nftfx::MintOutput::new(5, owners.clone())),
+        ..Utxo::default()
+    };
+    let nft_utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[9; 32]),
+            output_index: 1,
+            ..Id::default()
+        },
+        asset_id,
+        nft_transfer_output: Some(avm::nftfx::TransferOutput::new(
+            5,
+            b"hello".to_vec(),
+            owners,
+        )),
+        ..Utxo::default()
+    };
+    for utxo in [&mint_utxo, &nft_utxo] {
+        let packed = utxo.pack(codec::VERSION).unwrap().take_bytes();
+        assert_eq!(&Utxo::unpack(&packed).unwrap(), utxo);
+        assert_eq!(&Utxo::from_hex(&utxo.to_hex().unwrap()).unwrap(), utxo);
+
+        // the NFTs are owned but hold no balance
+        assert_eq!(utxo.amount(), 0);
+        assert_eq!(utxo.owners().unwrap().addresses, vec![addr.clone()]);
+    }
+
+    let set = Set::new(vec![mint_utxo, nft_utxo]);
+    assert_eq!(set.by_owner(&addr).len(), 2);
+    assert_eq!(set.balance(&asset_id, now).total(), 0);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::utxo::test_utxo_property_outputs --exact --show-output
+#[test]
+fn test_utxo_property_outputs() {
+    let asset_id = ids::Id::from_slice(&[3; 32]);
+    let addr = short::Id::from_slice(&[3; 20]);
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+
+    let mint_utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[9; 32]),
+            ..Id::default()
+        },
+        asset_id,
+        property_mint_output: Some(avm::propertyfx::MintOutput::new(owners.clone())),
+        ..Utxo::default()
+    };
+    let owned_utxo = Utxo {
+        utxo_id: Id {
+            tx_id: ids::Id::from_slice(&[9; 32]),
+            output_index: 1,
+            ..Id::default()
+        },
+        asset_id,
+        property_owned_output: Some(avm::propertyfx::OwnedOutput::new(owners)),
+        ..Utxo::default()
+    };
+    for utxo in [&mint_utxo, &owned_utxo] {
+        let packed = utxo.pack(codec::VERSION).unwrap().take_bytes();
+        assert_eq!(&Utxo::unpack(&packed).unwrap(), utxo);
+        assert_eq!(utxo.amount(), 0);
+        assert_eq!(utxo.owners().unwrap().addresses, vec![addr.clone()]);
+    }
+
+    // the owned output is not parsed as the minting right with the same layout
+    let packed = owned_utxo.pack(codec::VERSION).unwrap().take_bytes();
+    let parsed = Utxo::unpack(&packed).unwrap();
+    assert!(parsed.property_mint_output.is_none());
+    assert!(parsed.property_owned_output.is_some());
+}
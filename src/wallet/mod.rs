@@ -44,10 +44,16 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
     pub tx_fee: u64,
     /// Transaction fee for adding a primary network validator.
     pub add_primary_network_validator_fee: u64,
+    /// Transaction fee for adding a primary network delegator.
+    pub add_primary_network_delegator_fee: u64,
     /// Transaction fee to create a new subnet.
     pub create_subnet_tx_fee: u64,
     /// Transaction fee to create a new blockchain.
     pub create_blockchain_tx_fee: u64,
+    /// Transaction fee to transform a subnet into an elastic subnet.
+    pub transform_subnet_tx_fee: u64,
+    /// Transaction fee to create a new X-chain asset.
+    pub create_asset_tx_fee: u64,
 }
 
 /// ref. <https://doc.rust-lang.org/std/string/trait.ToString.html>
@@ -80,12 +86,23 @@ where
             "add_primary_network_validator_fee: {}\n",
             self.add_primary_network_validator_fee
         )?;
+        write!(
+            f,
+            "add_primary_network_delegator_fee: {}\n",
+            self.add_primary_network_delegator_fee
+        )?;
         write!(f, "create_subnet_tx_fee: {}\n", self.create_subnet_tx_fee)?;
         write!(
             f,
             "create_blockchain_tx_fee: {}\n",
             self.create_blockchain_tx_fee
-        )
+        )?;
+        write!(
+            f,
+            "transform_subnet_tx_fee: {}\n",
+            self.transform_subnet_tx_fee
+        )?;
+        write!(f, "create_asset_tx_fee: {}\n", self.create_asset_tx_fee)
     }
 }
 
@@ -202,6 +219,8 @@ where
             tx_fee,
             create_subnet_tx_fee,
             create_blockchain_tx_fee,
+            transform_subnet_tx_fee,
+            create_asset_tx_fee,
         ) = if self.only_evm {
             log::warn!("wallet is only used for EVM thus skipping querying info API");
             (
@@ -213,6 +232,8 @@ where
                 0,
                 0,
                 0,
+                0,
+                0,
             )
         } else {
             let resp = api_info::get_network_id(&self.base_http_urls[0]).await?;
@@ -237,6 +258,8 @@ where
             let tx_fee = get_tx_fee_result.tx_fee;
             let create_subnet_tx_fee = get_tx_fee_result.create_subnet_tx_fee;
             let create_blockchain_tx_fee = get_tx_fee_result.create_blockchain_tx_fee;
+            let transform_subnet_tx_fee = get_tx_fee_result.transform_subnet_tx_fee;
+            let create_asset_tx_fee = get_tx_fee_result.create_asset_tx_fee;
 
             (
                 network_id,
@@ -247,6 +270,8 @@ where
                 tx_fee,
                 create_subnet_tx_fee,
                 create_blockchain_tx_fee,
+                transform_subnet_tx_fee,
+                create_asset_tx_fee,
             )
         };
 
@@ -273,8 +298,11 @@ where
 
             tx_fee,
             add_primary_network_validator_fee: ADD_PRIMARY_NETWORK_VALIDATOR_FEE,
+            add_primary_network_delegator_fee: ADD_PRIMARY_NETWORK_DELEGATOR_FEE,
             create_subnet_tx_fee,
             create_blockchain_tx_fee,
+            transform_subnet_tx_fee,
+            create_asset_tx_fee,
         };
         log::info!("initiated the wallet:\n{}", w);
 
@@ -284,3 +312,6 @@ where
 
 /// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
 pub const ADD_PRIMARY_NETWORK_VALIDATOR_FEE: u64 = 0;
+
+/// ref. <https://docs.avax.network/learn/platform-overview/transaction-fees/#fee-schedule>
+pub const ADD_PRIMARY_NETWORK_DELEGATOR_FEE: u64 = 0;
@@ -0,0 +1,382 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::{Error, Result},
+    formatting,
+    ids::{self, node},
+    jsonrpc::client::p as client_p,
+    key, platformvm, txs, units, wallet,
+};
+use chrono::{DateTime, Utc};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents P-chain "AddPermissionlessDelegator" transaction.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewAddPermissionlessDelegatorTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    /// Node Id of the validator to delegate to.
+    pub node_id: node::Id,
+
+    /// Empty for the primary network.
+    pub subnet_id: ids::Id,
+
+    /// Denominated in nano-AVAX.
+    /// On the X-Chain, one AVAX is 10^9  units.
+    /// On the P-Chain, one AVAX is 10^9  units.
+    /// On the C-Chain, one AVAX is 10^18 units.
+    /// ref. <https://snowtrace.io/unitconverter>
+    pub stake_amount: u64,
+
+    /// The delegation period must be within the validation period.
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+
+    /// Staking rules to check the transaction against before issuance,
+    /// or "None" to skip the check and leave it to the node.
+    /// Defaults to the rules of the known networks, and "None" for the custom networks.
+    pub staking_rules: Option<platformvm::staking::Rules>,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        let now_unix = wallet::p::now_unix();
+
+        let start_time = now_unix + 60;
+        let start_time = DateTime::from_timestamp(start_time as i64, 0).unwrap();
+
+        // 14-day + 1-min, so that the default delegation period
+        // is bounded by the default validation period of "add_validator"
+        // otherwise "delegator's staking period must be a subset of the validator's"
+        let end_time = now_unix + 14 * 24 * 60 * 60 + 60;
+        let end_time = DateTime::from_timestamp(end_time as i64, 0).unwrap();
+
+        Self {
+            inner: p.clone(),
+            node_id: node::Id::empty(),
+            subnet_id: ids::Id::empty(),
+            stake_amount: 25 * units::AVAX,
+            start_time,
+            end_time,
+            staking_rules: platformvm::staking::Rules::for_known_network(p.inner.network_id),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_secs(62), // enough to elapse delegate start time
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the validator node Id to delegate to.
+    #[must_use]
+    pub fn node_id(mut self, node_id: node::Id) -> Self {
+        self.node_id = node_id;
+        self
+    }
+
+    /// Sets the subnet Id of the validator.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the stake amount.
+    #[must_use]
+    pub fn stake_amount(mut self, stake_amount: u64) -> Self {
+        self.stake_amount = stake_amount;
+        self
+    }
+
+    /// Sets the delegate start time.
+    #[must_use]
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Sets the delegate end time.
+    #[must_use]
+    pub fn end_time(mut self, end_time: DateTime<Utc>) -> Self {
+        self.end_time = end_time;
+        self
+    }
+
+    /// Sets the delegate start/end time in days from 'offset_seconds' later.
+    #[must_use]
+    pub fn delegate_period_in_days(mut self, days: u64, offset_seconds: u64) -> Self {
+        let now_unix = wallet::p::now_unix();
+
+        let start_time = now_unix + offset_seconds;
+        let start_time = DateTime::from_timestamp(start_time as i64, 0).unwrap();
+
+        // must be smaller than the validator end time
+        let end_time = now_unix + days * 24 * 60 * 60;
+        let end_time = DateTime::from_timestamp(end_time as i64, 0).unwrap();
+
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self
+    }
+
+    /// Sets the staking rules of the primary network to check the transaction against
+    /// (defaults to the rules of the wallet network), or "None" to skip the check
+    /// (e.g., a custom network with its own staking config).
+    /// Only used for the primary network, since the permissionless subnet
+    /// rules are defined by its "TransformSubnetTx".
+    ///
+    /// Since Durango, the node ignores the staker start time and the staking
+    /// starts on acceptance, thus the start time check is only an approximation
+    /// of the pre-Durango rules.
+    #[must_use]
+    pub fn staking_rules(mut self, staking_rules: Option<platformvm::staking::Rules>) -> Self {
+        self.staking_rules = staking_rules;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(
+        &self,
+    ) -> Result<(
+        platformvm::txs::add_permissionless_delegator::Tx,
+        Vec<Vec<T>>,
+    )> {
+        // stakeable locked UTXOs are consumed first for the stake
+        let fee = self.inner.inner.add_primary_network_delegator_fee;
+        let (ins, unstaked_outs, staked_outs, signers) =
+            self.inner.spend(self.stake_amount, fee).await?;
+
+        let tx = platformvm::txs::add_permissionless_delegator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            validator: platformvm::txs::Validator {
+                node_id: self.node_id,
+                start: self.start_time.timestamp() as u64,
+                end: self.end_time.timestamp() as u64,
+                weight: self.stake_amount,
+            },
+
+            // empty id for primary network
+            subnet_id: self.subnet_id,
+
+            stake_transferable_outputs: Some(staked_outs),
+            rewards_owner: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![self.inner.inner.short_address.clone()],
+            },
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+
+        // permissionless subnet rules are defined by its "TransformSubnetTx"
+        if self.subnet_id.is_empty() {
+            if let Some(rules) = &self.staking_rules {
+                rules.verify_add_permissionless_delegator(&tx, wallet::p::now_unix())?;
+            }
+        }
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::add_permissionless_delegator::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                None,
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the add permissionless delegator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "add_delegator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// Returns an error if the node is not a current validator of the subnet.
+    pub async fn issue(&self) -> Result<(ids::Id, bool)> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "delegating to permissionless validator '{}' for subnet '{}' with stake amount {} AVAX ({} nAVAX) via {}",
+            self.node_id,
+            self.subnet_id,
+            units::cast_xp_navax_to_avax(primitive_types::U256::from(self.stake_amount)),
+            self.stake_amount,
+            picked_http_rpc.1
+        );
+
+        let is_validator = self
+            .inner
+            .is_subnet_validator(&self.node_id, &self.subnet_id)
+            .await?;
+        if !is_validator {
+            return Err(Error::Other {
+                message: format!(
+                    "node Id '{}' is not a validator for subnet '{}' to delegate to",
+                    self.node_id, self.subnet_id
+                ),
+                retryable: false,
+            });
+        }
+
+        let fee = self.inner.inner.add_primary_network_delegator_fee;
+        let cur_balance_p = self.inner.balance().await?;
+        if cur_balance_p < self.stake_amount + fee {
+            return Err(Error::Other {
+                message: format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.stake_amount + fee),
+                retryable: false,
+            });
+        };
+        log::info!(
+            "{} current P-chain balance {}",
+            self.inner.inner.p_address,
+            cur_balance_p
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok((tx.base_tx.metadata.unwrap().id, false));
+        }
+
+        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!(
+                    "failed to issue add permissionless delegator transaction {:?}",
+                    e
+                ),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok((tx_id, true));
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm add permissionless delegator transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == platformvm::txs::status::Status::Committed {
+                log::info!("{} successfully committed", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok((tx_id, true))
+    }
+}
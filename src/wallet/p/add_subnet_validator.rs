@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::{sync::Arc, time::SystemTime};
 
 use crate::{
     errors::{Error, Result},
@@ -164,6 +164,17 @@ where
         self
     }
 
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -171,6 +182,58 @@ where
         self
     }
 
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(
+        &self,
+    ) -> Result<(platformvm::txs::add_subnet_validator::Tx, Vec<Vec<T>>)> {
+        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
+        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+
+        let tx = platformvm::txs::add_subnet_validator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            validator: platformvm::txs::add_subnet_validator::Validator {
+                validator: platformvm::txs::Validator {
+                    node_id: self.node_id,
+                    start: self.start_time.timestamp() as u64,
+                    end: self.end_time.timestamp() as u64,
+                    weight: self.weight,
+                },
+                subnet_id: self.subnet_id,
+            },
+            // if "sig_indices" empty, it errors with "unauthorized subnet modification: input has less signers than expected"
+            subnet_auth,
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        let signers = [signers, subnet_signers].concat();
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::add_subnet_validator::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                Some(self.subnet_id),
+                &signers,
+            )
+            .await
+    }
+
     /// Issues the add subnet validator transaction and returns the transaction Id.
     /// The boolean return represents whether the "add_subnet_validator" request was
     /// successfully issued or not (regardless of its acceptance).
@@ -210,31 +273,8 @@ where
             cur_balance_p
         );
 
-        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
-        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
-
-        let mut tx = platformvm::txs::add_subnet_validator::Tx {
-            base_tx: txs::Tx {
-                network_id: self.inner.inner.network_id,
-                blockchain_id: self.inner.inner.blockchain_id_p,
-                transferable_outputs: Some(unstaked_outs),
-                transferable_inputs: Some(ins),
-                ..Default::default()
-            },
-            validator: platformvm::txs::add_subnet_validator::Validator {
-                validator: platformvm::txs::Validator {
-                    node_id: self.node_id.clone(),
-                    start: self.start_time.timestamp() as u64,
-                    end: self.end_time.timestamp() as u64,
-                    weight: self.weight,
-                },
-                subnet_id: self.subnet_id,
-            },
-            // if "sig_indices" empty, it errors with "unauthorized subnet modification: input has less signers than expected"
-            subnet_auth,
-            ..Default::default()
-        };
-        tx.sign(vec![signers, subnet_signers].concat()).await?;
+        let (mut tx, signers) = self.unsigned_tx().await?;
+        tx.sign(signers).await?;
 
         if self.dry_mode {
             return Ok((tx.base_tx.metadata.unwrap().id, false));
@@ -1,11 +1,11 @@
-use std::time::SystemTime;
+use std::sync::Arc;
 
 use crate::{
     errors::{Error, Result},
     formatting,
     ids::{self, node},
     jsonrpc::client::p as client_p,
-    key, platformvm, txs, units,
+    key, platformvm, txs, units, wallet,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
 use tokio::time::{sleep, Duration, Instant};
@@ -35,6 +35,11 @@ where
     /// Validate reward fee in percent.
     pub reward_fee_percent: u32,
 
+    /// Staking rules to check the transaction against before issuance,
+    /// or "None" to skip the check and leave it to the node.
+    /// Defaults to the rules of the known networks, and "None" for the custom networks.
+    pub staking_rules: Option<platformvm::staking::Rules>,
+
     /// Set "true" to poll transaction status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -54,10 +59,7 @@ where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
 {
     pub fn new(p: &crate::wallet::p::P<T>) -> Self {
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = wallet::p::now_unix();
 
         let start_time = now_unix + 60;
         let native_dt = NaiveDateTime::from_timestamp_opt(start_time as i64, 0).unwrap();
@@ -79,6 +81,7 @@ where
             start_time,
             end_time,
             reward_fee_percent: 2,
+            staking_rules: platformvm::staking::Rules::for_known_network(p.inner.network_id),
             check_acceptance: false,
             poll_initial_wait: Duration::from_secs(62), // enough to elapse validate start time
             poll_interval: Duration::from_secs(1),
@@ -118,10 +121,7 @@ where
     /// Sets the validate start/end time in days from 'offset_seconds' later.
     #[must_use]
     pub fn validate_period_in_days(mut self, days: u64, offset_seconds: u64) -> Self {
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = wallet::p::now_unix();
 
         let start_time = now_unix + offset_seconds;
         let native_dt = NaiveDateTime::from_timestamp_opt(start_time as i64, 0).unwrap();
@@ -145,6 +145,19 @@ where
         self
     }
 
+    /// Sets the staking rules to check the transaction against
+    /// (defaults to the rules of the wallet network), or "None" to skip the check
+    /// (e.g., a custom network with its own staking config).
+    ///
+    /// Since Durango, the node ignores the staker start time and the staking
+    /// starts on acceptance, thus the start time check is only an approximation
+    /// of the pre-Durango rules.
+    #[must_use]
+    pub fn staking_rules(mut self, staking_rules: Option<platformvm::staking::Rules>) -> Self {
+        self.staking_rules = staking_rules;
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -173,6 +186,17 @@ where
         self
     }
 
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -180,6 +204,65 @@ where
         self
     }
 
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(platformvm::txs::add_validator::Tx, Vec<Vec<T>>)> {
+        let (ins, unstaked_outs, staked_outs, signers) = self
+            .inner
+            .spend(
+                self.stake_amount,
+                self.inner.inner.add_primary_network_validator_fee,
+            )
+            .await?;
+
+        let tx = platformvm::txs::add_validator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            validator: platformvm::txs::Validator {
+                node_id: self.node_id,
+                start: self.start_time.timestamp() as u64,
+                end: self.end_time.timestamp() as u64,
+                weight: self.stake_amount,
+            },
+            stake_transferable_outputs: Some(staked_outs),
+            rewards_owner: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![self.inner.inner.short_address.clone()],
+            },
+            shares: self.reward_fee_percent * 10000,
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        if let Some(rules) = &self.staking_rules {
+            rules.verify_add_validator(&tx, wallet::p::now_unix())?;
+        }
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::add_validator::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                None,
+                &signers,
+            )
+            .await
+    }
+
     /// Issues the add validator transaction and returns the transaction Id.
     /// The boolean return represents whether the "add_validator" request was
     /// successfully issued or not (regardless of its acceptance).
@@ -219,37 +302,7 @@ where
             cur_balance_p
         );
 
-        let (ins, unstaked_outs, staked_outs, signers) = self
-            .inner
-            .spend(
-                self.stake_amount,
-                self.inner.inner.add_primary_network_validator_fee,
-            )
-            .await?;
-
-        let mut tx = platformvm::txs::add_validator::Tx {
-            base_tx: txs::Tx {
-                network_id: self.inner.inner.network_id,
-                blockchain_id: self.inner.inner.blockchain_id_p,
-                transferable_outputs: Some(unstaked_outs),
-                transferable_inputs: Some(ins),
-                ..Default::default()
-            },
-            validator: platformvm::txs::Validator {
-                node_id: self.node_id.clone(),
-                start: self.start_time.timestamp() as u64,
-                end: self.end_time.timestamp() as u64,
-                weight: self.stake_amount,
-            },
-            stake_transferable_outputs: Some(staked_outs),
-            rewards_owner: key::secp256k1::txs::OutputOwners {
-                locktime: 0,
-                threshold: 1,
-                addresses: vec![self.inner.inner.short_address.clone()],
-            },
-            shares: self.reward_fee_percent * 10000,
-            ..Default::default()
-        };
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
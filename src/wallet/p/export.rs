@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use crate::{
     errors::{Error, Result},
     formatting, ids,
@@ -95,6 +97,17 @@ where
         self
     }
 
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -102,20 +115,11 @@ where
         self
     }
 
-    /// Issues the export transaction and returns the transaction Id.
-    pub async fn issue(&self) -> Result<ids::Id> {
-        let picked_http_rpc = self.inner.inner.pick_base_http_url();
-        log::info!(
-            "exporting {} AVAX from {} to {} via {}",
-            self.amount,
-            self.inner.inner.short_address,
-            self.destination_blockchain_id,
-            picked_http_rpc.1
-        );
-
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(platformvm::txs::export::Tx, Vec<Vec<T>>)> {
         let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
 
-        let mut tx = platformvm::txs::export::Tx {
+        let tx = platformvm::txs::export::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
                 blockchain_id: self.inner.inner.blockchain_id_p,
@@ -138,6 +142,41 @@ where
             }]),
             ..Default::default()
         };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::export::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                None,
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the export transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "exporting {} AVAX from {} to {} via {}",
+            self.amount,
+            self.inner.inner.short_address,
+            self.destination_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
@@ -93,17 +93,9 @@ where
         self
     }
 
-    /// Issues the import transaction and returns the transaction Id.
-    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewImportTx"
-    /// TODO: not working... cache exported Utxos
-    pub async fn issue(&self) -> Result<ids::Id> {
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(platformvm::txs::import::Tx, Vec<Vec<T>>)> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
-        log::info!(
-            "importing from {} via {}",
-            self.source_blockchain_id,
-            picked_http_rpc.1
-        );
-
         let utxos = client_p::get_utxos(&picked_http_rpc.1, &self.inner.inner.p_address).await?;
         let utxos_result = utxos.result.unwrap();
         let utxos = utxos_result.utxos.unwrap();
@@ -158,6 +150,12 @@ where
             });
         }
 
+        // imported inputs must be sorted, along with the signers of each input
+        // ref. "avalanchego/vms/components/avax.SortTransferableInputsWithSigners"
+        let mut inputs_with_signers: Vec<_> = import_inputs.into_iter().zip(signers).collect();
+        inputs_with_signers.sort_by(|a, b| a.0.cmp(&b.0));
+        let (import_inputs, signers): (Vec<_>, Vec<_>) = inputs_with_signers.into_iter().unzip();
+
         // TODO: check import amount with tx fee
         log::info!(
             "importing total {} AVAX with tx fee {}",
@@ -187,7 +185,7 @@ where
             import_inputs.len(),
             outputs.len()
         );
-        let mut tx = platformvm::txs::import::Tx {
+        let tx = platformvm::txs::import::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
                 blockchain_id: self.inner.inner.blockchain_id_p.clone(),
@@ -198,6 +196,40 @@ where
             source_chain_transferable_inputs: Some(import_inputs),
             ..Default::default()
         };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::import::Tx::type_id(),
+                &tx,
+                tx.source_chain_transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                None,
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the import transaction and returns the transaction Id.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewImportTx"
+    /// TODO: not working... cache exported Utxos
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "importing from {} via {}",
+            self.source_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
@@ -1,3 +1,5 @@
+pub mod add_delegator;
+pub mod add_permissionless_delegator;
 pub mod add_permissionless_validator;
 pub mod add_subnet_validator;
 pub mod add_validator;
@@ -5,15 +7,70 @@ pub mod create_chain;
 pub mod create_subnet;
 pub mod export;
 pub mod import;
+pub mod remove_subnet_validator;
+pub mod transfer_subnet_ownership;
+pub mod transform_subnet;
 
-use std::{cmp, time::SystemTime};
+use std::{cmp, sync::Arc, time::SystemTime};
 
 use crate::{
+    codec,
     errors::{Error, Result},
+    formatting,
     ids::{self, node},
     jsonrpc::client::p as client_p,
     key, platformvm, txs, wallet,
 };
+use tokio::time::{sleep, Duration, Instant};
+
+/// Returns the current time in unix seconds, to check the UTXO locktimes
+/// and the staking periods against.
+fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("unexpected None duration_since")
+        .as_secs()
+}
+
+/// Issuance settings of the tx builders, to sign and issue the tx
+/// with "P::sign_and_issue".
+struct Issuance {
+    dry_mode: bool,
+    check_acceptance: bool,
+    poll_initial_wait: Duration,
+    poll_interval: Duration,
+    poll_timeout: Duration,
+}
+
+/// Splits the still-locked output into the staked output of "amount_to_stake",
+/// and the returned output of the locked value that was not staked, if any.
+/// Both outputs keep the locktime and the owners of the consumed output.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go#L169> "Spend"
+fn split_locked_output(
+    asset_id: ids::Id,
+    out: &platformvm::txs::StakeableLockOut,
+    amount_to_stake: u64,
+) -> (txs::transferable::Output, Option<txs::transferable::Output>) {
+    let locked_output = |amount: u64| txs::transferable::Output {
+        asset_id,
+        stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+            locktime: out.locktime,
+            transfer_output: key::secp256k1::txs::transfer::Output {
+                amount,
+                output_owners: out.transfer_output.output_owners.clone(),
+            },
+        }),
+        ..txs::transferable::Output::default()
+    };
+
+    let remaining_value = out.transfer_output.amount - amount_to_stake;
+    let returned_output = if remaining_value > 0 {
+        Some(locked_output(remaining_value))
+    } else {
+        None
+    };
+    (locked_output(amount_to_stake), returned_output)
+}
 
 impl<T> wallet::Wallet<T>
 where
@@ -23,6 +80,7 @@ where
     pub fn p(&self) -> P<T> {
         P {
             inner: self.clone(),
+            coin_selection: Arc::new(txs::utxo::Order::UtxoId),
         }
     }
 }
@@ -33,6 +91,9 @@ where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
 {
     pub inner: crate::wallet::Wallet<T>,
+
+    /// Coin selection strategy to pick the UTXOs to spend.
+    pub coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
 }
 
 impl<T> P<T>
@@ -79,6 +140,23 @@ where
         Ok(utxos)
     }
 
+    /// Fetches UTXOs for "P" chain, indexed by owner, asset and lock state.
+    pub async fn utxo_set(&self) -> Result<txs::utxo::Set> {
+        Ok(txs::utxo::Set::new(self.utxos().await?))
+    }
+
+    /// Fetches the AVAX balances of the wallet owner by the lock state,
+    /// computed from the UTXOs at the current time.
+    pub async fn balance_by_lock_state(&self) -> Result<txs::utxo::Balance> {
+        let now_unix = now_unix();
+        let utxos = self.utxo_set().await?;
+        Ok(utxos.balance_of(
+            &self.inner.short_address,
+            &self.inner.avax_asset_id,
+            now_unix,
+        ))
+    }
+
     /// Returns "true" if the node_id is a current primary network validator.
     pub async fn is_primary_network_validator(&self, node_id: &node::Id) -> Result<bool> {
         let resp =
@@ -124,6 +202,29 @@ where
         Ok(false)
     }
 
+    /// Selects the UTXOs of the asset in the lock state that the keychain can spend,
+    /// with the coin selection strategy. Returns all the spendable UTXOs
+    /// if they cannot cover the target amount.
+    fn select_utxos<'a>(
+        &self,
+        utxos: &'a txs::utxo::Set,
+        asset_id: &ids::Id,
+        lock_state: txs::utxo::LockState,
+        target: u64,
+        now_unix: u64,
+    ) -> Vec<&'a txs::utxo::Utxo> {
+        let candidates: Vec<&txs::utxo::Utxo> = utxos
+            .by_asset(asset_id, lock_state, now_unix)
+            .into_iter()
+            .filter(|utxo| match utxo.output() {
+                Some(out) => self.inner.keychain.spend(out, now_unix).is_some(),
+                None => false,
+            })
+            .collect();
+        txs::coin_selection::select(self.coin_selection.as_ref(), candidates.clone(), target)
+            .unwrap_or(candidates)
+    }
+
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go#L169> "Spend"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go#L325-L358> "NewAddValidatorTx"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go#L428> "NewAddValidatorTx"
@@ -137,12 +238,9 @@ where
         Vec<txs::transferable::Output>,
         Vec<Vec<T>>,
     )> {
-        let utxos = self.utxos().await?;
+        let utxos = self.utxo_set().await?;
 
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = now_unix();
 
         let mut ins: Vec<txs::transferable::Input> = Vec::new();
         let mut returned_outputs: Vec<txs::transferable::Output> = Vec::new();
@@ -153,30 +251,24 @@ where
         let mut amount_staked: u64 = 0_u64;
 
         // consume locked UTXOs
-        for utxo in utxos.iter() {
+        // only staking avax so ignore other assets
+        for utxo in self.select_utxos(
+            &utxos,
+            &self.inner.avax_asset_id,
+            txs::utxo::LockState::LockedStakeable,
+            amount,
+            now_unix,
+        ) {
             // no need to consume more locked AVAX
             // because it already has consumed more than the target stake amount
+            // (the coin selection falls back to all spendable UTXOs
+            // when they cannot cover the target)
             if amount_staked >= amount {
                 break;
             }
 
-            // only staking avax so ignore other assets
-            if utxo.asset_id != self.inner.avax_asset_id {
-                continue;
-            }
-
-            // check "*platformvm.StakeableLockOut"
-            if utxo.stakeable_lock_out.is_none() {
-                // output is not locked, thus handle this in the next iteration
-                continue;
-            }
-
-            // check locktime
+            // "*platformvm.StakeableLockOut" that is still locked
             let out = utxo.stakeable_lock_out.clone().unwrap();
-            if out.locktime <= now_unix {
-                // output is no longer locked, thus handle in the next iteration
-                continue;
-            }
 
             // check "*secp256k1fx.TransferOutput"
             let inner = out.clone().transfer_output;
@@ -187,13 +279,11 @@ where
             }
             let (transfer_input, in_signers) = res.unwrap();
 
-            let mut remaining_value = transfer_input.amount;
             let amount_to_stake = cmp::min(
                 amount - amount_staked, // amount we still need to stake
-                remaining_value,        // amount available to stake
+                transfer_input.amount,  // amount available to stake
             );
             amount_staked += amount_to_stake;
-            remaining_value -= amount_to_stake;
 
             // add input to the consumed inputs
             ins.push(txs::transferable::Input {
@@ -206,33 +296,13 @@ where
                 ..txs::transferable::Input::default()
             });
 
-            // add output to the staked outputs
-            staked_outputs.push(txs::transferable::Output {
-                asset_id: utxo.asset_id,
-                stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
-                    locktime: out.clone().locktime,
-                    transfer_output: key::secp256k1::txs::transfer::Output {
-                        amount: remaining_value,
-                        output_owners: out.clone().transfer_output.output_owners,
-                    },
-                }),
-                ..txs::transferable::Output::default()
-            });
-
-            if remaining_value > 0 {
+            let (staked_output, returned_output) =
+                split_locked_output(utxo.asset_id, &out, amount_to_stake);
+            staked_outputs.push(staked_output);
+            if let Some(returned_output) = returned_output {
                 // this input provided more value than was needed to be locked
                 // some must be returned
-                returned_outputs.push(txs::transferable::Output {
-                    asset_id: utxo.asset_id,
-                    stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
-                        locktime: out.clone().locktime,
-                        transfer_output: key::secp256k1::txs::transfer::Output {
-                            amount: amount_to_stake,
-                            output_owners: out.clone().transfer_output.output_owners,
-                        },
-                    }),
-                    ..txs::transferable::Output::default()
-                });
+                returned_outputs.push(returned_output);
             }
 
             signers.push(in_signers);
@@ -241,34 +311,24 @@ where
         // amount of AVAX that has been burned
         let mut amount_burned = 0_u64;
 
-        for utxo in utxos.iter() {
+        // only burn AVAX, thus ignore other assets
+        // the locked outputs cannot be burned
+        // or may have already been consumed above
+        for utxo in self.select_utxos(
+            &utxos,
+            &self.inner.avax_asset_id,
+            txs::utxo::LockState::Unlocked,
+            fee + (amount - amount_staked),
+            now_unix,
+        ) {
             // have staked/burned more AVAX than we need
             // thus no need to consume more AVAX
             if amount_burned >= fee && amount_staked >= amount {
                 break;
             }
 
-            // only burn AVAX, thus ignore other assets
-            if utxo.asset_id != self.inner.avax_asset_id {
-                continue;
-            }
-
-            let (skip, out) = {
-                if utxo.transfer_output.is_some() {
-                    let out = utxo.transfer_output.clone().unwrap();
-                    (false, out)
-                } else {
-                    let inner = utxo.stakeable_lock_out.clone().unwrap();
-                    (inner.locktime > now_unix, inner.transfer_output)
-                }
-            };
-            // output is currently locked, so this output cannot be burned
-            // or it may have already been consumed above
-            if skip {
-                continue;
-            }
-
-            let res = self.inner.keychain.spend(&out, now_unix);
+            let out = utxo.output().unwrap();
+            let res = self.inner.keychain.spend(out, now_unix);
             if res.is_none() {
                 // cannot spend the output, move onto next
                 continue;
@@ -354,15 +414,100 @@ where
             });
         }
 
-        // TODO: for now just ignore "signers" in the sorting
-        // since the wallet currently only supports one key
-        ins.sort();
+        // inputs must be sorted, along with the signers of each input
+        // ref. "avalanchego/vms/components/avax.SortTransferableInputsWithSigners"
+        let mut ins_with_signers: Vec<_> = ins.into_iter().zip(signers).collect();
+        ins_with_signers.sort_by(|a, b| a.0.cmp(&b.0));
+        let (ins, signers): (Vec<_>, Vec<_>) = ins_with_signers.into_iter().unzip();
         returned_outputs.sort();
         staked_outputs.sort();
 
         Ok((ins, returned_outputs, staked_outputs, signers))
     }
 
+    /// Burns the "amount" of the non-AVAX asset from the unlocked UTXOs,
+    /// returning the change to the wallet address.
+    /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "spend" with "amountsToBurn"
+    async fn burn_asset(
+        &self,
+        asset_id: ids::Id,
+        amount: u64,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        Vec<Vec<T>>,
+    )> {
+        let utxos = self.utxo_set().await?;
+
+        let now_unix = now_unix();
+
+        let mut ins: Vec<txs::transferable::Input> = Vec::new();
+        let mut returned_outputs: Vec<txs::transferable::Output> = Vec::new();
+        let mut signers: Vec<Vec<T>> = Vec::new();
+
+        let mut amount_burned = 0_u64;
+        // only the unlocked outputs can be burned
+        for utxo in self.select_utxos(
+            &utxos,
+            &asset_id,
+            txs::utxo::LockState::Unlocked,
+            amount,
+            now_unix,
+        ) {
+            if amount_burned >= amount {
+                break;
+            }
+
+            let out = utxo.output().unwrap();
+            let res = self.inner.keychain.spend(out, now_unix);
+            if res.is_none() {
+                // cannot spend the output, move onto next
+                continue;
+            }
+            let (transfer_input, in_signers) = res.unwrap();
+
+            let mut remaining_value = transfer_input.amount;
+            let amount_to_burn = cmp::min(amount - amount_burned, remaining_value);
+            amount_burned += amount_to_burn;
+            remaining_value -= amount_to_burn;
+
+            ins.push(txs::transferable::Input {
+                utxo_id: utxo.utxo_id.clone(),
+                asset_id: utxo.asset_id,
+                transfer_input: Some(transfer_input),
+                ..txs::transferable::Input::default()
+            });
+
+            if remaining_value > 0 {
+                returned_outputs.push(txs::transferable::Output {
+                    asset_id: utxo.asset_id,
+                    transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                        amount: remaining_value,
+                        output_owners: key::secp256k1::txs::OutputOwners {
+                            locktime: 0,
+                            threshold: 1,
+                            addresses: vec![self.inner.short_address.clone()],
+                        },
+                    }),
+                    ..txs::transferable::Output::default()
+                });
+            }
+
+            signers.push(in_signers);
+        }
+        if amount_burned < amount {
+            return Err(Error::Other {
+                message: format!(
+                    "provided keys have balance {} of asset {} but need {}",
+                    amount_burned, asset_id, amount
+                ),
+                retryable: false,
+            });
+        }
+
+        Ok((ins, returned_outputs, signers))
+    }
+
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/utxo/handler.go#L411> "Authorize"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go#L360-L390> "NewAddSubnetValidatorTx"
     /// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go#L512> "NewAddSubnetValidatorTx"
@@ -372,42 +517,198 @@ where
     ) -> Result<(key::secp256k1::txs::Input, Vec<Vec<T>>)> {
         log::info!("authorizing subnet {}", subnet_id);
 
+        let output_owners = self.subnet_owners(subnet_id).await?;
+        let now_unix = now_unix();
+
+        let res = self
+            .inner
+            .keychain
+            .match_threshold(&output_owners, now_unix);
+        let threshold_met = res.is_some();
+        if !threshold_met {
+            return Err(Error::Other {
+                message: "no threshold met, can't sign".to_string(),
+                retryable: false,
+            });
+        }
+        let (sig_indices, keys) = res.unwrap();
+
+        Ok((
+            key::secp256k1::txs::Input {
+                // if empty, it errors with "unauthorized subnet modification: input has less signers than expected"
+                sig_indices,
+            },
+            vec![keys],
+        ))
+    }
+
+    /// Fetches the owners of the subnet, who authorize the subnet modifications.
+    async fn subnet_owners(&self, subnet_id: ids::Id) -> Result<key::secp256k1::txs::OutputOwners> {
         let tx =
             client_p::get_tx(&self.inner.pick_base_http_url().1, &subnet_id.to_string()).await?;
-        if let Some(tx_result) = tx.result {
-            let output_owners = tx_result.tx.unsigned_tx.output_owners;
-
-            let now_unix = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .expect("unexpected None duration_since")
-                .as_secs();
-
-            let res = self
-                .inner
-                .keychain
-                .match_threshold(&output_owners, now_unix);
-            let threshold_met = res.is_some();
-            if !threshold_met {
-                return Err(Error::Other {
-                    message: "no threshold met, can't sign".to_string(),
-                    retryable: false,
-                });
+        match tx.result {
+            Some(tx_result) => Ok(tx_result.tx.unsigned_tx.output_owners),
+            None => Err(Error::Other {
+                message: "empty get tx result".to_string(),
+                retryable: false,
+            }),
+        }
+    }
+
+    /// Builds the partially-signed tx with the owners of each credential:
+    /// the owners of the wallet UTXO that each input spends, followed by
+    /// the subnet owners for the subnet auth if "subnet_id" is set.
+    async fn partially_signed<U: codec::linear::Pack>(
+        &self,
+        type_id: u32,
+        unsigned_tx: &U,
+        inputs: &[txs::transferable::Input],
+        subnet_id: Option<ids::Id>,
+        signers: &[Vec<T>],
+    ) -> Result<txs::partial::PartiallySignedTx> {
+        let utxos = self.utxo_set().await?;
+        let mut owners: Vec<Option<key::secp256k1::txs::OutputOwners>> = inputs
+            .iter()
+            .map(|input| {
+                utxos
+                    .get(&input.utxo_id)
+                    .and_then(|utxo| utxo.owners().cloned())
+            })
+            .collect();
+        if let Some(subnet_id) = subnet_id {
+            owners.push(Some(self.subnet_owners(subnet_id).await?));
+        }
+        Ok(
+            txs::partial::PartiallySignedTx::from_signers(type_id, unsigned_tx, signers)?
+                .owners(&owners),
+        )
+    }
+
+    /// Spends the fee from the unlocked AVAX and authorizes the subnet modification
+    /// with the subnet owner keys, returning the inputs, the change outputs,
+    /// the subnet auth and the signers of each credential (the subnet auth signers last).
+    async fn spend_with_subnet_auth(
+        &self,
+        subnet_id: ids::Id,
+        fee: u64,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        key::secp256k1::txs::Input,
+        Vec<Vec<T>>,
+    )> {
+        let (ins, unstaked_outs, _, signers) = self.spend(0, fee).await?;
+        let (subnet_auth, subnet_signers) = self.authorize(subnet_id).await?;
+        Ok((
+            ins,
+            unstaked_outs,
+            subnet_auth,
+            [signers, subnet_signers].concat(),
+        ))
+    }
+
+    /// Signs and issues the unsigned tx, and polls its status until committed
+    /// if "check_acceptance" is set. In "dry_mode", returns the tx Id without issuing.
+    /// The boolean return represents whether the tx was issued or not.
+    async fn sign_and_issue<U: codec::linear::Pack>(
+        &self,
+        tx_name: &str,
+        type_id: u32,
+        unsigned_tx: &U,
+        signers: Vec<Vec<T>>,
+        issuance: &Issuance,
+    ) -> Result<(ids::Id, bool)> {
+        let (_, metadata) = platformvm::txs::sign_tx(type_id, unsigned_tx, signers).await?;
+        if issuance.dry_mode {
+            return Ok((metadata.id, false));
+        }
+
+        let picked_http_rpc = self.inner.pick_base_http_url();
+        let hex_tx = formatting::encode_hex_with_checksum(&metadata.tx_bytes_with_signatures);
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue {} transaction {:?}", tx_name, e),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !issuance.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok((tx_id, true));
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", issuance.poll_initial_wait);
+        sleep(issuance.poll_initial_wait).await;
+
+        log::info!("polling to confirm {} transaction", tx_name);
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&issuance.poll_timeout) {
+                break;
+            }
+
+            let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == platformvm::txs::status::Status::Committed {
+                log::info!("{} successfully committed", tx_id);
+                success = true;
+                break;
             }
-            let (sig_indices, keys) = res.unwrap();
-
-            return Ok((
-                key::secp256k1::txs::Input {
-                    // if empty, it errors with "unauthorized subnet modification: input has less signers than expected"
-                    sig_indices,
-                },
-                vec![keys],
-            ));
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(issuance.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok((tx_id, true))
+    }
+
+    /// Issues the partially-signed tx once every signature slot is filled
+    /// (e.g., "build_unsigned" of the builders then signed by each key holder),
+    /// and returns the transaction Id.
+    pub async fn issue_partially_signed(
+        &self,
+        psbt: &txs::partial::PartiallySignedTx,
+    ) -> Result<ids::Id> {
+        let metadata = psbt.finalize()?;
+
+        let picked_http_rpc = self.inner.pick_base_http_url();
+        log::info!(
+            "issuing partially-signed transaction {} via {}",
+            metadata.id,
+            picked_http_rpc.1
+        );
+        let hex_tx = formatting::encode_hex_with_checksum(&metadata.tx_bytes_with_signatures);
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue partially-signed transaction {:?}", e),
+                retryable: false,
+            });
         }
 
-        return Err(Error::Other {
-            message: "empty get tx result".to_string(),
-            retryable: false,
-        });
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+        Ok(tx_id)
     }
 
     /// Subnet validators must validate the primary network.
@@ -421,6 +722,18 @@ where
         add_permissionless_validator::Tx::new(self)
     }
 
+    /// Delegates to an existing primary network validator.
+    #[must_use]
+    pub fn add_delegator(&self) -> add_delegator::Tx<T> {
+        add_delegator::Tx::new(self)
+    }
+
+    /// Delegates to an existing primary network or permissionless subnet validator.
+    #[must_use]
+    pub fn add_permissionless_delegator(&self) -> add_permissionless_delegator::Tx<T> {
+        add_permissionless_delegator::Tx::new(self)
+    }
+
     /// Once subnet is created, the avalanche node must whitelist the subnet Id
     /// (the returned/confirmed transaction Id).
     #[must_use]
@@ -435,6 +748,27 @@ where
         add_subnet_validator::Tx::new(self)
     }
 
+    /// Removes a validator from the permissioned subnet,
+    /// signed by the subnet owner keys.
+    #[must_use]
+    pub fn remove_subnet_validator(&self) -> remove_subnet_validator::Tx<T> {
+        remove_subnet_validator::Tx::new(self)
+    }
+
+    /// Transforms the permissioned subnet into an elastic subnet
+    /// with its own staking asset, signed by the subnet owner keys.
+    #[must_use]
+    pub fn transform_subnet(&self) -> transform_subnet::Tx<T> {
+        transform_subnet::Tx::new(self)
+    }
+
+    /// Transfers the subnet ownership to the new control keys,
+    /// signed by the current subnet owner keys.
+    #[must_use]
+    pub fn transfer_subnet_ownership(&self) -> transfer_subnet_ownership::Tx<T> {
+        transfer_subnet_ownership::Tx::new(self)
+    }
+
     /// Once the subnet validators are added, each virtual machine must create
     /// its own blockchain and use the chain Id as the RPC endpoint.
     #[must_use]
@@ -452,3 +786,41 @@ where
         import::Tx::new(self)
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- wallet::p::test_split_locked_output --exact --show-output
+#[test]
+fn test_split_locked_output() {
+    let asset_id = ids::Id::from_slice(&[1, 2, 3]);
+    let out = platformvm::txs::StakeableLockOut {
+        locktime: 12345,
+        transfer_output: key::secp256k1::txs::transfer::Output {
+            amount: 3000,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![ids::short::Id::from_slice(&[4, 5, 6])],
+            },
+        },
+    };
+    let locked = |amount: u64| txs::transferable::Output {
+        asset_id,
+        stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+            locktime: 12345,
+            transfer_output: key::secp256k1::txs::transfer::Output {
+                amount,
+                output_owners: out.transfer_output.output_owners.clone(),
+            },
+        }),
+        ..txs::transferable::Output::default()
+    };
+
+    // stakes the requested amount, and returns the rest still locked
+    let (staked, returned) = split_locked_output(asset_id, &out, 1000);
+    assert_eq!(staked, locked(1000));
+    assert_eq!(returned, Some(locked(2000)));
+
+    // nothing to return when the whole output is staked
+    let (staked, returned) = split_locked_output(asset_id, &out, 3000);
+    assert_eq!(staked, locked(3000));
+    assert_eq!(returned, None);
+}
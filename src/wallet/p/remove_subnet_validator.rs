@@ -0,0 +1,253 @@
+use std::sync::Arc;
+
+use crate::{
+    errors::{Error, Result},
+    ids::{self, node},
+    key, platformvm, txs, wallet,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Represents P-chain "RemoveSubnetValidator" transaction.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewRemoveSubnetValidatorTx"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/builder/builder.go> "NewRemoveSubnetValidatorTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    pub node_id: node::Id,
+    pub subnet_id: ids::Id,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+            node_id: node::Id::empty(),
+            subnet_id: ids::Id::empty(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the subnet validator node Id.
+    #[must_use]
+    pub fn node_id(mut self, node_id: node::Id) -> Self {
+        self.node_id = node_id;
+        self
+    }
+
+    /// Sets the subnet Id.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(
+        &self,
+    ) -> Result<(platformvm::txs::remove_subnet_validator::Tx, Vec<Vec<T>>)> {
+        let (ins, unstaked_outs, subnet_auth, signers) = self
+            .inner
+            .spend_with_subnet_auth(self.subnet_id, self.inner.inner.tx_fee)
+            .await?;
+
+        let tx = platformvm::txs::remove_subnet_validator::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            node_id: self.node_id,
+            subnet_id: self.subnet_id,
+            // if "sig_indices" empty, it errors with "unauthorized subnet modification: input has less signers than expected"
+            subnet_auth,
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::remove_subnet_validator::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                Some(self.subnet_id),
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the remove subnet validator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "remove_subnet_validator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// If the node is not a subnet validator, it returns an empty Id and false.
+    pub async fn issue(&self) -> Result<(ids::Id, bool)> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "removing {} from subnet {} validators via {}",
+            self.node_id,
+            self.subnet_id,
+            picked_http_rpc.1
+        );
+
+        let is_validator = self
+            .inner
+            .is_subnet_validator(&self.node_id, &self.subnet_id)
+            .await?;
+        if !is_validator {
+            log::warn!(
+                "node Id {} is not a subnet validator -- returning empty tx Id",
+                self.node_id
+            );
+            return Ok((ids::Id::empty(), false));
+        }
+
+        let cur_balance_p = self.inner.balance().await?;
+        if cur_balance_p < self.inner.inner.tx_fee {
+            return Err(Error::Other {
+                message: format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.inner.inner.tx_fee),
+                retryable: false,
+            });
+        };
+        log::info!(
+            "{} current P-chain balance {}",
+            self.inner.inner.p_address,
+            cur_balance_p
+        );
+
+        let (tx, signers) = self.unsigned_tx().await?;
+        let (tx_id, issued) = self
+            .inner
+            .sign_and_issue(
+                "remove subnet validator",
+                platformvm::txs::remove_subnet_validator::Tx::type_id(),
+                &tx,
+                signers,
+                &wallet::p::Issuance {
+                    dry_mode: self.dry_mode,
+                    check_acceptance: self.check_acceptance,
+                    poll_initial_wait: self.poll_initial_wait,
+                    poll_interval: self.poll_interval,
+                    poll_timeout: self.poll_timeout,
+                },
+            )
+            .await?;
+        if !issued || !self.check_acceptance {
+            return Ok((tx_id, issued));
+        }
+
+        log::info!("polling to confirm subnet validator removal");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let is_validator = self
+                .inner
+                .is_subnet_validator(&self.node_id, &self.subnet_id)
+                .await?;
+            if !is_validator {
+                log::info!("node Id {} is no longer a subnet validator", self.node_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "node Id {} is still a subnet validator (elapsed {:?})",
+                self.node_id,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check subnet validator removal in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok((tx_id, true))
+    }
+}
@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use crate::{errors::Result, ids, key, platformvm, txs, wallet};
+use tokio::time::Duration;
+
+/// Represents P-chain "TransferSubnetOwnership" transaction,
+/// to rotate the subnet control keys set with "create_subnet".
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.11.0/wallet/chain/p/builder.go> "NewTransferSubnetOwnershipTx"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    pub subnet_id: ids::Id,
+
+    /// New control keys, where the threshold signatures are required
+    /// from these addresses to modify the subnet.
+    pub control_keys: Vec<ids::short::Id>,
+    pub threshold: u32,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+            subnet_id: ids::Id::empty(),
+            control_keys: vec![p.inner.short_address.clone()],
+            threshold: 1,
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the subnet Id.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the new control keys.
+    #[must_use]
+    pub fn control_keys(mut self, control_keys: Vec<ids::short::Id>) -> Self {
+        self.control_keys = control_keys;
+        self
+    }
+
+    /// Sets the new threshold.
+    #[must_use]
+    pub fn threshold(mut self, threshold: u32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(
+        &self,
+    ) -> Result<(platformvm::txs::transfer_subnet_ownership::Tx, Vec<Vec<T>>)> {
+        let (ins, unstaked_outs, subnet_auth, signers) = self
+            .inner
+            .spend_with_subnet_auth(self.subnet_id, self.inner.inner.tx_fee)
+            .await?;
+
+        let mut control_keys = self.control_keys.clone();
+        control_keys.sort();
+
+        let tx = platformvm::txs::transfer_subnet_ownership::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_p,
+                transferable_outputs: Some(unstaked_outs),
+                transferable_inputs: Some(ins),
+                ..Default::default()
+            },
+            subnet_id: self.subnet_id,
+            subnet_auth,
+            owner: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: self.threshold,
+                addresses: control_keys,
+            },
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::transfer_subnet_ownership::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                Some(self.subnet_id),
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the transfer subnet ownership transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "transferring subnet {} ownership to {:?} (threshold {}) via {}",
+            self.subnet_id,
+            self.control_keys,
+            self.threshold,
+            picked_http_rpc.1
+        );
+
+        let (tx, signers) = self.unsigned_tx().await?;
+        let (tx_id, _) = self
+            .inner
+            .sign_and_issue(
+                "transfer subnet ownership",
+                platformvm::txs::transfer_subnet_ownership::Tx::type_id(),
+                &tx,
+                signers,
+                &wallet::p::Issuance {
+                    dry_mode: self.dry_mode,
+                    check_acceptance: self.check_acceptance,
+                    poll_initial_wait: self.poll_initial_wait,
+                    poll_interval: self.poll_interval,
+                    poll_timeout: self.poll_timeout,
+                },
+            )
+            .await?;
+        Ok(tx_id)
+    }
+}
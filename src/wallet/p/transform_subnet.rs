@@ -0,0 +1,330 @@
+use std::sync::Arc;
+
+use crate::{errors::Result, ids, key, platformvm, txs, units, wallet};
+use tokio::time::Duration;
+
+/// Represents P-chain "TransformSubnet" transaction.
+/// The parameters default to the primary network staking configuration.
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/wallet/chain/p/builder.go> "NewTransformSubnetTx"
+/// ref. <https://github.com/ava-labs/avalanchego/blob/v1.9.4/genesis/genesis_mainnet.go> "MainnetParams.StakingConfig"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::p::P<T>,
+
+    pub subnet_id: ids::Id,
+
+    /// Subnet staking asset, which must be imported to the P-chain
+    /// with at least "maximum_supply - initial_supply" to be burned.
+    pub asset_id: ids::Id,
+    pub initial_supply: u64,
+    pub maximum_supply: u64,
+
+    pub min_consumption_rate: u64,
+    pub max_consumption_rate: u64,
+
+    pub min_validator_stake: u64,
+    pub max_validator_stake: u64,
+
+    /// Minimum/maximum staking durations in seconds.
+    pub min_stake_duration: u32,
+    pub max_stake_duration: u32,
+
+    pub min_delegation_fee: u32,
+    pub min_delegator_stake: u64,
+    pub max_validator_weight_factor: u8,
+    pub uptime_requirement: u32,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+            subnet_id: ids::Id::empty(),
+            asset_id: ids::Id::empty(),
+            initial_supply: 240 * units::MEGA_AVAX,
+            maximum_supply: 720 * units::MEGA_AVAX,
+            min_consumption_rate: 100_000, // 10%
+            max_consumption_rate: 120_000, // 12%
+            min_validator_stake: 2 * units::KILO_AVAX,
+            max_validator_stake: 3 * units::MEGA_AVAX,
+            min_stake_duration: 14 * 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+            min_delegation_fee: 20_000, // 2%
+            min_delegator_stake: 25 * units::AVAX,
+            max_validator_weight_factor: 5,
+            uptime_requirement: 800_000, // 80%
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the subnet Id to transform.
+    #[must_use]
+    pub fn subnet_id(mut self, subnet_id: ids::Id) -> Self {
+        self.subnet_id = subnet_id;
+        self
+    }
+
+    /// Sets the subnet staking asset Id.
+    #[must_use]
+    pub fn asset_id(mut self, asset_id: ids::Id) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    /// Sets the initial supply of the staking asset.
+    #[must_use]
+    pub fn initial_supply(mut self, initial_supply: u64) -> Self {
+        self.initial_supply = initial_supply;
+        self
+    }
+
+    /// Sets the maximum supply of the staking asset.
+    #[must_use]
+    pub fn maximum_supply(mut self, maximum_supply: u64) -> Self {
+        self.maximum_supply = maximum_supply;
+        self
+    }
+
+    /// Sets the minimum consumption rate in "PERCENT_DENOMINATOR".
+    #[must_use]
+    pub fn min_consumption_rate(mut self, min_consumption_rate: u64) -> Self {
+        self.min_consumption_rate = min_consumption_rate;
+        self
+    }
+
+    /// Sets the maximum consumption rate in "PERCENT_DENOMINATOR".
+    #[must_use]
+    pub fn max_consumption_rate(mut self, max_consumption_rate: u64) -> Self {
+        self.max_consumption_rate = max_consumption_rate;
+        self
+    }
+
+    /// Sets the minimum validator stake.
+    #[must_use]
+    pub fn min_validator_stake(mut self, min_validator_stake: u64) -> Self {
+        self.min_validator_stake = min_validator_stake;
+        self
+    }
+
+    /// Sets the maximum validator stake.
+    #[must_use]
+    pub fn max_validator_stake(mut self, max_validator_stake: u64) -> Self {
+        self.max_validator_stake = max_validator_stake;
+        self
+    }
+
+    /// Sets the minimum stake duration in seconds.
+    #[must_use]
+    pub fn min_stake_duration(mut self, min_stake_duration: u32) -> Self {
+        self.min_stake_duration = min_stake_duration;
+        self
+    }
+
+    /// Sets the maximum stake duration in seconds.
+    #[must_use]
+    pub fn max_stake_duration(mut self, max_stake_duration: u32) -> Self {
+        self.max_stake_duration = max_stake_duration;
+        self
+    }
+
+    /// Sets the minimum delegation fee in "PERCENT_DENOMINATOR".
+    #[must_use]
+    pub fn min_delegation_fee(mut self, min_delegation_fee: u32) -> Self {
+        self.min_delegation_fee = min_delegation_fee;
+        self
+    }
+
+    /// Sets the minimum delegator stake.
+    #[must_use]
+    pub fn min_delegator_stake(mut self, min_delegator_stake: u64) -> Self {
+        self.min_delegator_stake = min_delegator_stake;
+        self
+    }
+
+    /// Sets the maximum validator weight factor.
+    #[must_use]
+    pub fn max_validator_weight_factor(mut self, max_validator_weight_factor: u8) -> Self {
+        self.max_validator_weight_factor = max_validator_weight_factor;
+        self
+    }
+
+    /// Sets the uptime requirement in "PERCENT_DENOMINATOR".
+    #[must_use]
+    pub fn uptime_requirement(mut self, uptime_requirement: u32) -> Self {
+        self.uptime_requirement = uptime_requirement;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(platformvm::txs::transform_subnet::Tx, Vec<Vec<T>>)> {
+        let mut tx = platformvm::txs::transform_subnet::Tx {
+            subnet_id: self.subnet_id,
+            asset_id: self.asset_id,
+            initial_supply: self.initial_supply,
+            maximum_supply: self.maximum_supply,
+            min_consumption_rate: self.min_consumption_rate,
+            max_consumption_rate: self.max_consumption_rate,
+            min_validator_stake: self.min_validator_stake,
+            max_validator_stake: self.max_validator_stake,
+            min_stake_duration: self.min_stake_duration,
+            max_stake_duration: self.max_stake_duration,
+            min_delegation_fee: self.min_delegation_fee,
+            min_delegator_stake: self.min_delegator_stake,
+            max_validator_weight_factor: self.max_validator_weight_factor,
+            uptime_requirement: self.uptime_requirement,
+            ..Default::default()
+        };
+        tx.verify_syntax(&self.inner.inner.avax_asset_id)?;
+
+        // the subnet asset yet to be minted as staking rewards must be burned
+        // ref. "avalanchego/vms/platformvm/txs/executor.StandardTxExecutor.TransformSubnetTx"
+        let (mut ins, mut unstaked_outs, _, mut signers) = self
+            .inner
+            .spend(0, self.inner.inner.transform_subnet_tx_fee)
+            .await?;
+        let (asset_ins, asset_outs, asset_signers) = self
+            .inner
+            .burn_asset(self.asset_id, self.maximum_supply - self.initial_supply)
+            .await?;
+        ins.extend(asset_ins);
+        unstaked_outs.extend(asset_outs);
+        signers.extend(asset_signers);
+
+        // TODO: for now just ignore "signers" in the sorting
+        // since the wallet currently only supports one key
+        ins.sort();
+        unstaked_outs.sort();
+
+        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+
+        tx.base_tx = txs::Tx {
+            network_id: self.inner.inner.network_id,
+            blockchain_id: self.inner.inner.blockchain_id_p,
+            transferable_outputs: Some(unstaked_outs),
+            transferable_inputs: Some(ins),
+            ..Default::default()
+        };
+        tx.subnet_auth = subnet_auth;
+        let signers = [signers, subnet_signers].concat();
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::p::P::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        self.inner
+            .partially_signed(
+                platformvm::txs::transform_subnet::Tx::type_id(),
+                &tx,
+                tx.base_tx
+                    .transferable_inputs
+                    .as_deref()
+                    .unwrap_or_default(),
+                Some(self.subnet_id),
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the transform subnet transaction and returns the transaction Id.
+    /// The parameters are verified before spending any UTXO.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "transforming subnet {} with asset {} via {}",
+            self.subnet_id,
+            self.asset_id,
+            picked_http_rpc.1
+        );
+
+        let (tx, signers) = self.unsigned_tx().await?;
+        let (tx_id, _) = self
+            .inner
+            .sign_and_issue(
+                "transform subnet",
+                platformvm::txs::transform_subnet::Tx::type_id(),
+                &tx,
+                signers,
+                &wallet::p::Issuance {
+                    dry_mode: self.dry_mode,
+                    check_acceptance: self.check_acceptance,
+                    poll_initial_wait: self.poll_initial_wait,
+                    poll_interval: self.poll_interval,
+                    poll_timeout: self.poll_timeout,
+                },
+            )
+            .await?;
+        Ok(tx_id)
+    }
+}
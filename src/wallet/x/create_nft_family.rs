@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use crate::{
+    avm,
+    choices::status::Status,
+    errors::{Error, Result},
+    formatting, ids,
+    jsonrpc::client::x as client_x,
+    key, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Creates a new NFT family on the X-chain, where each minter set
+/// owns the minting right of the group at its index.
+/// ref. "avalanchego/vms/avm.Service.CreateNFTAsset"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::x::X<T>,
+
+    /// Human-readable name of the NFT family.
+    pub name: String,
+    /// Ticker symbol of the NFT family (up to 4 upper case letters).
+    pub symbol: String,
+
+    /// Owners of the minting rights, where the index is the group ID.
+    pub minters: Vec<key::secp256k1::txs::OutputOwners>,
+
+    /// Set "true" to poll transfer status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(x: &crate::wallet::x::X<T>) -> Self {
+        Self {
+            inner: x.clone(),
+            name: String::new(),
+            symbol: String::new(),
+            minters: Vec::new(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(700),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the NFT family name.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the NFT family symbol.
+    #[must_use]
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Adds the minting right of the next group owned by the owners.
+    #[must_use]
+    pub fn minter(mut self, owners: key::secp256k1::txs::OutputOwners) -> Self {
+        self.minters.push(owners);
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Returns the initial state with the sorted minting rights of the groups.
+    pub fn initial_states(&self) -> Vec<avm::txs::create_asset::InitialState> {
+        let mut outputs: Vec<avm::txs::create_asset::Output> = self
+            .minters
+            .iter()
+            .enumerate()
+            .map(|(group_id, owners)| {
+                avm::txs::create_asset::Output::NftMint(avm::nftfx::MintOutput::new(
+                    group_id as u32,
+                    owners.clone(),
+                ))
+            })
+            .collect();
+        if outputs.is_empty() {
+            return Vec::new();
+        }
+        outputs.sort();
+
+        vec![avm::txs::create_asset::InitialState::new(
+            avm::txs::fx::NFT_FX_INDEX,
+            outputs,
+        )]
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(avm::txs::create_asset::Tx, Vec<Vec<T>>)> {
+        let (inputs, change_outputs, signers) = self
+            .inner
+            .spend(
+                &self.inner.inner.avax_asset_id,
+                self.inner.inner.create_asset_tx_fee,
+            )
+            .await?;
+
+        let tx = avm::txs::create_asset::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_x,
+                transferable_outputs: Some(change_outputs),
+                transferable_inputs: Some(inputs),
+                ..Default::default()
+            },
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            states: self.initial_states(),
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::x::X::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        let utxo_ids: Vec<&txs::utxo::Id> = tx
+            .base_tx
+            .transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .collect();
+        self.inner
+            .partially_signed(
+                avm::txs::create_asset::Tx::type_id(),
+                &tx,
+                &utxo_ids,
+                &signers,
+            )
+            .await
+    }
+
+    /// Issues the create asset transaction and returns the transaction Id,
+    /// which is also the new NFT family asset Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "creating NFT family {} ({}) with {} groups via {}",
+            self.name,
+            self.symbol,
+            self.minters.len(),
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.base_tx.metadata.unwrap().id);
+        }
+
+        let tx_bytes_with_signatures = tx
+            .base_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if resp.result.is_none() {
+            return Err(Error::API {
+                message: format!("failed to issue tx {:?} (no result)", resp.error),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm create asset transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}
@@ -1,4 +1,4 @@
-use std::{cmp, time::SystemTime};
+use std::sync::Arc;
 
 use crate::{
     avm,
@@ -98,6 +98,17 @@ where
         self
     }
 
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -105,29 +116,19 @@ where
         self
     }
 
-    /// Issues the export transaction and returns the transaction Id.
-    pub async fn issue(&self) -> Result<ids::Id> {
-        let picked_http_rpc = self.inner.inner.pick_base_http_url();
-        log::info!(
-            "exporting {} AVAX from {} to {} via {}",
-            self.amount,
-            self.inner.inner.short_address,
-            self.destination_blockchain_id,
-            picked_http_rpc.1
-        );
-
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(avm::txs::export::Tx, Vec<Vec<T>>)> {
+        let (inputs, change_outputs, signers) = self
+            .inner
+            .spend(
+                &self.inner.inner.avax_asset_id,
+                self.amount + self.inner.inner.tx_fee,
+            )
+            .await?;
+        if inputs.len() > 1 {
+            log::debug!("signing for multiple inputs ({} inputs)", inputs.len());
+        }
 
-        let mut inputs: Vec<txs::transferable::Input> = Vec::new();
         let outputs: Vec<txs::transferable::Output> = vec![
             // receiver
             txs::transferable::Output {
@@ -144,81 +145,12 @@ where
             },
         ];
 
-        let mut change_outputs: Vec<txs::transferable::Output> = Vec::new();
-
-        // ref. "avalanchego/wallet/chain/x"
-        // "math.Add64(toBurn[assetID], out.Out.Amount())"
-        let mut remaining_amount_to_burn = self.amount + self.inner.inner.tx_fee;
-
-        // ref. "avalanchego/vms/avm#Service.SendMultiple"
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
-
-        for utxo in utxos.iter() {
-            if utxo.asset_id != self.inner.inner.avax_asset_id {
-                continue;
-            }
-
-            // consumed enough, no need to burn more
-            if remaining_amount_to_burn == 0 {
-                continue;
-            }
-
-            if let Some(out) = &utxo.transfer_output {
-                let (input, _) = self.inner.inner.keychain.spend(out, now_unix).unwrap();
-
-                inputs.push(txs::transferable::Input {
-                    utxo_id: utxo.utxo_id.clone(),
-                    asset_id: utxo.asset_id.clone(),
-                    transfer_input: Some(input),
-                    ..Default::default()
-                });
-
-                // burn any value that should be burned
-                let amount_to_burn = cmp::min(
-                    remaining_amount_to_burn, // amount we still need to burn
-                    out.amount,               // amount available to burn
-                );
-                remaining_amount_to_burn -= amount_to_burn;
-
-                let remaining_amount = out.amount - amount_to_burn;
-                if remaining_amount > 0 {
-                    // this input had extra value, so some must be returned
-                    change_outputs.push(txs::transferable::Output {
-                        asset_id: self.inner.inner.avax_asset_id.clone(),
-                        transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                            amount: remaining_amount,
-                            output_owners: key::secp256k1::txs::OutputOwners {
-                                locktime: 0,
-                                threshold: 1,
-                                addresses: vec![self.inner.inner.short_address.clone()],
-                            },
-                        }),
-                        ..Default::default()
-                    })
-                }
-            }
-        }
-        inputs.sort();
-        change_outputs.sort();
-
-        // make sure it does not incur "tx has 1 credentials but 2 inputs. Should be same" error
-        let mut signers: Vec<Vec<T>> = Vec::new();
-        for _ in 0..inputs.len() {
-            signers.push(vec![self.inner.inner.keychain.keys[0].clone()]);
-        }
-        if inputs.len() > 1 {
-            log::debug!("signing for multiple inputs ({} inputs)", inputs.len());
-        }
-
         log::debug!(
             "baseTx has {} inputs and {} outputs",
             inputs.len(),
             change_outputs.len()
         );
-        let mut tx = avm::txs::export::Tx {
+        let tx = avm::txs::export::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
                 blockchain_id: self.inner.inner.blockchain_id_x.clone(),
@@ -230,6 +162,38 @@ where
             destination_chain_transferable_outputs: Some(outputs),
             ..Default::default()
         };
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::x::X::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        let utxo_ids: Vec<&txs::utxo::Id> = tx
+            .base_tx
+            .transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .collect();
+        self.inner
+            .partially_signed(avm::txs::export::Tx::type_id(), &tx, &utxo_ids, &signers)
+            .await
+    }
+
+    /// Issues the export transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "exporting {} AVAX from {} to {} via {}",
+            self.amount,
+            self.inner.inner.short_address,
+            self.destination_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
@@ -94,15 +94,9 @@ where
         self
     }
 
-    /// Issues the import transaction and returns the transaction Id.
-    pub async fn issue(&self) -> Result<ids::Id> {
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(avm::txs::import::Tx, Vec<Vec<T>>)> {
         let picked_http_rpc = self.inner.inner.pick_base_http_url();
-        log::info!(
-            "importing from {} via {}",
-            self.source_blockchain_id,
-            picked_http_rpc.1
-        );
-
         // TODO: paginate next results
         let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
         let utxos_result = utxos.result.unwrap();
@@ -187,7 +181,7 @@ where
             import_inputs.len(),
             outputs.len()
         );
-        let mut tx = avm::txs::import::Tx {
+        let tx = avm::txs::import::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
                 blockchain_id: self.inner.inner.blockchain_id_p.clone(),
@@ -198,6 +192,35 @@ where
             source_chain_transferable_inputs: Some(import_inputs),
             ..Default::default()
         };
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::x::X::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        let utxo_ids: Vec<&txs::utxo::Id> = tx
+            .source_chain_transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .collect();
+        self.inner
+            .partially_signed(avm::txs::import::Tx::type_id(), &tx, &utxo_ids, &signers)
+            .await
+    }
+
+    /// Issues the import transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "importing from {} via {}",
+            self.source_blockchain_id,
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
@@ -1,8 +1,23 @@
+pub mod burn_property;
+pub mod create_asset;
+pub mod create_nft_family;
 pub mod export;
 pub mod import;
+pub mod mint;
+pub mod mint_nft;
+pub mod mint_property;
 pub mod transfer;
+pub mod transfer_nft;
 
-use crate::{errors::Result, jsonrpc::client::x as client_x, key, txs, wallet};
+use std::{cmp, collections::BTreeMap, sync::Arc, time::SystemTime};
+
+use crate::{
+    codec,
+    errors::{Error, Result},
+    formatting, ids,
+    jsonrpc::client::x as client_x,
+    key, txs, wallet,
+};
 
 impl<T> wallet::Wallet<T>
 where
@@ -12,6 +27,7 @@ where
     pub fn x(&self) -> X<T> {
         X {
             inner: self.clone(),
+            coin_selection: Arc::new(txs::utxo::Order::UtxoId),
         }
     }
 }
@@ -22,6 +38,9 @@ where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
 {
     pub inner: crate::wallet::Wallet<T>,
+
+    /// Coin selection strategy to pick the UTXOs to spend.
+    pub coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
 }
 
 impl<T> X<T>
@@ -73,11 +92,185 @@ where
         Ok(utxos)
     }
 
+    /// Fetches the UTXOs of the wallet "X" chain address to spend.
+    async fn utxo_set(&self) -> Result<txs::utxo::Set> {
+        // TODO: paginate next results
+        let picked_http_rpc = self.inner.pick_base_http_url();
+        let resp = client_x::get_utxos(&picked_http_rpc.1, &self.inner.x_address).await?;
+        let utxos_result = resp.result.unwrap();
+        let utxos = utxos_result.utxos.unwrap();
+        log::debug!(
+            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
+            utxos_result.num_fetched,
+            utxos_result.end_index,
+            utxos.len()
+        );
+        Ok(txs::utxo::Set::new(utxos))
+    }
+
+    /// Consumes the unlocked UTXOs of the asset to cover the amount,
+    /// picked by the coin selection strategy. Returns the sorted inputs with
+    /// their signers, and the change outputs to the wallet address.
+    /// ref. "avalanchego/wallet/chain/x.builder.spend"
+    async fn spend(
+        &self,
+        asset_id: &ids::Id,
+        amount: u64,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        Vec<Vec<T>>,
+    )> {
+        self.spend_multiple(&BTreeMap::from([(*asset_id, amount)]))
+            .await
+    }
+
+    /// Same as "spend" but covers the amounts of multiple assets at once,
+    /// returning the change outputs per asset.
+    /// ref. "avalanchego/wallet/chain/x.builder.spend"
+    async fn spend_multiple(
+        &self,
+        amounts: &BTreeMap<ids::Id, u64>,
+    ) -> Result<(
+        Vec<txs::transferable::Input>,
+        Vec<txs::transferable::Output>,
+        Vec<Vec<T>>,
+    )> {
+        let utxos = self.utxo_set().await?;
+
+        // ref. "avalanchego/vms/avm#Service.SendMultiple"
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        let mut inputs: Vec<(txs::transferable::Input, Vec<T>)> = Vec::new();
+        let mut change_outputs: Vec<txs::transferable::Output> = Vec::new();
+        for (asset_id, &amount) in amounts.iter() {
+            let candidates: Vec<&txs::utxo::Utxo> = utxos
+                .by_asset(asset_id, txs::utxo::LockState::Unlocked, now_unix)
+                .into_iter()
+                .filter(|utxo| match &utxo.transfer_output {
+                    Some(out) => self.inner.keychain.spend(out, now_unix).is_some(),
+                    None => false,
+                })
+                .collect();
+            let selected =
+                txs::coin_selection::select(self.coin_selection.as_ref(), candidates, amount)
+                    .ok_or_else(|| Error::Other {
+                        message: format!(
+                            "provided keys do not have enough balance of asset {} (need {})",
+                            asset_id, amount
+                        ),
+                        retryable: false,
+                    })?;
+
+            // ref. "avalanchego/wallet/chain/x"
+            // "math.Add64(toBurn[assetID], out.Out.Amount())"
+            let mut remaining_amount_to_burn = amount;
+            for utxo in selected {
+                let out = utxo.transfer_output.as_ref().unwrap();
+                let (input, signers) = self.inner.keychain.spend(out, now_unix).unwrap();
+                inputs.push((
+                    txs::transferable::Input {
+                        utxo_id: utxo.utxo_id.clone(),
+                        asset_id: utxo.asset_id,
+                        transfer_input: Some(input),
+                        ..Default::default()
+                    },
+                    signers,
+                ));
+
+                // burn any value that should be burned
+                let amount_to_burn = cmp::min(
+                    remaining_amount_to_burn, // amount we still need to burn
+                    out.amount,               // amount available to burn
+                );
+                remaining_amount_to_burn -= amount_to_burn;
+
+                let remaining_amount = out.amount - amount_to_burn;
+                if remaining_amount > 0 {
+                    // this input had extra value, so some must be returned
+                    change_outputs.push(txs::transferable::Output {
+                        asset_id: utxo.asset_id,
+                        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                            amount: remaining_amount,
+                            output_owners: key::secp256k1::txs::OutputOwners {
+                                locktime: 0,
+                                threshold: 1,
+                                addresses: vec![self.inner.short_address.clone()],
+                            },
+                        }),
+                        ..Default::default()
+                    })
+                }
+            }
+        }
+
+        // make sure it does not incur "tx has 1 credentials but 2 inputs. Should be same" error
+        // thus sort the signers in the same order of the inputs
+        inputs.sort_by(|a, b| a.0.cmp(&b.0));
+        change_outputs.sort();
+        let (inputs, signers) = inputs.into_iter().unzip();
+
+        Ok((inputs, change_outputs, signers))
+    }
+
+    /// Builds the partially-signed tx with the owners of each credential,
+    /// looked up from the wallet UTXOs that the credentials spend.
+    async fn partially_signed<U: codec::linear::Pack>(
+        &self,
+        type_id: u32,
+        unsigned_tx: &U,
+        utxo_ids: &[&txs::utxo::Id],
+        signers: &[Vec<T>],
+    ) -> Result<txs::partial::PartiallySignedTx> {
+        let utxos = self.utxo_set().await?;
+        Ok(
+            txs::partial::PartiallySignedTx::from_signers(type_id, unsigned_tx, signers)?
+                .owners_from_utxos(&utxos, utxo_ids),
+        )
+    }
+
+    /// Issues the partially-signed tx once every signature slot is filled
+    /// (e.g., "build_unsigned" of the builders then signed by each key holder),
+    /// and returns the transaction Id.
+    pub async fn issue_partially_signed(
+        &self,
+        psbt: &txs::partial::PartiallySignedTx,
+    ) -> Result<ids::Id> {
+        let metadata = psbt.finalize()?;
+
+        let picked_http_rpc = self.inner.pick_base_http_url();
+        log::info!(
+            "issuing partially-signed transaction {} via {}",
+            metadata.id,
+            picked_http_rpc.1
+        );
+        let hex_tx = formatting::encode_hex_with_checksum(&metadata.tx_bytes_with_signatures);
+        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        if let Some(e) = resp.error {
+            return Err(Error::API {
+                message: format!("failed to issue partially-signed transaction {:?}", e),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+        Ok(tx_id)
+    }
+
     #[must_use]
     pub fn transfer(&self) -> transfer::Tx<T> {
         transfer::Tx::new(self)
     }
 
+    #[must_use]
+    pub fn create_asset(&self) -> create_asset::Tx<T> {
+        create_asset::Tx::new(self)
+    }
+
     #[must_use]
     pub fn export(&self) -> export::Tx<T> {
         export::Tx::new(self)
@@ -87,4 +280,34 @@ where
     pub fn import(&self) -> import::Tx<T> {
         import::Tx::new(self)
     }
+
+    #[must_use]
+    pub fn mint(&self) -> mint::Tx<T> {
+        mint::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn create_nft_family(&self) -> create_nft_family::Tx<T> {
+        create_nft_family::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn mint_nft(&self) -> mint_nft::Tx<T> {
+        mint_nft::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn transfer_nft(&self) -> transfer_nft::Tx<T> {
+        transfer_nft::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn mint_property(&self) -> mint_property::Tx<T> {
+        mint_property::Tx::new(self)
+    }
+
+    #[must_use]
+    pub fn burn_property(&self) -> burn_property::Tx<T> {
+        burn_property::Tx::new(self)
+    }
 }
@@ -1,4 +1,4 @@
-use std::{cmp, time::SystemTime};
+use std::{collections::BTreeMap, sync::Arc};
 
 use crate::{
     avm,
@@ -11,6 +11,9 @@ use crate::{
 };
 use tokio::time::{sleep, Duration, Instant};
 
+/// Transfers the X-chain assets to the receivers in a single base tx,
+/// paying the fee in AVAX and returning the change per asset.
+/// ref. "avalanchego/wallet/chain/x.builder.NewBaseTx"
 #[derive(Clone, Debug)]
 pub struct Tx<T>
 where
@@ -18,10 +21,14 @@ where
 {
     pub inner: crate::wallet::x::X<T>,
 
-    /// Transfer fund receiver address.
+    /// Transfer outputs of the asset, the receiver owners and the amount.
+    pub outputs: Vec<(ids::Id, key::secp256k1::txs::OutputOwners, u64)>,
+
+    /// Transfer fund receiver address of the AVAX "amount",
+    /// in addition to the "outputs" (kept for the existing callers).
     pub receiver: short::Id,
 
-    /// Transfer amount.
+    /// Transfer amount of AVAX to the "receiver".
     pub amount: u64,
 
     /// Set "true" to poll transfer status after issuance for its acceptance.
@@ -45,6 +52,7 @@ where
     pub fn new(x: &crate::wallet::x::X<T>) -> Self {
         Self {
             inner: x.clone(),
+            outputs: Vec::new(),
             receiver: short::Id::empty(),
             amount: 0,
             check_acceptance: false,
@@ -55,20 +63,72 @@ where
         }
     }
 
-    /// Sets the transfer fund receiver address.
+    /// Adds the transfer output of the asset amount to the receiver owners.
+    #[must_use]
+    pub fn output(
+        mut self,
+        asset_id: ids::Id,
+        owners: key::secp256k1::txs::OutputOwners,
+        amount: u64,
+    ) -> Self {
+        self.outputs.push((asset_id, owners, amount));
+        self
+    }
+
+    /// Sets the transfer outputs.
+    #[must_use]
+    pub fn outputs(
+        mut self,
+        outputs: Vec<(ids::Id, key::secp256k1::txs::OutputOwners, u64)>,
+    ) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    /// Sets the transfer fund receiver address of the AVAX amount.
+    #[deprecated(note = "use \"output\" with the AVAX asset Id instead")]
     #[must_use]
     pub fn receiver(mut self, receiver: short::Id) -> Self {
         self.receiver = receiver;
         self
     }
 
-    /// Sets the transfer amount.
+    /// Sets the transfer amount of AVAX to the receiver.
+    #[deprecated(note = "use \"output\" with the AVAX asset Id instead")]
     #[must_use]
     pub fn amount(mut self, amount: u64) -> Self {
         self.amount = amount;
         self
     }
 
+    /// Returns the transfer outputs, including the AVAX output
+    /// to the "receiver" if its "amount" is set.
+    fn transfer_outputs(&self) -> Vec<(ids::Id, key::secp256k1::txs::OutputOwners, u64)> {
+        let mut outputs = self.outputs.clone();
+        if self.amount > 0 {
+            outputs.push((
+                self.inner.inner.avax_asset_id,
+                key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&self.receiver)),
+                self.amount,
+            ));
+        }
+        outputs
+    }
+
+    /// Returns the amount to spend for each asset, including the AVAX fee.
+    pub fn amounts_to_spend(&self) -> Result<BTreeMap<ids::Id, u64>> {
+        let mut amounts = BTreeMap::new();
+        amounts.insert(self.inner.inner.avax_asset_id, self.inner.inner.tx_fee);
+        for (asset_id, _, amount) in self.transfer_outputs().iter() {
+            let total = amounts.entry(*asset_id).or_insert(0_u64);
+            *total = total.checked_add(*amount).ok_or_else(|| Error::Other {
+                message: format!("overflow in the transfer amount of asset {}", asset_id),
+                retryable: false,
+            })?;
+        }
+        Ok(amounts)
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -97,6 +157,17 @@ where
         self
     }
 
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
     /// Sets the dry mode boolean flag.
     #[must_use]
     pub fn dry_mode(mut self, dry_mode: bool) -> Self {
@@ -104,129 +175,78 @@ where
         self
     }
 
-    /// Issues the transfer transaction and returns the transaction Id.
-    pub async fn issue(&self) -> Result<ids::Id> {
-        let picked_http_rpc = self.inner.inner.pick_base_http_url();
-        log::info!(
-            "transferring {} AVAX from {} to {} via {}",
-            self.amount,
-            self.inner.inner.short_address,
-            self.receiver,
-            picked_http_rpc.1
-        );
-
-        // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/wallet/chain/p/builder.go
-        // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/add_validator_tx.go#L263
-        // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/spend.go#L39 "stake"
-        // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
-        // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
-        // TODO: paginate next results
-        let utxos = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let utxos_result = utxos.result.unwrap();
-        let utxos = utxos_result.utxos.unwrap();
-        log::debug!(
-            "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
-            utxos_result.num_fetched,
-            utxos_result.end_index,
-            utxos.len()
-        );
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(avm::txs::Tx, Vec<Vec<T>>)> {
+        let transfer_outputs = self.transfer_outputs();
+        if transfer_outputs.is_empty() {
+            return Err(Error::Other {
+                message: "no transfer output".to_string(),
+                retryable: false,
+            });
+        }
 
-        let mut inputs: Vec<txs::transferable::Input> = Vec::new();
-        let mut outputs: Vec<txs::transferable::Output> = vec![
-            // receiver
-            txs::transferable::Output {
-                asset_id: self.inner.inner.avax_asset_id.clone(),
-                transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                    amount: self.amount,
-                    output_owners: key::secp256k1::txs::OutputOwners {
-                        locktime: 0,
-                        threshold: 1,
-                        addresses: vec![self.receiver.clone()],
-                    },
-                }),
+        let mut outputs: Vec<txs::transferable::Output> = Vec::new();
+        for (asset_id, owners, amount) in transfer_outputs.iter() {
+            let transfer_output =
+                key::secp256k1::txs::transfer::Output::new(*amount, owners.clone());
+            transfer_output.verify_syntax()?;
+            outputs.push(txs::transferable::Output {
+                asset_id: *asset_id,
+                transfer_output: Some(transfer_output),
                 ..Default::default()
-            },
-        ];
-
-        // ref. "avalanchego/wallet/chain/x"
-        // "math.Add64(toBurn[assetID], out.Out.Amount())"
-        let mut remaining_amount_to_burn = self.amount + self.inner.inner.tx_fee;
-
-        // ref. "avalanchego/vms/avm#Service.SendMultiple"
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
-
-        for utxo in utxos.iter() {
-            if utxo.asset_id != self.inner.inner.avax_asset_id {
-                continue;
-            }
-
-            // consumed enough, no need to burn more
-            if remaining_amount_to_burn == 0 {
-                continue;
-            }
-
-            if let Some(out) = &utxo.transfer_output {
-                let (input, _) = self.inner.inner.keychain.spend(out, now_unix).unwrap();
-
-                inputs.push(txs::transferable::Input {
-                    utxo_id: utxo.utxo_id.clone(),
-                    asset_id: utxo.asset_id.clone(),
-                    transfer_input: Some(input),
-                    ..Default::default()
-                });
-
-                // burn any value that should be burned
-                let amount_to_burn = cmp::min(
-                    remaining_amount_to_burn, // amount we still need to burn
-                    out.amount,               // amount available to burn
-                );
-                remaining_amount_to_burn -= amount_to_burn;
-
-                let remaining_amount = out.amount - amount_to_burn;
-                if remaining_amount > 0 {
-                    // this input had extra value, so some must be returned
-                    outputs.push(txs::transferable::Output {
-                        asset_id: self.inner.inner.avax_asset_id.clone(),
-                        transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                            amount: remaining_amount,
-                            output_owners: key::secp256k1::txs::OutputOwners {
-                                locktime: 0,
-                                threshold: 1,
-                                addresses: vec![self.inner.inner.short_address.clone()],
-                            },
-                        }),
-                        ..Default::default()
-                    })
-                }
-            }
+            });
         }
-        inputs.sort();
-        outputs.sort();
 
-        // make sure it does not incur "tx has 1 credentials but 2 inputs. Should be same" error
-        let mut signers: Vec<Vec<T>> = Vec::new();
-        for _ in 0..inputs.len() {
-            signers.push(vec![self.inner.inner.keychain.keys[0].clone()]);
-        }
+        let (inputs, change_outputs, signers) =
+            self.inner.spend_multiple(&self.amounts_to_spend()?).await?;
         if inputs.len() > 1 {
             log::debug!("signing for multiple inputs ({} inputs)", inputs.len());
         }
+        outputs.extend(change_outputs);
+        outputs.sort();
 
         log::debug!(
             "baseTx has {} inputs and {} outputs",
             inputs.len(),
             outputs.len()
         );
-        let mut tx = avm::txs::Tx::new(txs::Tx {
+        let tx = avm::txs::Tx::new(txs::Tx {
             network_id: self.inner.inner.network_id,
-            blockchain_id: self.inner.inner.blockchain_id_x.clone(),
+            blockchain_id: self.inner.inner.blockchain_id_x,
             transferable_outputs: Some(outputs),
             transferable_inputs: Some(inputs.clone()),
             ..Default::default()
         });
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::x::X::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        let utxo_ids: Vec<&txs::utxo::Id> = tx
+            .base_tx
+            .transferable_inputs
+            .iter()
+            .flatten()
+            .map(|input| &input.utxo_id)
+            .collect();
+        self.inner
+            .partially_signed(avm::txs::Tx::type_id(), &tx, &utxo_ids, &signers)
+            .await
+    }
+
+    /// Issues the transfer transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "transferring {} outputs from {} via {}",
+            self.transfer_outputs().len(),
+            self.inner.inner.short_address,
+            picked_http_rpc.1
+        );
+        let (mut tx, signers) = self.unsigned_tx().await?;
         tx.sign(signers).await?;
 
         if self.dry_mode {
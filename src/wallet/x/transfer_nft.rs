@@ -0,0 +1,313 @@
+use std::{sync::Arc, time::SystemTime};
+
+use crate::{
+    avm,
+    choices::status::Status,
+    errors::{Error, Result},
+    formatting,
+    ids::{self, short},
+    jsonrpc::client::x as client_x,
+    key, txs,
+};
+use tokio::time::{sleep, Duration, Instant};
+
+/// Transfers the NFT of the X-chain NFT family owned by the wallet
+/// to the receiver, keeping its group and payload.
+/// ref. "avalanchego/vms/avm.Service.SendNFT"
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::wallet::x::X<T>,
+
+    /// NFT family to transfer.
+    pub asset_id: ids::Id,
+    /// Group of the NFT to transfer.
+    pub group_id: u32,
+
+    /// NFT receiver address.
+    pub receiver: short::Id,
+
+    /// Set "true" to poll transfer status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Id for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(x: &crate::wallet::x::X<T>) -> Self {
+        Self {
+            inner: x.clone(),
+            asset_id: ids::Id::empty(),
+            group_id: 0,
+            receiver: short::Id::empty(),
+            check_acceptance: false,
+            poll_initial_wait: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(700),
+            poll_timeout: Duration::from_secs(300),
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the NFT family to transfer.
+    #[must_use]
+    pub fn asset_id(mut self, asset_id: ids::Id) -> Self {
+        self.asset_id = asset_id;
+        self
+    }
+
+    /// Sets the group of the NFT to transfer.
+    #[must_use]
+    pub fn group_id(mut self, group_id: u32) -> Self {
+        self.group_id = group_id;
+        self
+    }
+
+    /// Sets the NFT receiver address.
+    #[must_use]
+    pub fn receiver(mut self, receiver: short::Id) -> Self {
+        self.receiver = receiver;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the coin selection strategy to pick the UTXOs to spend
+    /// (e.g., "txs::coin_selection::Consolidate").
+    #[must_use]
+    pub fn coin_selection(
+        mut self,
+        coin_selection: Arc<dyn txs::coin_selection::CoinSelector>,
+    ) -> Self {
+        self.inner.coin_selection = coin_selection;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Builds the unsigned transaction, and returns it with the signers of each credential.
+    async fn unsigned_tx(&self) -> Result<(avm::txs::operation::Tx, Vec<Vec<T>>)> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        // TODO: paginate next results
+        let resp = client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
+        let utxos = resp
+            .result
+            .expect("unexpected None GetUtxosResult")
+            .utxos
+            .expect("unexpected None Utxos");
+
+        let now_unix = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("unexpected None duration_since")
+            .as_secs();
+
+        // ref. "avalanchego/vms/avm.Service.buildSendNFT"
+        let mut nft = None;
+        for utxo in utxos.iter() {
+            if utxo.asset_id != self.asset_id {
+                continue;
+            }
+            if let Some(out) = &utxo.nft_transfer_output {
+                if out.group_id != self.group_id {
+                    continue;
+                }
+                if let Some((sig_indices, keys)) = self
+                    .inner
+                    .inner
+                    .keychain
+                    .match_threshold(&out.output_owners, now_unix)
+                {
+                    nft = Some((utxo, out, sig_indices, keys));
+                    break;
+                }
+            }
+        }
+        let (utxo, nft_output, sig_indices, nft_signers) = nft.ok_or_else(|| Error::Other {
+            message: format!(
+                "provided keys do not own the NFT of asset {} group {}",
+                self.asset_id, self.group_id
+            ),
+            retryable: false,
+        })?;
+
+        let (inputs, change_outputs, mut signers) = self
+            .inner
+            .spend(&self.inner.inner.avax_asset_id, self.inner.inner.tx_fee)
+            .await?;
+
+        // credentials for the base tx inputs, followed by the operation
+        signers.push(nft_signers);
+
+        let tx = avm::txs::operation::Tx {
+            base_tx: txs::Tx {
+                network_id: self.inner.inner.network_id,
+                blockchain_id: self.inner.inner.blockchain_id_x,
+                transferable_outputs: Some(change_outputs),
+                transferable_inputs: Some(inputs),
+                ..Default::default()
+            },
+            operations: vec![avm::txs::operation::Operation::new(
+                self.asset_id,
+                vec![utxo.utxo_id.clone()],
+                avm::txs::operation::FxOperation::NftTransfer(avm::nftfx::TransferOperation::new(
+                    key::secp256k1::txs::Input::new(sig_indices),
+                    avm::nftfx::TransferOutput::new(
+                        nft_output.group_id,
+                        nft_output.payload.clone(),
+                        key::secp256k1::txs::OutputOwners::new(
+                            0,
+                            1,
+                            std::slice::from_ref(&self.receiver),
+                        ),
+                    ),
+                )),
+            )],
+            ..Default::default()
+        };
+        tx.verify_syntax()?;
+        Ok((tx, signers))
+    }
+
+    /// Builds the transaction without signing, and returns the partially-signed tx
+    /// with the address slots and the owners of each credential, to be signed
+    /// by each key holder and issued with "wallet::x::X::issue_partially_signed".
+    pub async fn build_unsigned(&self) -> Result<txs::partial::PartiallySignedTx> {
+        let (tx, signers) = self.unsigned_tx().await?;
+        let fx_ids = tx.credential_fx_ids(signers.len());
+        Ok(self
+            .inner
+            .partially_signed(
+                avm::txs::operation::Tx::type_id(),
+                &tx,
+                &tx.credential_utxo_ids(),
+                &signers,
+            )
+            .await?
+            .fx_ids(&fx_ids))
+    }
+
+    /// Issues the NFT transfer transaction and returns the transaction Id.
+    pub async fn issue(&self) -> Result<ids::Id> {
+        let picked_http_rpc = self.inner.inner.pick_base_http_url();
+        log::info!(
+            "transferring NFT of asset {} group {} to {} via {}",
+            self.asset_id,
+            self.group_id,
+            self.receiver,
+            picked_http_rpc.1
+        );
+
+        let (mut tx, signers) = self.unsigned_tx().await?;
+        tx.sign(signers).await?;
+
+        if self.dry_mode {
+            return Ok(tx.base_tx.metadata.unwrap().id);
+        }
+
+        let tx_bytes_with_signatures = tx
+            .base_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures;
+        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
+        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        if resp.result.is_none() {
+            return Err(Error::API {
+                message: format!("failed to issue tx {:?} (no result)", resp.error),
+                retryable: false,
+            });
+        }
+
+        let tx_id = resp.result.unwrap().tx_id;
+        log::info!("{} successfully issued", tx_id);
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        sleep(self.poll_initial_wait).await;
+
+        log::info!("polling to confirm operation transaction");
+        let (start, mut success) = (Instant::now(), false);
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&self.poll_timeout) {
+                break;
+            }
+
+            let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
+
+            let status = resp.result.unwrap().status;
+            if status == Status::Accepted {
+                log::info!("{} successfully accepted", tx_id);
+                success = true;
+                break;
+            }
+
+            log::warn!(
+                "{} {} (not accepted yet in {}, elapsed {:?})",
+                tx_id,
+                status,
+                picked_http_rpc.1,
+                elapsed
+            );
+            sleep(self.poll_interval).await;
+        }
+        if !success {
+            return Err(Error::API {
+                message: "failed to check acceptance in time".to_string(),
+                retryable: true,
+            });
+        }
+
+        Ok(tx_id)
+    }
+}